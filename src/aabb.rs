@@ -0,0 +1,104 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Axis-aligned bounding boxes.
+
+use std::fmt;
+
+use matrix::Matrix4;
+use num::{BaseNum, BaseFloat};
+use point::{Point2, Point3};
+use vector::{Vector2, Vector3};
+
+macro_rules! impl_aabb {
+    ($AabbN:ident, $PointN:ident, $VectorN:ident { $($field:ident),+ }, $n:expr) => {
+        /// An axis-aligned bounding box in $n-dimensional space.
+        #[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+        pub struct $AabbN<S> {
+            pub min: $PointN<S>,
+            pub max: $PointN<S>,
+        }
+
+        impl<S: BaseNum> $AabbN<S> {
+            /// Create the smallest box that contains both points.
+            #[inline]
+            pub fn new(p1: $PointN<S>, p2: $PointN<S>) -> $AabbN<S> {
+                $AabbN {
+                    min: $PointN::new($(p1.$field.partial_min(p2.$field)),+),
+                    max: $PointN::new($(p1.$field.partial_max(p2.$field)),+),
+                }
+            }
+
+            /// The dimensions of the box.
+            #[inline]
+            pub fn dim(&self) -> $VectorN<S> {
+                self.max - self.min
+            }
+
+            /// The center of the box.
+            #[inline]
+            pub fn center(&self) -> $PointN<S> {
+                let two = S::one() + S::one();
+                self.min + self.dim() / two
+            }
+
+            /// Whether the point is inside the box or on its boundary.
+            #[inline]
+            pub fn contains(&self, p: $PointN<S>) -> bool {
+                $(self.min.$field <= p.$field && p.$field <= self.max.$field)&&+
+            }
+
+            /// The smallest box that contains this box and the point.
+            #[inline]
+            pub fn grow(&self, p: $PointN<S>) -> $AabbN<S> {
+                $AabbN {
+                    min: $PointN::new($(self.min.$field.partial_min(p.$field)),+),
+                    max: $PointN::new($(self.max.$field.partial_max(p.$field)),+),
+                }
+            }
+        }
+
+        impl<S: BaseNum> fmt::Debug for $AabbN<S> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "[{:?} - {:?}]", self.min, self.max)
+            }
+        }
+    }
+}
+
+impl_aabb!(Aabb2, Point2, Vector2 { x, y }, 2);
+impl_aabb!(Aabb3, Point3, Vector3 { x, y, z }, 3);
+
+impl<S: BaseFloat> Aabb3<S> {
+    /// The bounding box of this box after transforming it by the affine
+    /// matrix `mat`.
+    ///
+    /// Rather than transforming all eight corners, this transforms the
+    /// center and adds up the absolute values of the matrix entries scaled by
+    /// the half extents, as described by Jim Arvo in [Transforming Axis-Aligned
+    /// Bounding Boxes](http://www.graphicsgems.org/), Graphics Gems.
+    pub fn transform(&self, mat: &Matrix4<S>) -> Aabb3<S> {
+        let two = S::two();
+        let center = Point3::from_homogeneous(*mat * self.center().to_homogeneous());
+        let half = self.dim() / two;
+        let mut extent = Vector3::zero();
+        for i in 0..3 {
+            for j in 0..3 {
+                extent[i] = extent[i] + mat[j][i].abs() * half[j];
+            }
+        }
+        Aabb3 { min: center + (-extent), max: center + extent }
+    }
+}
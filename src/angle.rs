@@ -22,7 +22,7 @@ use std::ops::*;
 use rand::{Rand, Rng};
 use rand::distributions::range::SampleRange;
 
-use rust_num::{Float, Zero};
+use rust_num::Float;
 use rust_num::traits::cast;
 
 use approx::ApproxEq;
@@ -90,7 +90,7 @@ pub trait Angle where
     /// Returns the interior bisector of the two angles
     #[inline]
     fn bisect(self, other: Self) -> Self {
-        let half = cast(0.5f64).unwrap();
+        let half = Self::Unitless::half();
         Self::normalize((self - other) * half + self)
     }
 
@@ -137,10 +137,10 @@ macro_rules! impl_angle {
             }
 
             #[inline] fn full_turn() -> $Angle<S> { $Angle::new(cast($full_turn).unwrap()) }
-            #[inline] fn turn_div_2() -> $Angle<S> { let factor: S = cast(2).unwrap(); $Angle::full_turn() / factor }
-            #[inline] fn turn_div_3() -> $Angle<S> { let factor: S = cast(3).unwrap(); $Angle::full_turn() / factor }
-            #[inline] fn turn_div_4() -> $Angle<S> { let factor: S = cast(4).unwrap(); $Angle::full_turn() / factor }
-            #[inline] fn turn_div_6() -> $Angle<S> { let factor: S = cast(6).unwrap(); $Angle::full_turn() / factor }
+            #[inline] fn turn_div_2() -> $Angle<S> { $Angle::full_turn() / S::two() }
+            #[inline] fn turn_div_3() -> $Angle<S> { $Angle::full_turn() / (S::two() + S::one()) }
+            #[inline] fn turn_div_4() -> $Angle<S> { $Angle::turn_div_2() / S::two() }
+            #[inline] fn turn_div_6() -> $Angle<S> { $Angle::turn_div_3() / S::two() }
 
             #[inline] fn sin(self) -> S { Rad::from(self).s.sin() }
             #[inline] fn cos(self) -> S { Rad::from(self).s.cos() }
@@ -209,6 +209,16 @@ macro_rules! impl_angle {
             fn approx_eq_eps(&self, other: &$Angle<S>, epsilon: &S) -> bool {
                 self.s.approx_eq_eps(&other.s, epsilon)
             }
+
+            #[inline]
+            fn relative_eq(&self, other: &$Angle<S>, epsilon: &S, max_relative: &S) -> bool {
+                self.s.relative_eq(&other.s, epsilon, max_relative)
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &$Angle<S>, epsilon: &S, max_ulps: u32) -> bool {
+                self.s.ulps_eq(&other.s, epsilon, max_ulps)
+            }
         }
 
         impl<S: BaseFloat + SampleRange> Rand for $Angle<S> {
@@ -16,6 +16,14 @@
 use rust_num::{Float, NumCast};
 use rust_num::traits::cast;
 
+/// Approximate equality, for comparing values that are subject to rounding
+/// errors.
+///
+/// The `==` operator on the types in this crate is always exact
+/// component-wise equality, so it is transitive, and reflexive for values
+/// without NaN components, and can be relied on for keys and deduplication.
+/// A NaN compares unequal to everything, including itself. Use the methods of
+/// this trait when a tolerance is required.
 pub trait ApproxEq: Sized {
     type Epsilon: NumCast + Float;
 
@@ -28,11 +36,39 @@ pub trait ApproxEq: Sized {
     }
 
     fn approx_eq_eps(&self, other: &Self, epsilon: &Self::Epsilon) -> bool;
+
+    /// Compare with a tolerance relative to the magnitude of the values, so
+    /// that the comparison is meaningful at any scale.
+    ///
+    /// Scalars are equal if their difference is within `epsilon`, which
+    /// handles values near zero, or within `max_relative` times the larger
+    /// of their magnitudes. Composite values compare component-wise.
+    ///
+    /// The default implementation ignores `max_relative`, and is the same as
+    /// `approx_eq_eps`.
+    fn relative_eq(&self, other: &Self, epsilon: &Self::Epsilon,
+                   _max_relative: &Self::Epsilon) -> bool {
+        self.approx_eq_eps(other, epsilon)
+    }
+
+    /// Compare by the number of representable floating point values between
+    /// the two values.
+    ///
+    /// Scalars are equal if their difference is within `epsilon`, which
+    /// handles values near zero, or if they have the same sign and are at
+    /// most `max_ulps` units in the last place apart. Composite values
+    /// compare component-wise.
+    ///
+    /// The default implementation ignores `max_ulps`, and is the same as
+    /// `approx_eq_eps`.
+    fn ulps_eq(&self, other: &Self, epsilon: &Self::Epsilon, _max_ulps: u32) -> bool {
+        self.approx_eq_eps(other, epsilon)
+    }
 }
 
 
 macro_rules! approx_float(
-    ($S:ident) => (
+    ($S:ident, $I:ident) => (
         impl ApproxEq for $S {
             type Epsilon = $S;
 
@@ -40,12 +76,38 @@ macro_rules! approx_float(
             fn approx_eq_eps(&self, other: &$S, epsilon: &$S) -> bool {
                  (*self - *other).abs() < *epsilon
             }
+
+            #[inline]
+            fn relative_eq(&self, other: &$S, epsilon: &$S, max_relative: &$S) -> bool {
+                if self == other {
+                    return true;
+                }
+                let diff = (*self - *other).abs();
+                if diff <= *epsilon {
+                    return true;
+                }
+                diff <= self.abs().max(other.abs()) * *max_relative
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &$S, epsilon: &$S, max_ulps: u32) -> bool {
+                if (*self - *other).abs() <= *epsilon {
+                    return true;
+                }
+                if self.is_nan() || other.is_nan() ||
+                   self.is_sign_negative() != other.is_sign_negative() {
+                    return false;
+                }
+                let a = self.to_bits() as $I;
+                let b = other.to_bits() as $I;
+                (a - b).abs() as u64 <= max_ulps as u64
+            }
         }
     )
 );
 
-approx_float!(f32);
-approx_float!(f64);
+approx_float!(f32, i32);
+approx_float!(f64, i64);
 
 #[macro_export]
 macro_rules! assert_approx_eq_eps(
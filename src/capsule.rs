@@ -0,0 +1,85 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use aabb::Aabb3;
+use cylinder::Cylinder3;
+use gjk::gjk_intersects;
+use line::Segment3;
+use num::BaseFloat;
+use point::Point3;
+use sphere::Sphere;
+
+/// A capsule: the set of points within `radius` of the segment from `start`
+/// to `end`.
+#[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+pub struct Capsule3<S> {
+    pub start: Point3<S>,
+    pub end: Point3<S>,
+    pub radius: S,
+}
+
+impl<S: BaseFloat> Capsule3<S> {
+    /// Create a new capsule.
+    #[inline]
+    pub fn new(start: Point3<S>, end: Point3<S>, radius: S) -> Capsule3<S> {
+        Capsule3 { start: start, end: end, radius: radius }
+    }
+
+    /// The segment at the core of the capsule.
+    #[inline]
+    pub fn segment(&self) -> Segment3<S> {
+        Segment3::new(self.start, self.end)
+    }
+
+    /// Whether the point is inside the capsule or on its surface.
+    #[inline]
+    pub fn contains(&self, point: Point3<S>) -> bool {
+        self.segment().distance2(point) <= self.radius * self.radius
+    }
+
+    /// Whether the capsule and the sphere overlap.
+    #[inline]
+    pub fn intersects_sphere(&self, sphere: &Sphere<S>) -> bool {
+        let reach = self.radius + sphere.radius;
+        self.segment().distance2(sphere.center) <= reach * reach
+    }
+
+    /// Whether the two capsules overlap.
+    #[inline]
+    pub fn intersects_capsule(&self, other: &Capsule3<S>) -> bool {
+        let reach = self.radius + other.radius;
+        self.segment().segment_distance2(&other.segment()) <= reach * reach
+    }
+
+    /// Whether the capsule and the box overlap.
+    #[inline]
+    pub fn intersects_aabb(&self, aabb: &Aabb3<S>) -> bool {
+        gjk_intersects(self, aabb)
+    }
+
+    /// Whether the capsule and the cylinder overlap.
+    #[inline]
+    pub fn intersects_cylinder(&self, cylinder: &Cylinder3<S>) -> bool {
+        gjk_intersects(self, cylinder)
+    }
+}
+
+impl<S: BaseFloat> fmt::Debug for Capsule3<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Capsule3({:?}, {:?}, {:?})", self.start, self.end, self.radius)
+    }
+}
@@ -0,0 +1,234 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Colors, with conversions between color spaces.
+
+use std::fmt;
+use std::ops::*;
+
+use rust_num::traits::cast;
+
+use angle::{Angle, Deg, deg};
+use approx::ApproxEq;
+use num::BaseFloat;
+use vector::{Vector3, Vector4};
+
+/// A color with red, green, and blue channels.
+#[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+pub struct Rgb<S> { pub r: S, pub g: S, pub b: S }
+
+/// A color with red, green, blue, and alpha channels. The color channels are
+/// not premultiplied by the alpha.
+#[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+pub struct Rgba<S> { pub r: S, pub g: S, pub b: S, pub a: S }
+
+/// Convert a channel from the sRGB transfer function to linear intensity.
+pub fn srgb_to_linear<S: BaseFloat>(c: S) -> S {
+    if c <= cast(0.04045f64).unwrap() {
+        c / cast(12.92f64).unwrap()
+    } else {
+        ((c + cast(0.055f64).unwrap()) / cast(1.055f64).unwrap()).powf(cast(2.4f64).unwrap())
+    }
+}
+
+/// Convert a channel from linear intensity to the sRGB transfer function.
+pub fn linear_to_srgb<S: BaseFloat>(c: S) -> S {
+    if c <= cast(0.0031308f64).unwrap() {
+        c * cast(12.92f64).unwrap()
+    } else {
+        let exponent: S = cast(1.0f64 / 2.4).unwrap();
+        c.powf(exponent) * cast(1.055f64).unwrap() - cast(0.055f64).unwrap()
+    }
+}
+
+macro_rules! impl_color {
+    ($Color:ident { $($field:ident),+ }, $VectorN:ident) => {
+        impl<S> $Color<S> {
+            /// Construct a new color from its channels.
+            #[inline]
+            pub fn new($($field: S),+) -> $Color<S> {
+                $Color { $($field: $field),+ }
+            }
+        }
+
+        impl<S: BaseFloat> From<$VectorN<S>> for $Color<S> {
+            #[inline]
+            fn from(v: $VectorN<S>) -> $Color<S> {
+                let ($($field),+) = v.into();
+                $Color { $($field: $field),+ }
+            }
+        }
+
+        impl<S: BaseFloat> From<$Color<S>> for $VectorN<S> {
+            #[inline]
+            fn from(c: $Color<S>) -> $VectorN<S> {
+                $VectorN::new($(c.$field),+)
+            }
+        }
+
+        impl_operator!(<S: BaseFloat> Add<$Color<S> > for $Color<S> {
+            fn add(lhs, rhs) -> $Color<S> { $Color::new($(lhs.$field + rhs.$field),+) }
+        });
+        impl_operator!(<S: BaseFloat> Sub<$Color<S> > for $Color<S> {
+            fn sub(lhs, rhs) -> $Color<S> { $Color::new($(lhs.$field - rhs.$field),+) }
+        });
+        impl_operator!(<S: BaseFloat> Mul<$Color<S> > for $Color<S> {
+            fn mul(lhs, rhs) -> $Color<S> { $Color::new($(lhs.$field * rhs.$field),+) }
+        });
+        impl_operator!(<S: BaseFloat> Mul<S> for $Color<S> {
+            fn mul(color, scalar) -> $Color<S> { $Color::new($(color.$field * scalar),+) }
+        });
+        impl_operator!(<S: BaseFloat> Div<S> for $Color<S> {
+            fn div(color, scalar) -> $Color<S> { $Color::new($(color.$field / scalar),+) }
+        });
+
+        impl<S: BaseFloat> ApproxEq for $Color<S> {
+            type Epsilon = S;
+
+            #[inline]
+            fn approx_eq_eps(&self, other: &$Color<S>, epsilon: &S) -> bool {
+                $(self.$field.approx_eq_eps(&other.$field, epsilon))&&+
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &$Color<S>, epsilon: &S, max_relative: &S) -> bool {
+                $(self.$field.relative_eq(&other.$field, epsilon, max_relative))&&+
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &$Color<S>, epsilon: &S, max_ulps: u32) -> bool {
+                $(self.$field.ulps_eq(&other.$field, epsilon, max_ulps))&&+
+            }
+        }
+    }
+}
+
+impl_color!(Rgb { r, g, b }, Vector3);
+impl_color!(Rgba { r, g, b, a }, Vector4);
+
+impl<S: BaseFloat> Rgb<S> {
+    /// Construct a color from a hue, and a saturation and value in `[0, 1]`.
+    /// The hue wraps around, so red is at both 0° and 360°.
+    pub fn from_hsv(hue: Deg<S>, saturation: S, value: S) -> Rgb<S> {
+        let sixty: S = cast(60).unwrap();
+        let two = S::two();
+        let h = hue.normalize().s / sixty;
+        let chroma = value * saturation;
+        let x = chroma * (S::one() - (h % two - S::one()).abs());
+        let (r, g, b) = match cast::<S, u8>(h).unwrap_or(0) {
+            0 => (chroma, x, S::zero()),
+            1 => (x, chroma, S::zero()),
+            2 => (S::zero(), chroma, x),
+            3 => (S::zero(), x, chroma),
+            4 => (x, S::zero(), chroma),
+            _ => (chroma, S::zero(), x),
+        };
+        let m = value - chroma;
+        Rgb::new(r + m, g + m, b + m)
+    }
+
+    /// Convert the color to a hue in `[0°, 360°)`, and a saturation and
+    /// value. The hue of a gray is zero.
+    pub fn to_hsv(&self) -> (Deg<S>, S, S) {
+        let max = self.r.partial_max(self.g).partial_max(self.b);
+        let min = self.r.partial_min(self.g).partial_min(self.b);
+        let delta = max - min;
+        let sixty: S = cast(60).unwrap();
+
+        let hue = if delta == S::zero() {
+            S::zero()
+        } else if max == self.r {
+            (self.g - self.b) / delta
+        } else if max == self.g {
+            (self.b - self.r) / delta + S::two()
+        } else {
+            (self.r - self.g) / delta + cast(4).unwrap()
+        };
+        let saturation = if max == S::zero() { S::zero() } else { delta / max };
+        (deg(hue * sixty).normalize(), saturation, max)
+    }
+
+    /// Convert from sRGB-encoded channels to linear intensities.
+    pub fn to_linear(&self) -> Rgb<S> {
+        Rgb::new(srgb_to_linear(self.r), srgb_to_linear(self.g), srgb_to_linear(self.b))
+    }
+
+    /// Convert from linear intensities to sRGB-encoded channels.
+    pub fn to_srgb(&self) -> Rgb<S> {
+        Rgb::new(linear_to_srgb(self.r), linear_to_srgb(self.g), linear_to_srgb(self.b))
+    }
+
+    /// Add an alpha channel to the color.
+    #[inline]
+    pub fn with_alpha(&self, a: S) -> Rgba<S> {
+        Rgba::new(self.r, self.g, self.b, a)
+    }
+}
+
+impl<S: BaseFloat> Rgba<S> {
+    /// The color without its alpha channel.
+    #[inline]
+    pub fn rgb(&self) -> Rgb<S> {
+        Rgb::new(self.r, self.g, self.b)
+    }
+
+    /// Convert the color channels from sRGB to linear intensities. Alpha is
+    /// always linear, so it is unchanged.
+    pub fn to_linear(&self) -> Rgba<S> {
+        self.rgb().to_linear().with_alpha(self.a)
+    }
+
+    /// Convert the color channels from linear intensities to sRGB. Alpha is
+    /// always linear, so it is unchanged.
+    pub fn to_srgb(&self) -> Rgba<S> {
+        self.rgb().to_srgb().with_alpha(self.a)
+    }
+}
+
+impl Rgb<f32> {
+    /// Pack the channels, clamped to `[0, 1]`, into the low 24 bits, with red
+    /// in the least significant byte, and an opaque alpha in the high byte.
+    #[inline]
+    pub fn pack_rgba8(&self) -> u32 {
+        self.with_alpha(1.0).pack_rgba8()
+    }
+}
+
+impl Rgba<f32> {
+    /// Pack the channels, clamped to `[0, 1]`, into 8 bits each, with red in
+    /// the least significant byte.
+    #[inline]
+    pub fn pack_rgba8(&self) -> u32 {
+        Vector4::from(*self).pack_unorm4x8()
+    }
+
+    /// Unpack 8-bit channels, with red in the least significant byte.
+    #[inline]
+    pub fn unpack_rgba8(packed: u32) -> Rgba<f32> {
+        Rgba::from(Vector4::unpack_unorm4x8(packed))
+    }
+}
+
+impl<S: BaseFloat> fmt::Debug for Rgb<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Rgb({:?}, {:?}, {:?})", self.r, self.g, self.b)
+    }
+}
+
+impl<S: BaseFloat> fmt::Debug for Rgba<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Rgba({:?}, {:?}, {:?}, {:?})", self.r, self.g, self.b, self.a)
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use aabb::Aabb3;
+use capsule::Capsule3;
+use gjk::gjk_intersects;
+use line::Segment3;
+use num::BaseFloat;
+use point::Point3;
+use sphere::Sphere;
+use vector::{Vector, Vector3, EuclideanVector};
+
+/// A solid cylinder with flat caps, whose axis runs from `start` to `end`.
+#[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+pub struct Cylinder3<S> {
+    pub start: Point3<S>,
+    pub end: Point3<S>,
+    pub radius: S,
+}
+
+impl<S: BaseFloat> Cylinder3<S> {
+    /// Create a new cylinder.
+    #[inline]
+    pub fn new(start: Point3<S>, end: Point3<S>, radius: S) -> Cylinder3<S> {
+        Cylinder3 { start: start, end: end, radius: radius }
+    }
+
+    /// The axis of the cylinder, as a segment.
+    #[inline]
+    pub fn axis(&self) -> Segment3<S> {
+        Segment3::new(self.start, self.end)
+    }
+
+    /// The distance between the caps of the cylinder.
+    #[inline]
+    pub fn height(&self) -> S {
+        self.axis().length()
+    }
+
+    /// The component of `v` perpendicular to the axis of the cylinder.
+    #[inline]
+    pub fn radial(&self, v: Vector3<S>) -> Vector3<S> {
+        let axis = self.end - self.start;
+        let length2 = axis.length2();
+        if length2 == S::zero() {
+            v
+        } else {
+            v - axis * (v.dot(axis) / length2)
+        }
+    }
+
+    /// Whether the point is inside the cylinder or on its surface.
+    pub fn contains(&self, point: Point3<S>) -> bool {
+        let axis = self.end - self.start;
+        let offset = point - self.start;
+        let t = offset.dot(axis);
+        t >= S::zero() && t <= axis.length2() &&
+            self.radial(offset).length2() <= self.radius * self.radius
+    }
+
+    /// Whether the cylinder and the sphere overlap.
+    #[inline]
+    pub fn intersects_sphere(&self, sphere: &Sphere<S>) -> bool {
+        gjk_intersects(self, sphere)
+    }
+
+    /// Whether the cylinder and the box overlap.
+    #[inline]
+    pub fn intersects_aabb(&self, aabb: &Aabb3<S>) -> bool {
+        gjk_intersects(self, aabb)
+    }
+
+    /// Whether the cylinder and the capsule overlap.
+    #[inline]
+    pub fn intersects_capsule(&self, capsule: &Capsule3<S>) -> bool {
+        gjk_intersects(self, capsule)
+    }
+
+    /// Whether the two cylinders overlap.
+    #[inline]
+    pub fn intersects_cylinder(&self, other: &Cylinder3<S>) -> bool {
+        gjk_intersects(self, other)
+    }
+}
+
+impl<S: BaseFloat> fmt::Debug for Cylinder3<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Cylinder3({:?}, {:?}, {:?})", self.start, self.end, self.radius)
+    }
+}
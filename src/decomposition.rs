@@ -0,0 +1,644 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Factorizations of square matrices.
+
+use std::fmt;
+
+use array::Array;
+use matrix::{Matrix, SquareMatrix, is_singular};
+use matrix::{Matrix2, Matrix3, Matrix4};
+use approx::ApproxEq;
+use num::BaseFloat;
+use quaternion::Quaternion;
+use vector::{Vector, EuclideanVector};
+use vector::{Vector2, Vector3, Vector4};
+
+macro_rules! impl_lu {
+    ($Lu:ident, $MatrixN:ident, $VectorN:ident, $n:expr) => {
+        /// The LU decomposition of a matrix `A` with partial pivoting, such
+        /// that `P * A = L * U`.
+        #[derive(Copy, Clone, PartialEq)]
+        pub struct $Lu<S> {
+            /// A lower triangular matrix with ones on the diagonal.
+            pub l: $MatrixN<S>,
+            /// An upper triangular matrix.
+            pub u: $MatrixN<S>,
+            /// The row permutation `P`: row `i` of `P * A` is row `perm[i]` of
+            /// `A`.
+            pub perm: [usize; $n],
+        }
+
+        impl<S: BaseFloat> $MatrixN<S> {
+            /// Compute the LU decomposition of this matrix, using partial
+            /// pivoting for numerical stability. Returns `None` if the matrix
+            /// is singular, by the same scale-independent test as `invert`.
+            pub fn lu(&self) -> Option<$Lu<S>> {
+                let mut l = $MatrixN::identity();
+                let mut u = *self;
+                let mut perm = [0; $n];
+                for i in 0..$n {
+                    perm[i] = i;
+                }
+
+                for k in 0..$n {
+                    // choose the largest remaining pivot in column k
+                    let mut p = k;
+                    for r in (k + 1)..$n {
+                        if u[k][r].abs() > u[k][p].abs() {
+                            p = r;
+                        }
+                    }
+                    // the determinant is zero, and the elimination would
+                    // divide by zero
+                    if u[k][p] == S::zero() {
+                        return None;
+                    }
+                    if p != k {
+                        u.swap_rows(k, p);
+                        perm.swap(k, p);
+                        for c in 0..k {
+                            l.swap_elements((c, k), (c, p));
+                        }
+                    }
+
+                    for r in (k + 1)..$n {
+                        let factor = u[k][r] / u[k][k];
+                        l[k][r] = factor;
+                        for c in k..$n {
+                            u[c][r] = u[c][r] - factor * u[c][k];
+                        }
+                    }
+                }
+
+                let lu = $Lu { l: l, u: u, perm: perm };
+                if is_singular(lu.determinant(), self.column_length2_product()) {
+                    None
+                } else {
+                    Some(lu)
+                }
+            }
+
+            /// Solve the linear system `self * x = b` for `x`, using the LU
+            /// decomposition. Returns `None` if the matrix is singular.
+            ///
+            /// This is cheaper and more accurate than multiplying `b` by the
+            /// inverse. To solve for several right-hand sides, reuse the
+            /// result of `lu` instead.
+            #[inline]
+            pub fn solve(&self, b: $VectorN<S>) -> Option<$VectorN<S>> {
+                self.lu().map(|lu| lu.solve(b))
+            }
+        }
+
+        impl<S: BaseFloat> $Lu<S> {
+            /// Solve `A * x = b` for `x`, by forward and back substitution.
+            pub fn solve(&self, b: $VectorN<S>) -> $VectorN<S> {
+                // L * y = P * b
+                let mut y = $VectorN::zero();
+                for i in 0..$n {
+                    let mut sum = b[self.perm[i]];
+                    for j in 0..i {
+                        sum = sum - self.l[j][i] * y[j];
+                    }
+                    y[i] = sum;
+                }
+                // U * x = y
+                let mut x = $VectorN::zero();
+                for i in (0..$n).rev() {
+                    let mut sum = y[i];
+                    for j in (i + 1)..$n {
+                        sum = sum - self.u[j][i] * x[j];
+                    }
+                    x[i] = sum / self.u[i][i];
+                }
+                x
+            }
+
+            /// The determinant of `A`.
+            pub fn determinant(&self) -> S {
+                let mut det = S::one();
+                for i in 0..$n {
+                    det = det * self.u[i][i];
+                    for j in (i + 1)..$n {
+                        if self.perm[j] < self.perm[i] {
+                            det = -det;
+                        }
+                    }
+                }
+                det
+            }
+        }
+
+        impl<S: BaseFloat> fmt::Debug for $Lu<S> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{} {{ l: {:?}, u: {:?}, perm: {:?} }}",
+                       stringify!($Lu), self.l, self.u, self.perm)
+            }
+        }
+    }
+}
+
+impl_lu!(Lu2, Matrix2, Vector2, 2);
+impl_lu!(Lu3, Matrix3, Vector3, 3);
+impl_lu!(Lu4, Matrix4, Vector4, 4);
+
+macro_rules! impl_cholesky {
+    ($Cholesky:ident, $MatrixN:ident, $VectorN:ident, $n:expr) => {
+        /// The Cholesky decomposition of a symmetric positive-definite matrix
+        /// `A`, such that `A = L * L^T`.
+        #[derive(Copy, Clone, PartialEq)]
+        pub struct $Cholesky<S> {
+            /// A lower triangular matrix with a positive diagonal.
+            pub l: $MatrixN<S>,
+        }
+
+        impl<S: BaseFloat> $MatrixN<S> {
+            /// Compute the Cholesky decomposition of a symmetric matrix.
+            /// Returns `None` if the matrix is not positive-definite. Only
+            /// the lower triangle of the matrix is read.
+            ///
+            /// This is about twice as fast as the LU decomposition, and is
+            /// the usual way to solve the normal equations of a least-squares
+            /// fit. Multiplying a vector of independent standard normal
+            /// samples by `L` gives a sample with covariance `A`.
+            pub fn cholesky(&self) -> Option<$Cholesky<S>> {
+                let mut l = $MatrixN::zero();
+                for j in 0..$n {
+                    let mut diagonal = self[j][j];
+                    for k in 0..j {
+                        diagonal = diagonal - l[k][j] * l[k][j];
+                    }
+                    if !(diagonal > S::zero()) {
+                        return None;
+                    }
+                    l[j][j] = diagonal.sqrt();
+
+                    for i in (j + 1)..$n {
+                        let mut sum = self[j][i];
+                        for k in 0..j {
+                            sum = sum - l[k][i] * l[k][j];
+                        }
+                        l[j][i] = sum / l[j][j];
+                    }
+                }
+                Some($Cholesky { l: l })
+            }
+        }
+
+        impl<S: BaseFloat> $Cholesky<S> {
+            /// Solve `A * x = b` for `x`, by forward and back substitution.
+            pub fn solve(&self, b: $VectorN<S>) -> $VectorN<S> {
+                // L * y = b
+                let mut y = $VectorN::zero();
+                for i in 0..$n {
+                    let mut sum = b[i];
+                    for j in 0..i {
+                        sum = sum - self.l[j][i] * y[j];
+                    }
+                    y[i] = sum / self.l[i][i];
+                }
+                // L^T * x = y
+                let mut x = $VectorN::zero();
+                for i in (0..$n).rev() {
+                    let mut sum = y[i];
+                    for j in (i + 1)..$n {
+                        sum = sum - self.l[i][j] * x[j];
+                    }
+                    x[i] = sum / self.l[i][i];
+                }
+                x
+            }
+
+            /// The determinant of `A`.
+            pub fn determinant(&self) -> S {
+                let mut det = S::one();
+                for i in 0..$n {
+                    det = det * self.l[i][i] * self.l[i][i];
+                }
+                det
+            }
+        }
+
+        impl<S: BaseFloat> fmt::Debug for $Cholesky<S> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{} {{ l: {:?} }}", stringify!($Cholesky), self.l)
+            }
+        }
+    }
+}
+
+impl_cholesky!(Cholesky2, Matrix2, Vector2, 2);
+impl_cholesky!(Cholesky3, Matrix3, Vector3, 3);
+impl_cholesky!(Cholesky4, Matrix4, Vector4, 4);
+
+macro_rules! impl_qr {
+    ($MatrixN:ident, $n:expr) => {
+        impl<S: BaseFloat> $MatrixN<S> {
+            /// Compute the QR decomposition of this matrix, `A = Q * R`, where
+            /// `Q` is orthogonal and `R` is upper triangular with a positive
+            /// diagonal. Returns `None` if the columns are linearly dependent.
+            ///
+            /// This uses the modified Gram-Schmidt process on the columns.
+            pub fn qr(&self) -> Option<($MatrixN<S>, $MatrixN<S>)> {
+                let mut q = *self;
+                let mut r = $MatrixN::zero();
+                for j in 0..$n {
+                    let mut v = self[j];
+                    for i in 0..j {
+                        let projection = q[i].dot(v);
+                        r[j][i] = projection;
+                        v = v - q[i] * projection;
+                    }
+                    let length = v.length();
+                    if length == S::zero() {
+                        return None;
+                    }
+                    r[j][j] = length;
+                    q[j] = v / length;
+                }
+                Some((q, r))
+            }
+
+            /// Make the columns of this matrix orthonormal again, for example
+            /// to repair a rotation matrix that has drifted after many
+            /// incremental updates.
+            ///
+            /// The first column keeps its direction, and each following column
+            /// is made perpendicular to the ones before it. The columns must be
+            /// linearly independent, which is always the case for a matrix
+            /// that is close to a rotation.
+            pub fn orthonormalize(&self) -> $MatrixN<S> {
+                let mut q = *self;
+                for j in 0..$n {
+                    let mut v = q[j];
+                    for i in 0..j {
+                        v = v - q[i] * q[i].dot(v);
+                    }
+                    q[j] = v.normalize();
+                }
+                q
+            }
+        }
+    }
+}
+
+impl_qr!(Matrix2, 2);
+impl_qr!(Matrix3, 3);
+impl_qr!(Matrix4, 4);
+
+/// The maximum number of sweeps of the Jacobi eigenvalue algorithm. It
+/// converges quadratically, so this is only reached for pathological input.
+const MAX_JACOBI_SWEEPS: usize = 32;
+
+macro_rules! impl_eigen_symmetric {
+    ($MatrixN:ident, $VectorN:ident, $n:expr) => {
+        impl<S: BaseFloat> $MatrixN<S> {
+            /// Compute the eigenvalues and eigenvectors of a symmetric matrix,
+            /// using the [Jacobi eigenvalue algorithm]
+            /// (https://en.wikipedia.org/wiki/Jacobi_eigenvalue_algorithm).
+            ///
+            /// Returns the eigenvalues in decreasing order, and a rotation
+            /// matrix whose columns are the corresponding unit eigenvectors.
+            /// Only the lower triangle of the matrix is read.
+            pub fn eigen_symmetric(&self) -> ($VectorN<S>, $MatrixN<S>) {
+                let mut a = *self;
+                for c in 0..$n {
+                    for r in 0..c {
+                        a[c][r] = a[r][c];
+                    }
+                }
+                let mut v = $MatrixN::identity();
+                let two = S::two();
+                let threshold = S::epsilon() * S::epsilon() * a.norm_frobenius() * a.norm_frobenius();
+
+                for _ in 0..MAX_JACOBI_SWEEPS {
+                    let mut off = S::zero();
+                    for q in 0..$n {
+                        for p in 0..q {
+                            off = off + a[q][p] * a[q][p];
+                        }
+                    }
+                    if off <= threshold {
+                        break;
+                    }
+
+                    for p in 0..$n {
+                        for q in (p + 1)..$n {
+                            let apq = a[q][p];
+                            if apq == S::zero() {
+                                continue;
+                            }
+                            // the rotation that zeroes the (p, q) element
+                            let theta = (a[q][q] - a[p][p]) / (two * apq);
+                            let t = theta.signum() / (theta.abs() + (theta * theta + S::one()).sqrt());
+                            let c = (t * t + S::one()).sqrt().recip();
+                            let s = t * c;
+                            for k in 0..$n {
+                                let (akp, akq) = (a[p][k], a[q][k]);
+                                a[p][k] = c * akp - s * akq;
+                                a[q][k] = s * akp + c * akq;
+                            }
+                            for k in 0..$n {
+                                let (apk, aqk) = (a[k][p], a[k][q]);
+                                a[k][p] = c * apk - s * aqk;
+                                a[k][q] = s * apk + c * aqk;
+                            }
+                            for k in 0..$n {
+                                let (vkp, vkq) = (v[p][k], v[q][k]);
+                                v[p][k] = c * vkp - s * vkq;
+                                v[q][k] = s * vkp + c * vkq;
+                            }
+                        }
+                    }
+                }
+
+                let mut values = a.diagonal();
+                for i in 0..$n {
+                    let mut largest = i;
+                    for j in (i + 1)..$n {
+                        if values[j] > values[largest] {
+                            largest = j;
+                        }
+                    }
+                    if largest != i {
+                        values.swap_elements(i, largest);
+                        v.swap_columns(i, largest);
+                    }
+                }
+                // keep a right-handed basis
+                if v.determinant() < S::zero() {
+                    v[$n - 1] = -v[$n - 1];
+                }
+                (values, v)
+            }
+        }
+    }
+}
+
+impl_eigen_symmetric!(Matrix2, Vector2, 2);
+impl_eigen_symmetric!(Matrix3, Vector3, 3);
+
+/// The maximum number of Newton iterations of the polar decomposition.
+const MAX_POLAR_ITERATIONS: usize = 32;
+
+impl<S: BaseFloat> Matrix3<S> {
+    /// Compute the [polar decomposition](https://en.wikipedia.org/wiki/Polar_decomposition)
+    /// of this matrix, `A = R * S`, where `R` is orthogonal and `S` is
+    /// symmetric positive-definite. Returns `None` if the matrix is singular.
+    ///
+    /// `R` is the rotation closest to the matrix, and `S` the stretch that
+    /// it applies before the rotation. If the matrix contains a reflection
+    /// (a negative determinant), so does `R`.
+    pub fn polar_decompose(&self) -> Option<(Matrix3<S>, Matrix3<S>)> {
+        let half = S::half();
+        let tolerance = S::epsilon() * S::two() * S::two();
+        let mut r = *self;
+        for _ in 0..MAX_POLAR_ITERATIONS {
+            // average the matrix with its inverse transpose
+            let next = match r.invert() {
+                Some(inverse) => (r + inverse.transpose()) * half,
+                None => return None,
+            };
+            let delta = (next - r).norm_frobenius();
+            r = next;
+            if delta <= tolerance * r.norm_frobenius() {
+                break;
+            }
+        }
+        let s = r.transpose() * *self;
+        Some((r, (s + s.transpose()) * half))
+    }
+}
+
+macro_rules! impl_svd {
+    ($MatrixN:ident, $VectorN:ident, $n:expr, $complete:ident) => {
+        impl<S: BaseFloat> $MatrixN<S> {
+            /// Compute the [singular value decomposition]
+            /// (https://en.wikipedia.org/wiki/Singular_value_decomposition) of
+            /// this matrix, `A = U * Σ * V^T`, using one-sided Jacobi
+            /// rotations.
+            ///
+            /// Returns `(U, Σ, V)`, where `U` and `V` are orthogonal and the
+            /// singular values `Σ` are non-negative and in decreasing order.
+            /// The columns of `U` for zero singular values are filled in to
+            /// complete an orthonormal basis.
+            pub fn svd(&self) -> ($MatrixN<S>, $VectorN<S>, $MatrixN<S>) {
+                let mut u = *self;
+                let mut v = $MatrixN::identity();
+                let two = S::two();
+
+                for _ in 0..MAX_JACOBI_SWEEPS {
+                    let mut rotated = false;
+                    for p in 0..$n {
+                        for q in (p + 1)..$n {
+                            let alpha = u[p].length2();
+                            let beta = u[q].length2();
+                            let gamma = u[p].dot(u[q]);
+                            if gamma.abs() <= S::epsilon() * (alpha * beta).sqrt() {
+                                continue;
+                            }
+                            rotated = true;
+                            // the rotation that makes columns p and q orthogonal
+                            let zeta = (beta - alpha) / (two * gamma);
+                            let t = zeta.signum() / (zeta.abs() + (zeta * zeta + S::one()).sqrt());
+                            let c = (t * t + S::one()).sqrt().recip();
+                            let s = t * c;
+                            let (up, uq) = (u[p], u[q]);
+                            u[p] = up * c - uq * s;
+                            u[q] = up * s + uq * c;
+                            let (vp, vq) = (v[p], v[q]);
+                            v[p] = vp * c - vq * s;
+                            v[q] = vp * s + vq * c;
+                        }
+                    }
+                    if !rotated {
+                        break;
+                    }
+                }
+
+                let mut sigma = $VectorN::zero();
+                for i in 0..$n {
+                    sigma[i] = u[i].length();
+                }
+                for i in 0..$n {
+                    let mut largest = i;
+                    for j in (i + 1)..$n {
+                        if sigma[j] > sigma[largest] {
+                            largest = j;
+                        }
+                    }
+                    if largest != i {
+                        sigma.swap_elements(i, largest);
+                        u.swap_columns(i, largest);
+                        v.swap_columns(i, largest);
+                    }
+                }
+
+                // the number of singular values that are not negligible
+                let tolerance = sigma[0] * S::epsilon() * two * two;
+                let mut rank = 0;
+                for i in 0..$n {
+                    if sigma[i] > tolerance {
+                        u[i] = u[i] / sigma[i];
+                        rank += 1;
+                    } else {
+                        sigma[i] = S::zero();
+                    }
+                }
+                ($complete(u, rank), sigma, v)
+            }
+        }
+    }
+}
+
+/// Fill in the columns of `u` after the first `rank` to make an orthonormal
+/// basis.
+fn complete_basis2<S: BaseFloat>(u: Matrix2<S>, rank: usize) -> Matrix2<S> {
+    match rank {
+        0 => Matrix2::identity(),
+        1 => Matrix2::from_cols(u.x, u.x.perp()),
+        _ => u,
+    }
+}
+
+/// Fill in the columns of `u` after the first `rank` to make an orthonormal
+/// basis.
+fn complete_basis3<S: BaseFloat>(u: Matrix3<S>, rank: usize) -> Matrix3<S> {
+    match rank {
+        0 => Matrix3::identity(),
+        1 => {
+            let (y, z) = u.x.any_orthonormal_pair();
+            Matrix3::from_cols(u.x, y, z)
+        }
+        2 => Matrix3::from_cols(u.x, u.y, u.x.cross(u.y)),
+        _ => u,
+    }
+}
+
+impl_svd!(Matrix2, Vector2, 2, complete_basis2);
+impl_svd!(Matrix3, Vector3, 3, complete_basis3);
+
+/// An affine transformation split into a translation, a rotation, a shear,
+/// and a scale, such that the linear part is `R * H * S`, where `H` is the
+/// upper unit triangular shear matrix:
+///
+/// ```text
+/// [1, xy, xz]
+/// [0,  1, yz]
+/// [0,  0,  1]
+/// ```
+///
+/// This is the decomposition from Spencer W. Thomas' "Decomposing a Matrix
+/// into Simple Transformations", Graphics Gems II.
+#[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+pub struct AffineDecomposition<S> {
+    pub translation: Vector3<S>,
+    pub rotation: Quaternion<S>,
+    /// The shear factors `(xy, xz, yz)`.
+    pub shear: Vector3<S>,
+    /// The scale along each axis. A reflection is represented by negating
+    /// all three.
+    pub scale: Vector3<S>,
+}
+
+impl<S: BaseFloat> AffineDecomposition<S> {
+    /// Returns `true` if the shear factors are approximately zero, so that
+    /// the transformation can be represented by a translation, rotation, and
+    /// non-uniform scale alone.
+    #[inline]
+    pub fn is_shear_free(&self) -> bool {
+        self.shear.approx_eq(&Vector3::zero())
+    }
+}
+
+impl<S: BaseFloat> From<AffineDecomposition<S>> for Matrix4<S> {
+    fn from(d: AffineDecomposition<S>) -> Matrix4<S> {
+        let r = Matrix3::from(d.rotation);
+        let x = r.x * d.scale.x;
+        let y = (r.x * d.shear.x + r.y) * d.scale.y;
+        let z = (r.x * d.shear.y + r.y * d.shear.z + r.z) * d.scale.z;
+        Matrix4::from_cols(x.extend(S::zero()),
+                           y.extend(S::zero()),
+                           z.extend(S::zero()),
+                           d.translation.extend(S::one()))
+    }
+}
+
+impl<S: BaseFloat> fmt::Debug for AffineDecomposition<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AffineDecomposition {{ translation: {:?}, rotation: {:?}, shear: {:?}, scale: {:?} }}",
+               self.translation, self.rotation, self.shear, self.scale)
+    }
+}
+
+impl<S: BaseFloat> Matrix4<S> {
+    /// Decompose an affine transformation matrix into a translation,
+    /// rotation, shear, and scale, by Gram-Schmidt orthogonalization of its
+    /// columns. Unlike the polar decomposition, this reports any shear
+    /// separately, rather than folding it into the scale.
+    ///
+    /// Returns `None` if the bottom row is not `[0, 0, 0, 1]`, or the matrix
+    /// is singular.
+    pub fn decompose(&self) -> Option<AffineDecomposition<S>> {
+        if self.row(3) != Vector4::unit_w() {
+            return None;
+        }
+        let mut x = self.x.truncate();
+        let mut y = self.y.truncate();
+        let mut z = self.z.truncate();
+        // the product of the scales is the determinant, so none of them is
+        // close to zero relative to its column if this test passes
+        let linear = Matrix3::from_cols(x, y, z);
+        if is_singular(linear.determinant(), linear.column_length2_product()) {
+            return None;
+        }
+        let mut scale = Vector3::zero();
+        let mut shear = Vector3::zero();
+
+        scale.x = x.length();
+        x = x / scale.x;
+
+        shear.x = x.dot(y);
+        y = y - x * shear.x;
+        scale.y = y.length();
+        y = y / scale.y;
+        shear.x = shear.x / scale.y;
+
+        shear.y = x.dot(z);
+        z = z - x * shear.y;
+        shear.z = y.dot(z);
+        z = z - y * shear.z;
+        scale.z = z.length();
+        z = z / scale.z;
+        shear.y = shear.y / scale.z;
+        shear.z = shear.z / scale.z;
+
+        // move a reflection into the scale
+        if x.cross(y).dot(z) < S::zero() {
+            scale = -scale;
+            x = -x;
+            y = -y;
+            z = -z;
+        }
+
+        Some(AffineDecomposition {
+            translation: self.w.truncate(),
+            rotation: Quaternion::from(Matrix3::from_cols(x, y, z)),
+            shear: shear,
+            scale: scale,
+        })
+    }
+}
@@ -0,0 +1,617 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Heap-allocated vectors and matrices whose dimensions are chosen at
+//! runtime, for problems that don't fit in four dimensions, such as inverse
+//! kinematics Jacobians and least-squares fits.
+//!
+//! `MatN` is stored in column-major order like the fixed-size matrices, and
+//! indexing it by a column gives a slice, so `m[c][r]` is the element in
+//! column `c` and row `r`. The arithmetic operators panic if the dimensions
+//! of their operands don't agree.
+
+use std::fmt;
+use std::ops::*;
+
+use rustc_serialize::{Decodable, Decoder};
+
+use approx::ApproxEq;
+use matrix::is_singular;
+use num::{BaseNum, BaseFloat};
+
+/// A vector with a dimension chosen at runtime.
+#[derive(PartialEq, Clone, RustcEncodable, RustcDecodable)]
+pub struct VecN<S> {
+    components: Vec<S>,
+}
+
+/// A column-major matrix with dimensions chosen at runtime.
+#[derive(PartialEq, Clone, RustcEncodable)]
+pub struct MatN<S> {
+    rows: usize,
+    cols: usize,
+    elements: Vec<S>,
+}
+
+impl<S> VecN<S> {
+    /// Construct a vector from its components.
+    #[inline]
+    pub fn new(components: Vec<S>) -> VecN<S> {
+        VecN { components: components }
+    }
+
+    /// The number of components.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    /// Returns `true` if the vector has no components.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    /// The components as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[S] {
+        &self.components
+    }
+
+    /// The components as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [S] {
+        &mut self.components
+    }
+
+    /// Take the components.
+    #[inline]
+    pub fn into_vec(self) -> Vec<S> {
+        self.components
+    }
+}
+
+impl<S: BaseNum> VecN<S> {
+    /// A vector of `n` zeroes.
+    #[inline]
+    pub fn zero(n: usize) -> VecN<S> {
+        VecN::from_value(n, S::zero())
+    }
+
+    /// A vector of `n` components all set to `value`.
+    #[inline]
+    pub fn from_value(n: usize, value: S) -> VecN<S> {
+        VecN::new(vec![value; n])
+    }
+
+    /// Apply a function to each component.
+    #[inline]
+    pub fn map<F: FnMut(S) -> S>(&self, f: F) -> VecN<S> {
+        VecN::new(self.components.iter().cloned().map(f).collect())
+    }
+
+    /// Combine the corresponding components of two vectors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors have different lengths.
+    pub fn zip<F: FnMut(S, S) -> S>(&self, other: &VecN<S>, mut f: F) -> VecN<S> {
+        assert_eq!(self.len(), other.len(), "vector dimensions do not match");
+        VecN::new(self.components.iter().zip(other.components.iter()).map(|(&a, &b)| f(a, b)).collect())
+    }
+
+    /// The dot product of two vectors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors have different lengths.
+    pub fn dot(&self, other: &VecN<S>) -> S {
+        assert_eq!(self.len(), other.len(), "vector dimensions do not match");
+        self.components.iter().zip(other.components.iter())
+            .fold(S::zero(), |sum, (&a, &b)| sum + a * b)
+    }
+
+    /// The sum of the components.
+    #[inline]
+    pub fn sum(&self) -> S {
+        self.components.iter().fold(S::zero(), |sum, &a| sum + a)
+    }
+}
+
+impl<S: BaseFloat> VecN<S> {
+    /// The squared length of the vector.
+    #[inline]
+    pub fn length2(&self) -> S {
+        self.dot(self)
+    }
+
+    /// The length of the vector.
+    #[inline]
+    pub fn length(&self) -> S {
+        self.length2().sqrt()
+    }
+
+    /// A vector with the same direction, and a length of one.
+    #[inline]
+    pub fn normalize(&self) -> VecN<S> {
+        self / self.length()
+    }
+}
+
+impl<S> Index<usize> for VecN<S> {
+    type Output = S;
+
+    #[inline]
+    fn index<'a>(&'a self, i: usize) -> &'a S {
+        &self.components[i]
+    }
+}
+
+impl<S> IndexMut<usize> for VecN<S> {
+    #[inline]
+    fn index_mut<'a>(&'a mut self, i: usize) -> &'a mut S {
+        &mut self.components[i]
+    }
+}
+
+impl<S> From<Vec<S>> for VecN<S> {
+    #[inline]
+    fn from(components: Vec<S>) -> VecN<S> {
+        VecN::new(components)
+    }
+}
+
+impl_operator!(<S: BaseNum> Add<VecN<S> > for VecN<S> {
+    fn add(lhs, rhs) -> VecN<S> { lhs.zip(&rhs, |a, b| a + b) }
+});
+impl_operator!(<S: BaseNum> Sub<VecN<S> > for VecN<S> {
+    fn sub(lhs, rhs) -> VecN<S> { lhs.zip(&rhs, |a, b| a - b) }
+});
+impl_operator!(<S: BaseNum> Mul<S> for VecN<S> {
+    fn mul(vector, scalar) -> VecN<S> { vector.map(|a| a * scalar) }
+});
+impl_operator!(<S: BaseNum> Div<S> for VecN<S> {
+    fn div(vector, scalar) -> VecN<S> { vector.map(|a| a / scalar) }
+});
+
+impl<S> MatN<S> {
+    /// Construct a matrix from its elements in column-major order. Returns
+    /// `None` if there are not `rows * cols` elements.
+    pub fn from_column_major(rows: usize, cols: usize, elements: Vec<S>) -> Option<MatN<S>> {
+        if rows.checked_mul(cols) == Some(elements.len()) {
+            Some(MatN { rows: rows, cols: cols, elements: elements })
+        } else {
+            None
+        }
+    }
+
+    /// Construct a matrix by calling `f(row, col)` for each element.
+    pub fn from_fn<F: FnMut(usize, usize) -> S>(rows: usize, cols: usize, mut f: F) -> MatN<S> {
+        let mut elements = Vec::with_capacity(rows * cols);
+        for c in 0..cols {
+            for r in 0..rows {
+                elements.push(f(r, c));
+            }
+        }
+        MatN { rows: rows, cols: cols, elements: elements }
+    }
+
+    /// The number of rows.
+    #[inline]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// The number of columns.
+    #[inline]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns `true` if the matrix has as many rows as columns.
+    #[inline]
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// The elements in column-major order.
+    #[inline]
+    pub fn as_slice(&self) -> &[S] {
+        &self.elements
+    }
+
+    /// The elements in column-major order, as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [S] {
+        &mut self.elements
+    }
+
+    /// Swap two rows of the matrix.
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        for c in 0..self.cols {
+            self.elements.swap(c * self.rows + a, c * self.rows + b);
+        }
+    }
+
+    /// Swap two columns of the matrix.
+    pub fn swap_columns(&mut self, a: usize, b: usize) {
+        for r in 0..self.rows {
+            self.elements.swap(a * self.rows + r, b * self.rows + r);
+        }
+    }
+
+    /// Swap the elements at `(col, row)` indices `a` and `b`.
+    #[inline]
+    pub fn swap_elements(&mut self, a: (usize, usize), b: (usize, usize)) {
+        let (ac, ar) = a;
+        let (bc, br) = b;
+        self.elements.swap(ac * self.rows + ar, bc * self.rows + br);
+    }
+}
+
+impl<S: BaseNum> MatN<S> {
+    /// A matrix of zeroes.
+    #[inline]
+    pub fn zero(rows: usize, cols: usize) -> MatN<S> {
+        MatN { rows: rows, cols: cols, elements: vec![S::zero(); rows * cols] }
+    }
+
+    /// The `n` by `n` identity matrix.
+    #[inline]
+    pub fn identity(n: usize) -> MatN<S> {
+        MatN::from_value(n, S::one())
+    }
+
+    /// An `n` by `n` diagonal matrix with `value` on the diagonal.
+    #[inline]
+    pub fn from_value(n: usize, value: S) -> MatN<S> {
+        MatN::from_fn(n, n, |r, c| if r == c { value } else { S::zero() })
+    }
+
+    /// A square diagonal matrix.
+    #[inline]
+    pub fn from_diagonal(diagonal: &VecN<S>) -> MatN<S> {
+        MatN::from_fn(diagonal.len(), diagonal.len(), |r, c| if r == c { diagonal[r] } else { S::zero() })
+    }
+
+    /// Construct a matrix from its columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the columns have different lengths.
+    pub fn from_cols(cols: &[VecN<S>]) -> MatN<S> {
+        let rows = cols.first().map_or(0, |c| c.len());
+        let mut elements = Vec::with_capacity(rows * cols.len());
+        for c in cols {
+            assert_eq!(c.len(), rows, "column dimensions do not match");
+            elements.extend_from_slice(c.as_slice());
+        }
+        MatN { rows: rows, cols: cols.len(), elements: elements }
+    }
+
+    /// A column of the matrix.
+    #[inline]
+    pub fn col(&self, c: usize) -> VecN<S> {
+        VecN::new(self[c].to_vec())
+    }
+
+    /// A row of the matrix.
+    #[inline]
+    pub fn row(&self, r: usize) -> VecN<S> {
+        VecN::new((0..self.cols).map(|c| self[c][r]).collect())
+    }
+
+    /// The transpose of the matrix.
+    pub fn transpose(&self) -> MatN<S> {
+        MatN::from_fn(self.cols, self.rows, |r, c| self[r][c])
+    }
+
+    /// The diagonal of the matrix.
+    pub fn diagonal(&self) -> VecN<S> {
+        VecN::new((0..self.rows.min(self.cols)).map(|i| self[i][i]).collect())
+    }
+
+    /// The sum of the diagonal.
+    #[inline]
+    pub fn trace(&self) -> S {
+        self.diagonal().sum()
+    }
+
+    /// Apply a function to each element.
+    #[inline]
+    pub fn map<F: FnMut(S) -> S>(&self, f: F) -> MatN<S> {
+        MatN {
+            rows: self.rows,
+            cols: self.cols,
+            elements: self.elements.iter().cloned().map(f).collect(),
+        }
+    }
+
+    fn zip<F: FnMut(S, S) -> S>(&self, other: &MatN<S>, mut f: F) -> MatN<S> {
+        assert!(self.rows == other.rows && self.cols == other.cols, "matrix dimensions do not match");
+        MatN {
+            rows: self.rows,
+            cols: self.cols,
+            elements: self.elements.iter().zip(other.elements.iter()).map(|(&a, &b)| f(a, b)).collect(),
+        }
+    }
+
+    fn mul_vector(&self, v: &VecN<S>) -> VecN<S> {
+        assert_eq!(self.cols, v.len(), "matrix and vector dimensions do not match");
+        let mut result = VecN::zero(self.rows);
+        for c in 0..self.cols {
+            for r in 0..self.rows {
+                result[r] = result[r] + self[c][r] * v[c];
+            }
+        }
+        result
+    }
+
+    fn mul_matrix(&self, other: &MatN<S>) -> MatN<S> {
+        assert_eq!(self.cols, other.rows, "matrix dimensions do not match");
+        let mut result = MatN::zero(self.rows, other.cols);
+        for c in 0..other.cols {
+            for k in 0..self.cols {
+                let factor = other[c][k];
+                for r in 0..self.rows {
+                    result[c][r] = result[c][r] + self[k][r] * factor;
+                }
+            }
+        }
+        result
+    }
+}
+
+/// The LU decomposition of a square matrix with partial pivoting, with `L`
+/// and `U` packed into one matrix, the row permutation, and its parity.
+struct PackedLu<S> {
+    lu: MatN<S>,
+    perm: Vec<usize>,
+    odd: bool,
+}
+
+impl<S: BaseFloat> PackedLu<S> {
+    fn solve(&self, b: &VecN<S>) -> VecN<S> {
+        let n = self.perm.len();
+        let mut x = VecN::new(self.perm.iter().map(|&p| b[p]).collect());
+        for i in 0..n {
+            for j in 0..i {
+                x[i] = x[i] - self.lu[j][i] * x[j];
+            }
+        }
+        for i in (0..n).rev() {
+            for j in (i + 1)..n {
+                x[i] = x[i] - self.lu[j][i] * x[j];
+            }
+            x[i] = x[i] / self.lu[i][i];
+        }
+        x
+    }
+
+    fn determinant(&self) -> S {
+        let det = (0..self.perm.len()).fold(S::one(), |det, i| det * self.lu[i][i]);
+        if self.odd { -det } else { det }
+    }
+}
+
+impl<S: BaseFloat> MatN<S> {
+    fn packed_lu(&self) -> Option<PackedLu<S>> {
+        assert!(self.is_square(), "matrix is not square");
+        let n = self.rows;
+        let mut lu = self.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut odd = false;
+
+        for k in 0..n {
+            // choose the largest remaining pivot in column k
+            let mut p = k;
+            for r in (k + 1)..n {
+                if lu[k][r].abs() > lu[k][p].abs() {
+                    p = r;
+                }
+            }
+            if lu[k][p] == S::zero() {
+                return None;
+            }
+            if p != k {
+                lu.swap_rows(k, p);
+                perm.swap(k, p);
+                odd = !odd;
+            }
+
+            for r in (k + 1)..n {
+                let factor = lu[k][r] / lu[k][k];
+                lu[k][r] = factor;
+                for c in (k + 1)..n {
+                    lu[c][r] = lu[c][r] - factor * lu[c][k];
+                }
+            }
+        }
+        Some(PackedLu { lu: lu, perm: perm, odd: odd })
+    }
+
+    /// The LU decomposition, or `None` if the matrix is singular by the same
+    /// scale-independent test as the fixed-size matrices use in `invert`.
+    fn invertible_lu(&self) -> Option<PackedLu<S>> {
+        self.packed_lu().and_then(|lu| {
+            let column_length2_product = (0..self.cols)
+                .map(|c| self[c].iter().fold(S::zero(), |sum, &x| sum + x * x))
+                .fold(S::one(), |product, length2| product * length2);
+            if is_singular(lu.determinant(), column_length2_product) { None } else { Some(lu) }
+        })
+    }
+
+    /// The determinant of the matrix, computed from its LU decomposition.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn determinant(&self) -> S {
+        match self.packed_lu() {
+            Some(lu) => lu.determinant(),
+            None => S::zero(),
+        }
+    }
+
+    /// The inverse of the matrix. Returns `None` if it is singular, or so
+    /// close to singular relative to its scale that the inverse would be
+    /// meaningless, like `SquareMatrix::invert`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn invert(&self) -> Option<MatN<S>> {
+        self.invertible_lu().map(|lu| {
+            let n = self.rows;
+            let mut inverse = MatN::zero(n, n);
+            for c in 0..n {
+                let mut e = VecN::zero(n);
+                e[c] = S::one();
+                let x = lu.solve(&e);
+                inverse[c].clone_from_slice(x.as_slice());
+            }
+            inverse
+        })
+    }
+
+    /// Returns `true` if the matrix is square and `invert` would succeed.
+    #[inline]
+    pub fn is_invertible(&self) -> bool {
+        self.is_square() && self.invertible_lu().is_some()
+    }
+
+    /// Solve the linear system `self * x = b` for `x`. Returns `None` if the
+    /// matrix is singular, by the same test as `invert`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square, or `b` has the wrong dimension.
+    pub fn solve(&self, b: &VecN<S>) -> Option<VecN<S>> {
+        assert_eq!(self.rows, b.len(), "matrix and vector dimensions do not match");
+        self.invertible_lu().map(|lu| lu.solve(b))
+    }
+}
+
+impl<S> Index<usize> for MatN<S> {
+    type Output = [S];
+
+    #[inline]
+    fn index<'a>(&'a self, c: usize) -> &'a [S] {
+        assert!(c < self.cols, "column index out of bounds");
+        &self.elements[c * self.rows..(c + 1) * self.rows]
+    }
+}
+
+impl<S> IndexMut<usize> for MatN<S> {
+    #[inline]
+    fn index_mut<'a>(&'a mut self, c: usize) -> &'a mut [S] {
+        assert!(c < self.cols, "column index out of bounds");
+        &mut self.elements[c * self.rows..(c + 1) * self.rows]
+    }
+}
+
+impl_operator!(<S: BaseNum> Add<MatN<S> > for MatN<S> {
+    fn add(lhs, rhs) -> MatN<S> { lhs.zip(&rhs, |a, b| a + b) }
+});
+impl_operator!(<S: BaseNum> Sub<MatN<S> > for MatN<S> {
+    fn sub(lhs, rhs) -> MatN<S> { lhs.zip(&rhs, |a, b| a - b) }
+});
+impl_operator!(<S: BaseNum> Mul<S> for MatN<S> {
+    fn mul(matrix, scalar) -> MatN<S> { matrix.map(|a| a * scalar) }
+});
+impl_operator!(<S: BaseNum> Div<S> for MatN<S> {
+    fn div(matrix, scalar) -> MatN<S> { matrix.map(|a| a / scalar) }
+});
+impl_operator!(<S: BaseNum> Mul<VecN<S> > for MatN<S> {
+    fn mul(lhs, rhs) -> VecN<S> { lhs.mul_vector(&rhs) }
+});
+impl_operator!(<S: BaseNum> Mul<MatN<S> > for MatN<S> {
+    fn mul(lhs, rhs) -> MatN<S> { lhs.mul_matrix(&rhs) }
+});
+
+impl<S: BaseFloat> ApproxEq for VecN<S> {
+    type Epsilon = S;
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &VecN<S>, epsilon: &S) -> bool {
+        self.len() == other.len() &&
+        self.components.iter().zip(other.components.iter()).all(|(a, b)| a.approx_eq_eps(b, epsilon))
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &VecN<S>, epsilon: &S, max_relative: &S) -> bool {
+        self.len() == other.len() &&
+        self.components.iter().zip(other.components.iter()).all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &VecN<S>, epsilon: &S, max_ulps: u32) -> bool {
+        self.len() == other.len() &&
+        self.components.iter().zip(other.components.iter()).all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+impl<S: BaseFloat> ApproxEq for MatN<S> {
+    type Epsilon = S;
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &MatN<S>, epsilon: &S) -> bool {
+        self.rows == other.rows && self.cols == other.cols &&
+        self.elements.iter().zip(other.elements.iter()).all(|(a, b)| a.approx_eq_eps(b, epsilon))
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &MatN<S>, epsilon: &S, max_relative: &S) -> bool {
+        self.rows == other.rows && self.cols == other.cols &&
+        self.elements.iter().zip(other.elements.iter()).all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &MatN<S>, epsilon: &S, max_ulps: u32) -> bool {
+        self.rows == other.rows && self.cols == other.cols &&
+        self.elements.iter().zip(other.elements.iter()).all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for VecN<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VecN({:?})", self.components)
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for MatN<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MatN {}x{} [", self.rows, self.cols)?;
+        for (c, col) in self.elements.chunks(self.rows.max(1)).enumerate() {
+            if c > 0 { write!(f, ", ")?; }
+            write!(f, "{:?}", col)?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// Decoding checks that the number of elements matches the dimensions, as
+/// `from_column_major` does.
+impl<S: Decodable> Decodable for MatN<S> {
+    fn decode<D: Decoder>(d: &mut D) -> Result<MatN<S>, D::Error> {
+        d.read_struct("MatN", 3, |d| {
+            let rows = d.read_struct_field("rows", 0, Decodable::decode)?;
+            let cols = d.read_struct_field("cols", 1, Decodable::decode)?;
+            let elements = d.read_struct_field("elements", 2, Decodable::decode)?;
+            MatN::from_column_major(rows, cols, elements)
+                .ok_or_else(|| d.error("the number of elements does not match the dimensions"))
+        })
+    }
+}
@@ -0,0 +1,214 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Euler angles with an explicit rotation order.
+
+use std::fmt;
+
+use angle::{Angle, Rad};
+use approx::ApproxEq;
+use matrix::Matrix3;
+use num::BaseFloat;
+use quaternion::Quaternion;
+use rotation::Rotation3;
+
+/// The order in which the rotations of a set of Euler angles are applied.
+///
+/// The rotations are applied one after another, in the order listed, about
+/// the fixed axes of the parent space. For example, `XYZ` rotates about the
+/// `x` axis first and the `z` axis last, which is the rotation matrix
+/// `Rz * Ry * Rx`. This is equivalent to rotating about the axes of the
+/// rotated frame in the reverse order (`ZYX` intrinsic rotations).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, RustcEncodable, RustcDecodable)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+}
+
+impl EulerOrder {
+    /// The indices of the axes in the order that their rotations are applied.
+    #[inline]
+    pub fn axes(self) -> (usize, usize, usize) {
+        match self {
+            EulerOrder::XYZ => (0, 1, 2),
+            EulerOrder::XZY => (0, 2, 1),
+            EulerOrder::YXZ => (1, 0, 2),
+            EulerOrder::YZX => (1, 2, 0),
+            EulerOrder::ZXY => (2, 0, 1),
+            EulerOrder::ZYX => (2, 1, 0),
+        }
+    }
+
+    /// Whether the axes are an even permutation of `x`, `y`, `z`.
+    #[inline]
+    fn is_even(self) -> bool {
+        match self {
+            EulerOrder::XYZ | EulerOrder::YZX | EulerOrder::ZXY => true,
+            EulerOrder::XZY | EulerOrder::YXZ | EulerOrder::ZYX => false,
+        }
+    }
+}
+
+/// A set of Euler angles, along with the order that they are applied in.
+///
+/// Storing the order with the angles avoids mistakes when exchanging
+/// rotations between applications that use different conventions.
+#[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+pub struct Euler<S> {
+    /// The rotation around the `x` axis.
+    pub pitch: Rad<S>,
+    /// The rotation around the `y` axis.
+    pub yaw: Rad<S>,
+    /// The rotation around the `z` axis.
+    pub roll: Rad<S>,
+    /// The order that the rotations are applied in.
+    pub order: EulerOrder,
+}
+
+impl<S: BaseFloat> Euler<S> {
+    /// Construct a set of Euler angles.
+    #[inline]
+    pub fn new(pitch: Rad<S>, yaw: Rad<S>, roll: Rad<S>, order: EulerOrder) -> Euler<S> {
+        Euler { pitch: pitch, yaw: yaw, roll: roll, order: order }
+    }
+
+    /// The angle of the rotation around the axis with the given index.
+    #[inline]
+    fn angle(&self, axis: usize) -> Rad<S> {
+        match axis {
+            0 => self.pitch,
+            1 => self.yaw,
+            2 => self.roll,
+            _ => panic!("{:?} is out of range", axis),
+        }
+    }
+
+    /// Decompose a rotation matrix into Euler angles applied in `order`.
+    ///
+    /// The middle rotation is in the range `[-π/2, π/2]`, and the other two
+    /// are in the range `[-π, π]`.
+    ///
+    /// When the middle rotation is a quarter turn the first and last axes
+    /// line up (gimbal lock), and only their combined rotation can be
+    /// recovered. In that case the last rotation is set to zero.
+    pub fn from_matrix(mat: Matrix3<S>, order: EulerOrder) -> Euler<S> {
+        let (i, j, k) = order.axes();
+        let sign = if order.is_even() { S::one() } else { -S::one() };
+        // element at row `r`, column `c`
+        let m = |r: usize, c: usize| mat[c][r];
+
+        let sin_b = (-sign * m(k, i)).max(-S::one()).min(S::one());
+        let b = Rad::asin(sin_b);
+        let (a, c) = if sin_b.abs().approx_eq(&S::one()) {
+            (Rad::atan2(-sign * m(j, k), m(j, j)), Rad::zero())
+        } else {
+            (Rad::atan2(sign * m(k, j), m(k, k)), Rad::atan2(sign * m(j, i), m(i, i)))
+        };
+
+        let mut angles = [Rad::zero(); 3];
+        angles[i] = a;
+        angles[j] = b;
+        angles[k] = c;
+        Euler::new(angles[0], angles[1], angles[2], order)
+    }
+
+    /// Decompose a unit quaternion into Euler angles applied in `order`.
+    #[inline]
+    pub fn from_quaternion(quat: Quaternion<S>, order: EulerOrder) -> Euler<S> {
+        Euler::from_matrix(quat.into(), order)
+    }
+
+    /// Convert the angles to be applied in a different order, preserving the
+    /// rotation that they represent.
+    #[inline]
+    pub fn reorder(self, order: EulerOrder) -> Euler<S> {
+        Euler::from_matrix(self.into(), order)
+    }
+}
+
+fn matrix_about_axis<S: BaseFloat>(axis: usize, angle: Rad<S>) -> Matrix3<S> {
+    match axis {
+        0 => Matrix3::from_angle_x(angle),
+        1 => Matrix3::from_angle_y(angle),
+        2 => Matrix3::from_angle_z(angle),
+        _ => panic!("{:?} is out of range", axis),
+    }
+}
+
+fn quaternion_about_axis<S: BaseFloat>(axis: usize, angle: Rad<S>) -> Quaternion<S> {
+    match axis {
+        0 => Quaternion::from_angle_x(angle),
+        1 => Quaternion::from_angle_y(angle),
+        2 => Quaternion::from_angle_z(angle),
+        _ => panic!("{:?} is out of range", axis),
+    }
+}
+
+impl<S: BaseFloat> From<Euler<S>> for Matrix3<S> {
+    fn from(euler: Euler<S>) -> Matrix3<S> {
+        let (i, j, k) = euler.order.axes();
+        matrix_about_axis(k, euler.angle(k)) *
+        matrix_about_axis(j, euler.angle(j)) *
+        matrix_about_axis(i, euler.angle(i))
+    }
+}
+
+impl<S: BaseFloat> From<Euler<S>> for Quaternion<S> {
+    fn from(euler: Euler<S>) -> Quaternion<S> {
+        let (i, j, k) = euler.order.axes();
+        quaternion_about_axis(k, euler.angle(k)) *
+        quaternion_about_axis(j, euler.angle(j)) *
+        quaternion_about_axis(i, euler.angle(i))
+    }
+}
+
+impl<S: BaseFloat> ApproxEq for Euler<S> {
+    type Epsilon = S;
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Euler<S>, epsilon: &S) -> bool {
+        self.order == other.order &&
+        self.pitch.approx_eq_eps(&other.pitch, epsilon) &&
+        self.yaw.approx_eq_eps(&other.yaw, epsilon) &&
+        self.roll.approx_eq_eps(&other.roll, epsilon)
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Euler<S>, epsilon: &S, max_relative: &S) -> bool {
+        self.order == other.order &&
+        self.pitch.relative_eq(&other.pitch, epsilon, max_relative) &&
+        self.yaw.relative_eq(&other.yaw, epsilon, max_relative) &&
+        self.roll.relative_eq(&other.roll, epsilon, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Euler<S>, epsilon: &S, max_ulps: u32) -> bool {
+        self.order == other.order &&
+        self.pitch.ulps_eq(&other.pitch, epsilon, max_ulps) &&
+        self.yaw.ulps_eq(&other.yaw, epsilon, max_ulps) &&
+        self.roll.ulps_eq(&other.roll, epsilon, max_ulps)
+    }
+}
+
+impl<S: BaseFloat> fmt::Debug for Euler<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Euler {{ pitch: {:?}, yaw: {:?}, roll: {:?}, order: {:?} }}",
+               self.pitch, self.yaw, self.roll, self.order)
+    }
+}
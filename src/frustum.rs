@@ -0,0 +1,150 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use aabb::Aabb3;
+use matrix::{Matrix, Matrix4};
+use num::BaseFloat;
+use plane::Plane;
+use point::Point3;
+use projection::DepthRange;
+use sphere::Sphere;
+use vector::{Vector3, Vector4};
+
+/// The spatial relationship between a bounding volume and a region of space.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, RustcEncodable, RustcDecodable)]
+pub enum Relation {
+    /// The volume is completely inside the region.
+    Inside,
+    /// The volume is partly inside and partly outside the region.
+    Intersecting,
+    /// The volume is completely outside the region.
+    Outside,
+}
+
+/// A view frustum, bounded by six planes with normals pointing into the
+/// frustum.
+#[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+pub struct Frustum<S> {
+    pub left:   Plane<S>,
+    pub right:  Plane<S>,
+    pub bottom: Plane<S>,
+    pub top:    Plane<S>,
+    pub near:   Plane<S>,
+    pub far:    Plane<S>,
+}
+
+impl<S: BaseFloat> Frustum<S> {
+    /// Create a frustum from its bounding planes.
+    #[inline]
+    pub fn new(left: Plane<S>, right: Plane<S>,
+               bottom: Plane<S>, top: Plane<S>,
+               near: Plane<S>, far: Plane<S>) -> Frustum<S> {
+        Frustum {
+            left:   left,
+            right:  right,
+            bottom: bottom,
+            top:    top,
+            near:   near,
+            far:    far,
+        }
+    }
+
+    /// Extract the frustum of a combined view-projection matrix, whose
+    /// normalized device depth range is `depth`. The planes are in the space
+    /// that the matrix transforms from, so for a projection matrix they are
+    /// in eye space and for `proj * view` they are in world space.
+    ///
+    /// This is the method from [Fast Extraction of Viewing Frustum Planes
+    /// from the World-View-Projection Matrix]
+    /// (http://www.cs.otago.ac.nz/postgrads/alexis/planeExtraction.pdf) by
+    /// Gribb and Hartmann. The far plane of a projection with an infinitely
+    /// distant far plane contains every point.
+    pub fn from_matrix4(mat: Matrix4<S>, depth: DepthRange) -> Frustum<S> {
+        let (r0, r1, r2, r3) = (mat.row(0), mat.row(1), mat.row(2), mat.row(3));
+        let (near, far) = match depth {
+            DepthRange::NegativeOneToOne => (r3 + r2, r3 - r2),
+            DepthRange::ZeroToOne => (r2, r3 - r2),
+            DepthRange::ReversedZeroToOne => (r3 - r2, r2),
+        };
+        Frustum::new(plane_from_row(r3 + r0), plane_from_row(r3 - r0),
+                     plane_from_row(r3 + r1), plane_from_row(r3 - r1),
+                     plane_from_row(near), plane_from_row(far))
+    }
+
+    /// The bounding planes of the frustum.
+    #[inline]
+    pub fn planes(&self) -> [Plane<S>; 6] {
+        [self.left, self.right, self.bottom, self.top, self.near, self.far]
+    }
+
+    /// Whether the point is inside the frustum or on its boundary.
+    pub fn contains(&self, point: Point3<S>) -> bool {
+        self.planes().iter().all(|plane| plane.distance(point) >= S::zero())
+    }
+
+    /// Find how `sphere` relates to the frustum.
+    pub fn relate_sphere(&self, sphere: &Sphere<S>) -> Relation {
+        let mut relation = Relation::Inside;
+        for plane in self.planes().iter() {
+            let distance = plane.distance(sphere.center);
+            if distance < -sphere.radius {
+                return Relation::Outside;
+            } else if distance < sphere.radius {
+                relation = Relation::Intersecting;
+            }
+        }
+        relation
+    }
+
+    /// Find how `aabb` relates to the frustum.
+    ///
+    /// This tests the box against each plane in turn, so a box near a corner
+    /// of the frustum can be reported as `Intersecting` when it is actually
+    /// outside. This is conservative for culling.
+    pub fn relate_aabb(&self, aabb: &Aabb3<S>) -> Relation {
+        let mut relation = Relation::Inside;
+        for plane in self.planes().iter() {
+            // the corners furthest along and against the plane normal
+            let positive = Point3::new(if plane.n.x >= S::zero() { aabb.max.x } else { aabb.min.x },
+                                       if plane.n.y >= S::zero() { aabb.max.y } else { aabb.min.y },
+                                       if plane.n.z >= S::zero() { aabb.max.z } else { aabb.min.z });
+            let negative = Point3::new(if plane.n.x >= S::zero() { aabb.min.x } else { aabb.max.x },
+                                       if plane.n.y >= S::zero() { aabb.min.y } else { aabb.max.y },
+                                       if plane.n.z >= S::zero() { aabb.min.z } else { aabb.max.z });
+            if plane.distance(positive) < S::zero() {
+                return Relation::Outside;
+            } else if plane.distance(negative) < S::zero() {
+                relation = Relation::Intersecting;
+            }
+        }
+        relation
+    }
+}
+
+/// The plane of points where `row.dot(p) >= 0`, scaled to a unit normal so
+/// that distances are true distances.
+fn plane_from_row<S: BaseFloat>(row: Vector4<S>) -> Plane<S> {
+    let plane = Plane::new(Vector3::new(row.x, row.y, row.z), -row.w);
+    plane.normalize().unwrap_or(plane)
+}
+
+impl<S: BaseFloat> fmt::Debug for Frustum<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Frustum({:?}, {:?}, {:?}, {:?}, {:?}, {:?})",
+               self.left, self.right, self.bottom, self.top, self.near, self.far)
+    }
+}
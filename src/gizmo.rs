@@ -0,0 +1,106 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Constraint projections for interactive transform manipulators.
+//!
+//! An editor gizmo typically records the mouse ray when a drag begins, and
+//! then on each update converts the current mouse ray into a translation or
+//! rotation that is constrained to one of the object's axes or planes. The
+//! `origin` and `axis`/`normal` parameters are in world space, so for an
+//! object with a transform `t` they can be found using
+//! `t.transform_point(Point3::origin())` and
+//! `t.transform_vector(Vector3::unit_x())`, for example.
+
+use angle::{Angle, Rad};
+use num::BaseFloat;
+use plane::Plane;
+use point::Point3;
+use ray::Ray3;
+use vector::{Vector, Vector3, EuclideanVector};
+
+/// Find the point on the line through `origin` along `axis` that is closest
+/// to `ray`.
+///
+/// Returns `None` if the ray is parallel to the axis, or if the closest point
+/// lies behind the origin of the ray.
+pub fn project_ray_onto_axis<S: BaseFloat>(ray: Ray3<S>, origin: Point3<S>, axis: Vector3<S>) -> Option<Point3<S>> {
+    let u = axis.normalize();
+    let v = ray.direction.normalize();
+    let w = origin - ray.origin;
+
+    let b = u.dot(v);
+    let denom = S::one() - b * b;
+    if denom.approx_eq(&S::zero()) {
+        return None;
+    }
+
+    let d = u.dot(w);
+    let e = v.dot(w);
+    let s = (b * e - d) / denom;
+    let t = (e - b * d) / denom;
+    if t < S::zero() {
+        None
+    } else {
+        Some(origin + u * s)
+    }
+}
+
+/// Find the point where `ray` intersects the plane through `origin` with the
+/// given `normal`.
+///
+/// Returns `None` if the ray is parallel to the plane, or if the plane lies
+/// behind the origin of the ray.
+pub fn project_ray_onto_plane<S: BaseFloat>(ray: Ray3<S>, origin: Point3<S>, normal: Vector3<S>) -> Option<Point3<S>> {
+    ray.intersect_plane(&Plane::from_point_normal(origin, normal)).map(|(_, point)| point)
+}
+
+/// The translation along `axis` caused by dragging the mouse from `from` to
+/// `to`.
+pub fn axis_translation<S: BaseFloat>(from: Ray3<S>, to: Ray3<S>, origin: Point3<S>, axis: Vector3<S>) -> Option<Vector3<S>> {
+    match (project_ray_onto_axis(from, origin, axis), project_ray_onto_axis(to, origin, axis)) {
+        (Some(a), Some(b)) => Some(b - a),
+        _ => None,
+    }
+}
+
+/// The translation within the plane with the given `normal` caused by
+/// dragging the mouse from `from` to `to`.
+pub fn plane_translation<S: BaseFloat>(from: Ray3<S>, to: Ray3<S>, origin: Point3<S>, normal: Vector3<S>) -> Option<Vector3<S>> {
+    match (project_ray_onto_plane(from, origin, normal), project_ray_onto_plane(to, origin, normal)) {
+        (Some(a), Some(b)) => Some(b - a),
+        _ => None,
+    }
+}
+
+/// The counter-clockwise rotation around `axis` caused by dragging the mouse
+/// from `from` to `to`.
+///
+/// Both rays are projected onto the plane through `origin` perpendicular to
+/// `axis`, and the angle between the projected points is measured around
+/// `origin`. Returns `None` if either ray misses the plane, or lands on
+/// `origin` itself.
+pub fn axis_rotation<S: BaseFloat>(from: Ray3<S>, to: Ray3<S>, origin: Point3<S>, axis: Vector3<S>) -> Option<Rad<S>> {
+    match (project_ray_onto_plane(from, origin, axis), project_ray_onto_plane(to, origin, axis)) {
+        (Some(a), Some(b)) => {
+            let (a, b) = (a - origin, b - origin);
+            if a.length2().approx_eq(&S::zero()) || b.length2().approx_eq(&S::zero()) {
+                None
+            } else {
+                Some(Rad::atan2(axis.normalize().dot(a.cross(b)), a.dot(b)))
+            }
+        }
+        _ => None,
+    }
+}
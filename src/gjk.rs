@@ -0,0 +1,237 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Support mappings of convex shapes, and the Gilbert-Johnson-Keerthi (GJK)
+//! intersection test built on them.
+
+use aabb::Aabb3;
+use capsule::Capsule3;
+use cylinder::Cylinder3;
+use num::BaseFloat;
+use obb::Obb3;
+use point::{Point, Point3};
+use sphere::Sphere;
+use vector::{Vector, Vector3, EuclideanVector};
+
+/// A convex shape that can report its furthest point in any direction.
+pub trait SupportMap<S: BaseFloat> {
+    /// The point of the shape that is furthest along `dir`. The direction
+    /// does not need to be normalized.
+    fn support(&self, dir: &Vector3<S>) -> Point3<S>;
+}
+
+impl<S: BaseFloat> SupportMap<S> for Point3<S> {
+    #[inline]
+    fn support(&self, _: &Vector3<S>) -> Point3<S> {
+        *self
+    }
+}
+
+impl<S: BaseFloat> SupportMap<S> for Sphere<S> {
+    #[inline]
+    fn support(&self, dir: &Vector3<S>) -> Point3<S> {
+        if dir.length2() == S::zero() {
+            self.center
+        } else {
+            self.center + dir.normalize_to(self.radius)
+        }
+    }
+}
+
+impl<S: BaseFloat> SupportMap<S> for Aabb3<S> {
+    #[inline]
+    fn support(&self, dir: &Vector3<S>) -> Point3<S> {
+        Point3::new(if dir.x < S::zero() { self.min.x } else { self.max.x },
+                    if dir.y < S::zero() { self.min.y } else { self.max.y },
+                    if dir.z < S::zero() { self.min.z } else { self.max.z })
+    }
+}
+
+impl<S: BaseFloat> SupportMap<S> for Obb3<S> {
+    fn support(&self, dir: &Vector3<S>) -> Point3<S> {
+        let mut point = self.center;
+        for i in 0..3 {
+            let axis = self.axes[i] * self.half_extents[i];
+            point = point + if axis.dot(*dir) < S::zero() { -axis } else { axis };
+        }
+        point
+    }
+}
+
+impl<S: BaseFloat> SupportMap<S> for Capsule3<S> {
+    #[inline]
+    fn support(&self, dir: &Vector3<S>) -> Point3<S> {
+        let end = if self.start.dot(*dir) < self.end.dot(*dir) { self.end } else { self.start };
+        Sphere::new(end, self.radius).support(dir)
+    }
+}
+
+impl<S: BaseFloat> SupportMap<S> for Cylinder3<S> {
+    fn support(&self, dir: &Vector3<S>) -> Point3<S> {
+        let end = if self.start.dot(*dir) < self.end.dot(*dir) { self.end } else { self.start };
+        // project twice, so that the rounding error left along the axis is
+        // relative to the radial part rather than to `dir`, and treat what is
+        // left of directions along the axis as rounding error
+        let radial = self.radial(self.radial(*dir));
+        if radial.length2() <= S::epsilon() * S::epsilon() * dir.length2() {
+            end
+        } else {
+            end + radial.normalize_to(self.radius)
+        }
+    }
+}
+
+/// The convex hull of a set of points.
+impl<S: BaseFloat> SupportMap<S> for [Point3<S>] {
+    /// # Panics
+    ///
+    /// Panics if there are no points.
+    fn support(&self, dir: &Vector3<S>) -> Point3<S> {
+        let mut best = self[0];
+        let mut best_dot = best.dot(*dir);
+        for &p in self[1..].iter() {
+            let d = p.dot(*dir);
+            if d > best_dot {
+                best = p;
+                best_dot = d;
+            }
+        }
+        best
+    }
+}
+
+/// The maximum number of refinement steps of `gjk_intersects`.
+const MAX_ITERATIONS: usize = 64;
+
+/// The support point of the Minkowski difference `a - b`.
+#[inline]
+fn minkowski_support<S, A: ?Sized, B: ?Sized>(a: &A, b: &B, dir: Vector3<S>) -> Vector3<S>
+    where S: BaseFloat, A: SupportMap<S>, B: SupportMap<S>
+{
+    a.support(&dir) - b.support(&-dir)
+}
+
+/// Test whether two convex shapes overlap, using the [Gilbert-Johnson-Keerthi
+/// algorithm](https://en.wikipedia.org/wiki/Gilbert%E2%80%93Johnson%E2%80%93Keerthi_distance_algorithm).
+///
+/// An overlap is only reported once the search has enclosed the origin in the
+/// Minkowski difference of the shapes. If it doesn't converge, or its simplex
+/// becomes degenerate, `false` is returned, so shapes that only touch or
+/// nearly touch may be reported either way.
+pub fn gjk_intersects<S, A: ?Sized, B: ?Sized>(a: &A, b: &B) -> bool
+    where S: BaseFloat, A: SupportMap<S>, B: SupportMap<S>
+{
+    let mut dir = Vector3::unit_x();
+    let mut simplex = vec![minkowski_support(a, b, dir)];
+    dir = -simplex[0];
+
+    for _ in 0..MAX_ITERATIONS {
+        if dir.length2() == S::zero() {
+            // the origin lies on the simplex
+            return true;
+        }
+        let point = minkowski_support(a, b, dir);
+        if point.dot(dir) < S::zero() {
+            // the furthest point towards the origin doesn't reach it
+            return false;
+        }
+        simplex.push(point);
+        if let Some(result) = refine_simplex(&mut simplex, &mut dir) {
+            return result;
+        }
+    }
+    // only nearly touching configurations fail to converge
+    false
+}
+
+/// Reduce the simplex to the feature closest to the origin, and update the
+/// search direction to point from it towards the origin. The most recently
+/// added point is last. Returns `Some(true)` if the simplex encloses the
+/// origin, `Some(false)` if it is degenerate, so the search can't continue,
+/// and `None` otherwise.
+fn refine_simplex<S: BaseFloat>(simplex: &mut Vec<Vector3<S>>, dir: &mut Vector3<S>) -> Option<bool> {
+    let a = simplex[simplex.len() - 1];
+    let ao = -a;
+    match simplex.len() {
+        2 => {
+            let b = simplex[0];
+            refine_line(simplex, dir, a, b);
+            None
+        }
+        3 => {
+            let (c, b) = (simplex[0], simplex[1]);
+            refine_triangle(simplex, dir, a, b, c)
+        }
+        _ => {
+            let (d, c, b) = (simplex[0], simplex[1], simplex[2]);
+            let (ab, ac, ad) = (b - a, c - a, d - a);
+            if ab.cross(ac).dot(ad) == S::zero() {
+                Some(false)
+            } else if ab.cross(ac).dot(ao) > S::zero() {
+                refine_triangle(simplex, dir, a, b, c)
+            } else if ac.cross(ad).dot(ao) > S::zero() {
+                refine_triangle(simplex, dir, a, c, d)
+            } else if ad.cross(ab).dot(ao) > S::zero() {
+                refine_triangle(simplex, dir, a, d, b)
+            } else {
+                Some(true)
+            }
+        }
+    }
+}
+
+fn refine_line<S: BaseFloat>(simplex: &mut Vec<Vector3<S>>, dir: &mut Vector3<S>,
+                             a: Vector3<S>, b: Vector3<S>) {
+    let (ab, ao) = (b - a, -a);
+    *simplex = if ab.dot(ao) > S::zero() {
+        *dir = ab.cross(ao).cross(ab);
+        vec![b, a]
+    } else {
+        *dir = ao;
+        vec![a]
+    };
+}
+
+fn refine_triangle<S: BaseFloat>(simplex: &mut Vec<Vector3<S>>, dir: &mut Vector3<S>,
+                                 a: Vector3<S>, b: Vector3<S>, c: Vector3<S>) -> Option<bool> {
+    let (ab, ac, ao) = (b - a, c - a, -a);
+    let abc = ab.cross(ac);
+    if abc.length2() == S::zero() {
+        // the points are collinear, so there is no plane to search from
+        return Some(false);
+    }
+    if abc.cross(ac).dot(ao) > S::zero() {
+        if ac.dot(ao) > S::zero() {
+            *dir = ac.cross(ao).cross(ac);
+            *simplex = vec![c, a];
+        } else {
+            refine_line(simplex, dir, a, b);
+        }
+    } else if ab.cross(abc).dot(ao) > S::zero() {
+        refine_line(simplex, dir, a, b);
+    } else {
+        let side = abc.dot(ao);
+        if side > S::zero() {
+            *dir = abc;
+            *simplex = vec![c, b, a];
+        } else if side < S::zero() {
+            *dir = -abc;
+            *simplex = vec![b, c, a];
+        } else {
+            return Some(true);
+        }
+    }
+    None
+}
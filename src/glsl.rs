@@ -0,0 +1,93 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Free functions named after the GLSL built-ins, which forward to the
+//! equivalent methods, so that shader code can be ported with few changes.
+//!
+//! ```rust
+//! use cgmath::Vector3;
+//! use cgmath::glsl::*;
+//!
+//! let n = normalize(cross(Vector3::new(1.0f32, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0)));
+//! let diffuse = dot(n, Vector3::new(0.0, 0.0, 1.0)).max(0.0);
+//! ```
+
+use std::ops::{Add, Mul, Sub};
+
+use matrix::{Matrix, SquareMatrix};
+use num::BaseFloat;
+use vector::{Vector, Vector3, EuclideanVector};
+
+/// The dot product of two vectors.
+#[inline]
+pub fn dot<V: Vector>(a: V, b: V) -> V::Scalar {
+    a.dot(b)
+}
+
+/// The cross product of two vectors.
+#[inline]
+pub fn cross<S: BaseFloat>(a: Vector3<S>, b: Vector3<S>) -> Vector3<S> {
+    a.cross(b)
+}
+
+/// The length of a vector.
+#[inline]
+pub fn length<V: EuclideanVector>(v: V) -> V::Scalar where V::Scalar: BaseFloat {
+    v.length()
+}
+
+/// The distance between two vectors.
+#[inline]
+pub fn distance<V: EuclideanVector>(a: V, b: V) -> V::Scalar where V::Scalar: BaseFloat {
+    a.distance(b)
+}
+
+/// A vector with the same direction as `v`, and a length of one.
+#[inline]
+pub fn normalize<V: EuclideanVector>(v: V) -> V where V::Scalar: BaseFloat {
+    v.normalize()
+}
+
+/// The transpose of a matrix.
+#[inline]
+pub fn transpose<M: Matrix>(m: M) -> M::Transpose {
+    m.transpose()
+}
+
+/// The determinant of a matrix.
+#[inline]
+pub fn determinant<M: SquareMatrix>(m: M) -> M::Element {
+    m.determinant()
+}
+
+/// The inverse of a matrix.
+///
+/// # Panics
+///
+/// Panics if the matrix is not invertible, where the result in GLSL would be
+/// undefined. Use `SquareMatrix::invert` to handle this case.
+#[inline]
+pub fn inverse<M: SquareMatrix>(m: M) -> M {
+    m.invert().expect("matrix is not invertible")
+}
+
+/// Linearly interpolate between `a` and `b`, returning `a` when `t` is zero
+/// and `b` when it is one. This works on scalars as well as vectors.
+#[inline]
+pub fn mix<T, S>(a: T, b: T, t: S) -> T where
+    T: Copy + Add<T, Output = T> + Sub<T, Output = T> + Mul<S, Output = T>,
+{
+    a + (b - a) * t
+}
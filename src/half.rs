@@ -0,0 +1,122 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion to and from [half precision]
+//! (https://en.wikipedia.org/wiki/Half-precision_floating-point_format)
+//! floats, for vertex attributes and textures.
+
+use matrix::{Matrix2, Matrix3, Matrix4};
+use vector::{Vector2, Vector3, Vector4};
+
+/// Convert an `f32` to the bits of the nearest half precision float, rounding
+/// ties to even.
+///
+/// Values too large to be represented become infinities, and values too
+/// small become zeroes or subnormals. NaNs stay NaNs.
+pub fn f32_to_f16(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exponent = ((bits >> 23) & 0xff) as i32;
+    let mantissa = bits & 0x7f_ffff;
+
+    if exponent == 0xff {
+        // keep NaNs quiet, and NaN, even if the payload is truncated
+        let nan = if mantissa != 0 { 0x200 | (mantissa >> 13) as u16 } else { 0 };
+        return sign | 0x7c00 | nan;
+    }
+
+    let exponent = exponent - 127 + 15;
+    if exponent >= 0x1f {
+        return sign | 0x7c00;
+    }
+
+    // the bits of the mantissa to keep, and the number shifted out after them
+    let (half, shift, mantissa) = if exponent <= 0 {
+        if exponent < -10 {
+            return sign;
+        }
+        // subnormal, so the implicit leading bit becomes explicit
+        (0, (14 - exponent) as u32, mantissa | 0x80_0000)
+    } else {
+        ((exponent as u32) << 10, 13, mantissa)
+    };
+    let half = half | (mantissa >> shift);
+    let remainder = mantissa & ((1 << shift) - 1);
+    let halfway = 1 << (shift - 1);
+    // a carry out of the mantissa correctly bumps the exponent
+    let round_up = remainder > halfway || (remainder == halfway && half & 1 != 0);
+    sign | (half + round_up as u32) as u16
+}
+
+/// Convert the bits of a half precision float to an `f32`. Every half
+/// precision value can be represented exactly.
+pub fn f16_to_f32(half: u16) -> f32 {
+    let sign = ((half & 0x8000) as u32) << 16;
+    let exponent = ((half >> 10) & 0x1f) as u32;
+    let mantissa = (half & 0x3ff) as u32;
+
+    let bits = if exponent == 0 {
+        if mantissa == 0 {
+            sign
+        } else {
+            // normalize the subnormal
+            let (mut exponent, mut mantissa) = (127 - 15 + 1, mantissa);
+            while mantissa & 0x400 == 0 {
+                mantissa <<= 1;
+                exponent -= 1;
+            }
+            sign | (exponent << 23) | ((mantissa & 0x3ff) << 13)
+        }
+    } else if exponent == 0x1f {
+        sign | 0x7f80_0000 | (mantissa << 13)
+    } else {
+        sign | ((exponent + 127 - 15) << 23) | (mantissa << 13)
+    };
+    f32::from_bits(bits)
+}
+
+macro_rules! impl_f16 {
+    ($ArrayN:ident, $n:expr, $from:expr) => {
+        impl $ArrayN<f32> {
+            /// Convert the components to half precision floats, in the
+            /// order they are stored.
+            pub fn to_f16_array(&self) -> [u16; $n] {
+                let components: &[f32; $n] = self.as_ref();
+                let mut halves = [0; $n];
+                for (h, &c) in halves.iter_mut().zip(components.iter()) {
+                    *h = f32_to_f16(c);
+                }
+                halves
+            }
+
+            /// Construct from half precision components, in the order they
+            /// are stored.
+            pub fn from_f16_array(halves: [u16; $n]) -> $ArrayN<f32> {
+                let mut components = [0.0; $n];
+                for (c, &h) in components.iter_mut().zip(halves.iter()) {
+                    *c = f16_to_f32(h);
+                }
+                $from(components)
+            }
+        }
+    }
+}
+
+impl_f16!(Vector2, 2, Vector2::from);
+impl_f16!(Vector3, 3, Vector3::from);
+impl_f16!(Vector4, 4, Vector4::from);
+impl_f16!(Matrix2, 4, |m: [f32; 4]| Matrix2::from_slice(&m).unwrap());
+impl_f16!(Matrix3, 9, |m: [f32; 9]| Matrix3::from_slice(&m).unwrap());
+impl_f16!(Matrix4, 16, |m: [f32; 16]| Matrix4::from_slice(&m).unwrap());
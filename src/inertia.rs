@@ -0,0 +1,132 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! [Inertia tensors](https://en.wikipedia.org/wiki/Moment_of_inertia#Inertia_tensor)
+//! of solid shapes with a uniform density, for rigid body dynamics.
+//!
+//! The tensors of the shapes are about their centers of mass, in the same
+//! axes as the shapes. Use `parallel_axis` to move them to another point, and
+//! `rotate_inertia` to express them in rotated axes.
+
+use std::f64;
+
+use rust_num::traits::cast;
+
+use aabb::Aabb3;
+use capsule::Capsule3;
+use cylinder::Cylinder3;
+use matrix::{SquareMatrix, Matrix3};
+use num::BaseFloat;
+use obb::Obb3;
+use sphere::Sphere;
+use vector::{EuclideanVector, Vector3};
+
+/// Shift an inertia tensor about the center of mass to one about the point
+/// at `offset` from it, using the [parallel axis
+/// theorem](https://en.wikipedia.org/wiki/Parallel_axis_theorem).
+pub fn parallel_axis<S: BaseFloat>(inertia: Matrix3<S>, mass: S, offset: Vector3<S>) -> Matrix3<S> {
+    inertia + (Matrix3::from_value(offset.length2()) - offset.outer(offset)) * mass
+}
+
+/// Express an inertia tensor in the axes rotated by `rotation`, computing
+/// `R * I * Rᵀ`. This takes a tensor from the local axes of a body to the
+/// world axes.
+#[inline]
+pub fn rotate_inertia<S: BaseFloat>(inertia: Matrix3<S>, rotation: &Matrix3<S>) -> Matrix3<S> {
+    (*rotation * inertia).mul_transpose(rotation)
+}
+
+/// The inertia tensor of a body that is symmetric about `axis`, with the
+/// moment `axial` about the axis and `transverse` about any perpendicular
+/// axis. A zero axis is only allowed if the moments are equal.
+fn axisymmetric<S: BaseFloat>(transverse: S, axial: S, axis: Vector3<S>) -> Matrix3<S> {
+    let inertia = Matrix3::from_value(transverse);
+    if axis.length2() == S::zero() {
+        inertia
+    } else {
+        let axis = axis.normalize();
+        inertia + axis.outer(axis) * (axial - transverse)
+    }
+}
+
+impl<S: BaseFloat> Sphere<S> {
+    /// The inertia tensor of the solid sphere, about its center.
+    pub fn inertia(&self, mass: S) -> Matrix3<S> {
+        let moment = mass * self.radius * self.radius * cast(2.0f64 / 5.0).unwrap();
+        Matrix3::from_value(moment)
+    }
+}
+
+/// The inertia tensor of a solid box with the half extents `h`, about its
+/// center, in the axes of its edges.
+fn box_inertia<S: BaseFloat>(mass: S, h: Vector3<S>) -> Matrix3<S> {
+    let h2 = h * h;
+    let third: S = cast(1.0f64 / 3.0).unwrap();
+    Matrix3::from_diagonal(Vector3::new(h2.y + h2.z, h2.x + h2.z, h2.x + h2.y) * (mass * third))
+}
+
+impl<S: BaseFloat> Aabb3<S> {
+    /// The inertia tensor of the solid box, about its center.
+    pub fn inertia(&self, mass: S) -> Matrix3<S> {
+        box_inertia(mass, self.dim() / S::two())
+    }
+}
+
+impl<S: BaseFloat> Obb3<S> {
+    /// The inertia tensor of the solid box, about its center.
+    pub fn inertia(&self, mass: S) -> Matrix3<S> {
+        rotate_inertia(box_inertia(mass, self.half_extents), &self.axes)
+    }
+}
+
+impl<S: BaseFloat> Cylinder3<S> {
+    /// The inertia tensor of the solid cylinder, about its center. The ends
+    /// of the cylinder must be distinct.
+    pub fn inertia(&self, mass: S) -> Matrix3<S> {
+        let axis = self.end - self.start;
+        let (r2, h2) = (self.radius * self.radius, axis.length2());
+        let twelfth: S = cast(1.0f64 / 12.0).unwrap();
+        let transverse = mass * (r2 * cast(3).unwrap() + h2) * twelfth;
+        axisymmetric(transverse, mass * r2 * S::half(), axis)
+    }
+}
+
+impl<S: BaseFloat> Capsule3<S> {
+    /// The inertia tensor of the solid capsule, about its center. This is
+    /// the tensor of a sphere if the ends of the capsule are the same.
+    pub fn inertia(&self, mass: S) -> Matrix3<S> {
+        let axis = self.end - self.start;
+        let (r, h) = (self.radius, axis.length());
+        let (r2, h2) = (r * r, h * h);
+
+        // split the mass between the cylinder and the two hemispherical caps
+        // in proportion to their volumes
+        let pi: S = cast(f64::consts::PI).unwrap();
+        let cylinder_volume = pi * r2 * h;
+        let caps_volume = pi * r2 * r * cast(4.0f64 / 3.0).unwrap();
+        let cylinder_mass = mass * cylinder_volume / (cylinder_volume + caps_volume);
+        let caps_mass = mass - cylinder_mass;
+
+        let two_fifths: S = cast(2.0f64 / 5.0).unwrap();
+        let quarter = S::half() * S::half();
+        let axial = cylinder_mass * r2 * S::half() + caps_mass * r2 * two_fifths;
+        // each cap is offset by half the height, plus the distance from its
+        // flat face to its own center of mass, 3r/8
+        let transverse = cylinder_mass * (r2 * quarter + h2 * cast(1.0f64 / 12.0).unwrap()) +
+                         caps_mass * (r2 * two_fifths + h2 * quarter +
+                                      h * r * cast(3.0f64 / 8.0).unwrap());
+        axisymmetric(transverse, axial, axis)
+    }
+}
@@ -0,0 +1,207 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Interpolation between values of the same type, for writing keyframe
+//! animation and blending code once for any interpolated type, along with
+//! Hermite interpolation and easing curves.
+//!
+//! ```rust
+//! use cgmath::{Interpolate, Quaternion, Vector3};
+//!
+//! fn sample<T: Interpolate<f32> + Copy>(keys: &[(f32, T)], time: f32) -> T {
+//!     let i = keys.iter().position(|&(t, _)| t > time).unwrap_or(keys.len() - 1).max(1);
+//!     let ((t0, a), (t1, b)) = (keys[i - 1], keys[i]);
+//!     a.slerp(&b, (time - t0) / (t1 - t0))
+//! }
+//!
+//! let positions = [(0.0, Vector3::new(0.0, 0.0, 0.0)), (2.0, Vector3::new(4.0, 2.0, 0.0))];
+//! assert_eq!(sample(&positions, 1.0), Vector3::new(2.0, 1.0, 0.0));
+//! let rotations = [(0.0, Quaternion::one()), (1.0, Quaternion::one())];
+//! assert_eq!(sample(&rotations, 0.5), Quaternion::one());
+//! ```
+
+use std::ops::{Add, Mul};
+
+use rust_num::traits::cast;
+
+use color::{Rgb, Rgba};
+use isometry::{Isometry3, interpolate_pose};
+use matrix::{Matrix2, Matrix3, Matrix4};
+use num::{BaseFloat, clamp};
+use point::{Point2, Point3};
+use quaternion::Quaternion;
+use transform::Decomposed;
+use vector::{EuclideanVector, Vector2, Vector3, Vector4};
+
+/// A value that can be interpolated with another of the same type by an
+/// amount of type `S`.
+///
+/// The methods take their arguments by reference, so that the by-value
+/// methods of the same names, such as `EuclideanVector::lerp` and
+/// `Quaternion::slerp`, are still called when both are in scope.
+pub trait Interpolate<S>: Sized {
+    /// Interpolate linearly between `self` and `other`, returning `self` when
+    /// `amount` is zero and `other` when it is one.
+    fn lerp(&self, other: &Self, amount: S) -> Self;
+
+    /// Interpolate with a constant angular velocity between `self` and
+    /// `other`, for types representing rotations. For other types, this is
+    /// the same as `lerp`.
+    #[inline]
+    fn slerp(&self, other: &Self, amount: S) -> Self {
+        self.lerp(other, amount)
+    }
+}
+
+macro_rules! impl_interpolate_linear {
+    ($T:ty) => {
+        impl<S: BaseFloat> Interpolate<S> for $T {
+            #[inline]
+            fn lerp(&self, other: &$T, amount: S) -> $T {
+                *self + (*other - *self) * amount
+            }
+        }
+    }
+}
+
+impl_interpolate_linear!(S);
+impl_interpolate_linear!(Vector2<S>);
+impl_interpolate_linear!(Vector3<S>);
+impl_interpolate_linear!(Vector4<S>);
+impl_interpolate_linear!(Point2<S>);
+impl_interpolate_linear!(Point3<S>);
+impl_interpolate_linear!(Matrix2<S>);
+impl_interpolate_linear!(Matrix3<S>);
+impl_interpolate_linear!(Matrix4<S>);
+impl_interpolate_linear!(Rgb<S>);
+impl_interpolate_linear!(Rgba<S>);
+
+/// Normalized linear interpolation, and spherical linear interpolation. Like
+/// `nlerp` and `slerp`, these don't pick the shortest path between `q` and
+/// `-q`.
+impl<S: BaseFloat> Interpolate<S> for Quaternion<S> {
+    #[inline]
+    fn lerp(&self, other: &Quaternion<S>, amount: S) -> Quaternion<S> {
+        Quaternion::nlerp(*self, *other, amount)
+    }
+
+    #[inline]
+    fn slerp(&self, other: &Quaternion<S>, amount: S) -> Quaternion<S> {
+        Quaternion::slerp(*self, *other, amount)
+    }
+}
+
+/// The rotation is interpolated along the shortest path, with `nlerp` for
+/// `lerp`, and with `slerp` for `slerp`, as in `Decomposed::interpolate`.
+impl<S: BaseFloat> Interpolate<S> for Decomposed<Vector3<S>, Quaternion<S>> {
+    fn lerp(&self, other: &Decomposed<Vector3<S>, Quaternion<S>>, amount: S)
+            -> Decomposed<Vector3<S>, Quaternion<S>> {
+        Decomposed {
+            scale: self.scale.lerp(&other.scale, amount),
+            rot: self.rot.nlerp(other.rot.closest_to(self.rot), amount),
+            disp: self.disp.lerp(other.disp, amount),
+        }
+    }
+
+    #[inline]
+    fn slerp(&self, other: &Decomposed<Vector3<S>, Quaternion<S>>, amount: S)
+             -> Decomposed<Vector3<S>, Quaternion<S>> {
+        self.interpolate(other, amount)
+    }
+}
+
+/// The rotation is interpolated along the shortest path, with `nlerp` for
+/// `lerp`, and with `slerp` for `slerp`, as in `interpolate_pose`.
+impl<S: BaseFloat> Interpolate<S> for Isometry3<S> {
+    fn lerp(&self, other: &Isometry3<S>, amount: S) -> Isometry3<S> {
+        Isometry3 {
+            rot: self.rot.nlerp(other.rot.closest_to(self.rot), amount),
+            disp: self.disp.lerp(other.disp, amount),
+        }
+    }
+
+    #[inline]
+    fn slerp(&self, other: &Isometry3<S>, amount: S) -> Isometry3<S> {
+        interpolate_pose(self, other, amount)
+    }
+}
+
+/// Cubic Hermite interpolation from `p0` to `p1`, with the tangents `m0` and
+/// `m1` at each end, returning `p0` when `t` is zero and `p1` when it is one.
+/// This works on scalars as well as vectors.
+///
+/// The tangents are derivatives with respect to `t`, so when the curve spans
+/// a time interval `dt`, velocities should be multiplied by `dt`.
+pub fn hermite<V, S>(p0: V, m0: V, p1: V, m1: V, t: S) -> V where
+    V: Copy + Add<V, Output = V> + Mul<S, Output = V>,
+    S: BaseFloat,
+{
+    let two = S::two();
+    let three: S = cast(3).unwrap();
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let h00 = two * t3 - three * t2 + S::one();
+    let h10 = t3 - two * t2 + t;
+    let h01 = three * t2 - two * t3;
+    let h11 = t3 - t2;
+    p0 * h00 + m0 * h10 + p1 * h01 + m1 * h11
+}
+
+/// Cubic easing that starts slowly, mapping `[0, 1]` onto `[0, 1]`.
+#[inline]
+pub fn ease_in_cubic<S: BaseFloat>(t: S) -> S {
+    t * t * t
+}
+
+/// Cubic easing that ends slowly, mapping `[0, 1]` onto `[0, 1]`.
+#[inline]
+pub fn ease_out_cubic<S: BaseFloat>(t: S) -> S {
+    let u = S::one() - t;
+    S::one() - u * u * u
+}
+
+/// Cubic easing that starts and ends slowly, mapping `[0, 1]` onto `[0, 1]`.
+#[inline]
+pub fn ease_in_out_cubic<S: BaseFloat>(t: S) -> S {
+    let half = S::half();
+    let four: S = cast(4).unwrap();
+    if t < half {
+        four * t * t * t
+    } else {
+        let u = S::one() - t;
+        S::one() - four * u * u * u
+    }
+}
+
+/// Hermite interpolation between zero at `edge0` and one at `edge1`, as in
+/// GLSL. The result is clamped outside of the edges.
+#[inline]
+pub fn smoothstep<S: BaseFloat>(edge0: S, edge1: S, x: S) -> S {
+    let two = S::two();
+    let three: S = cast(3).unwrap();
+    let t = clamp((x - edge0) / (edge1 - edge0), S::zero(), S::one());
+    t * t * (three - two * t)
+}
+
+/// Ken Perlin's variant of `smoothstep`, which also has zero second
+/// derivatives at the edges, so that it eases in and out more gently.
+#[inline]
+pub fn smootherstep<S: BaseFloat>(edge0: S, edge1: S, x: S) -> S {
+    let six: S = cast(6).unwrap();
+    let fifteen: S = cast(15).unwrap();
+    let ten: S = cast(10).unwrap();
+    let t = clamp((x - edge0) / (edge1 - edge0), S::zero(), S::one());
+    t * t * t * (t * (t * six - fifteen) + ten)
+}
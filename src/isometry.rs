@@ -0,0 +1,245 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rigid body transformations.
+
+use std::fmt;
+
+use approx::ApproxEq;
+use matrix::{Matrix3, Matrix4};
+use num::BaseFloat;
+use point::{Point, Point3};
+use quaternion::Quaternion;
+use rotation::Rotation;
+use transform::{Transform, Transform3};
+use vector::{Vector3, EuclideanVector};
+
+/// A rigid body transformation in 3-dimensional space: a rotation followed
+/// by a translation, with no scaling.
+#[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+pub struct Isometry3<S> {
+    /// The rotation, which should be a unit quaternion.
+    pub rot: Quaternion<S>,
+    /// The translation, applied after the rotation.
+    pub disp: Vector3<S>,
+}
+
+impl<S: BaseFloat> Isometry3<S> {
+    /// Create a new isometry from a unit quaternion and a translation.
+    #[inline]
+    pub fn new(rot: Quaternion<S>, disp: Vector3<S>) -> Isometry3<S> {
+        Isometry3 { rot: rot, disp: disp }
+    }
+}
+
+impl<S: BaseFloat> Transform<Point3<S>> for Isometry3<S> {
+    #[inline]
+    fn one() -> Isometry3<S> {
+        Isometry3::new(Quaternion::one(), Vector3::zero())
+    }
+
+    #[inline]
+    fn look_at(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Isometry3<S> {
+        let rot: Quaternion<S> = Rotation::look_at(center - eye, up);
+        let disp = rot.rotate_vector(Point3::origin() - eye);
+        Isometry3::new(rot, disp)
+    }
+
+    #[inline]
+    fn transform_vector(&self, vec: Vector3<S>) -> Vector3<S> {
+        self.rot.rotate_vector(vec)
+    }
+
+    #[inline]
+    fn transform_point(&self, point: Point3<S>) -> Point3<S> {
+        self.rot.rotate_point(point) + self.disp
+    }
+
+    #[inline]
+    fn concat(&self, other: &Isometry3<S>) -> Isometry3<S> {
+        Isometry3::new(self.rot * other.rot, self.rot.rotate_vector(other.disp) + self.disp)
+    }
+
+    /// Invert the isometry. Since the rotation is assumed to be a unit
+    /// quaternion, its inverse is just its conjugate, and this always
+    /// succeeds.
+    #[inline]
+    fn invert(&self) -> Option<Isometry3<S>> {
+        let rot = self.rot.conjugate();
+        Some(Isometry3::new(rot, -rot.rotate_vector(self.disp)))
+    }
+}
+
+impl<S: BaseFloat> From<Isometry3<S>> for Matrix4<S> {
+    fn from(iso: Isometry3<S>) -> Matrix4<S> {
+        let mut m: Matrix4<S> = Matrix3::from(iso.rot).into();
+        m.w = iso.disp.extend(S::one());
+        m
+    }
+}
+
+impl<S: BaseFloat> Transform3<S> for Isometry3<S> {}
+
+impl<S: BaseFloat> ApproxEq for Isometry3<S> {
+    type Epsilon = S;
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Isometry3<S>, epsilon: &S) -> bool {
+        self.rot.approx_eq_eps(&other.rot, epsilon) &&
+        self.disp.approx_eq_eps(&other.disp, epsilon)
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Isometry3<S>, epsilon: &S, max_relative: &S) -> bool {
+        self.rot.relative_eq(&other.rot, epsilon, max_relative) &&
+        self.disp.relative_eq(&other.disp, epsilon, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Isometry3<S>, epsilon: &S, max_ulps: u32) -> bool {
+        self.rot.ulps_eq(&other.rot, epsilon, max_ulps) &&
+        self.disp.ulps_eq(&other.disp, epsilon, max_ulps)
+    }
+}
+
+impl<S: BaseFloat> fmt::Debug for Isometry3<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Isometry3 {{ rot: {:?}, disp: {:?} }}", self.rot, self.disp)
+    }
+}
+
+/// A similarity transformation in 3-dimensional space: a uniform scale,
+/// followed by a rigid body transformation.
+#[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+pub struct Similarity3<S> {
+    /// The rotation and translation, applied after the scale.
+    pub isometry: Isometry3<S>,
+    /// The uniform scale factor.
+    pub scale: S,
+}
+
+impl<S: BaseFloat> Similarity3<S> {
+    /// Create a new similarity from a rigid body transformation and a
+    /// uniform scale factor.
+    #[inline]
+    pub fn new(isometry: Isometry3<S>, scale: S) -> Similarity3<S> {
+        Similarity3 { isometry: isometry, scale: scale }
+    }
+
+    /// Create a new similarity from a scale factor, a unit quaternion, and a
+    /// translation.
+    #[inline]
+    pub fn from_parts(scale: S, rot: Quaternion<S>, disp: Vector3<S>) -> Similarity3<S> {
+        Similarity3::new(Isometry3::new(rot, disp), scale)
+    }
+}
+
+impl<S: BaseFloat> Transform<Point3<S>> for Similarity3<S> {
+    #[inline]
+    fn one() -> Similarity3<S> {
+        Similarity3::new(Isometry3::one(), S::one())
+    }
+
+    #[inline]
+    fn look_at(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Similarity3<S> {
+        Similarity3::new(Isometry3::look_at(eye, center, up), S::one())
+    }
+
+    #[inline]
+    fn transform_vector(&self, vec: Vector3<S>) -> Vector3<S> {
+        self.isometry.transform_vector(vec * self.scale)
+    }
+
+    #[inline]
+    fn transform_point(&self, point: Point3<S>) -> Point3<S> {
+        self.isometry.transform_point(point * self.scale)
+    }
+
+    #[inline]
+    fn concat(&self, other: &Similarity3<S>) -> Similarity3<S> {
+        Similarity3::from_parts(self.scale * other.scale,
+                                self.isometry.rot * other.isometry.rot,
+                                self.transform_as_point(other.isometry.disp))
+    }
+
+    /// Invert the similarity, failing if the scale factor is zero.
+    fn invert(&self) -> Option<Similarity3<S>> {
+        if self.scale.approx_eq(&S::zero()) {
+            None
+        } else {
+            let s = S::one() / self.scale;
+            let rot = self.isometry.rot.conjugate();
+            Some(Similarity3::from_parts(s, rot, rot.rotate_vector(self.isometry.disp) * -s))
+        }
+    }
+}
+
+impl<S: BaseFloat> From<Isometry3<S>> for Similarity3<S> {
+    #[inline]
+    fn from(iso: Isometry3<S>) -> Similarity3<S> {
+        Similarity3::new(iso, S::one())
+    }
+}
+
+impl<S: BaseFloat> From<Similarity3<S>> for Matrix4<S> {
+    fn from(sim: Similarity3<S>) -> Matrix4<S> {
+        let mut m: Matrix4<S> = (Matrix3::from(sim.isometry.rot) * sim.scale).into();
+        m.w = sim.isometry.disp.extend(S::one());
+        m
+    }
+}
+
+impl<S: BaseFloat> Transform3<S> for Similarity3<S> {}
+
+impl<S: BaseFloat> ApproxEq for Similarity3<S> {
+    type Epsilon = S;
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Similarity3<S>, epsilon: &S) -> bool {
+        self.isometry.approx_eq_eps(&other.isometry, epsilon) &&
+        self.scale.approx_eq_eps(&other.scale, epsilon)
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Similarity3<S>, epsilon: &S, max_relative: &S) -> bool {
+        self.isometry.relative_eq(&other.isometry, epsilon, max_relative) &&
+        self.scale.relative_eq(&other.scale, epsilon, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Similarity3<S>, epsilon: &S, max_ulps: u32) -> bool {
+        self.isometry.ulps_eq(&other.isometry, epsilon, max_ulps) &&
+        self.scale.ulps_eq(&other.scale, epsilon, max_ulps)
+    }
+}
+
+impl<S: BaseFloat> fmt::Debug for Similarity3<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Similarity3 {{ isometry: {:?}, scale: {:?} }}", self.isometry, self.scale)
+    }
+}
+
+/// Interpolate between the poses of two consecutive simulation steps, for
+/// rendering a fixed-timestep simulation at an arbitrary frame rate.
+///
+/// `alpha` is the fraction of a step that has passed since `curr`'s step
+/// was due, in the range `[0, 1]`. The translation is interpolated linearly,
+/// and the rotation spherically along the shortest path.
+pub fn interpolate_pose<S: BaseFloat>(prev: &Isometry3<S>, curr: &Isometry3<S>, alpha: S) -> Isometry3<S> {
+    Isometry3 {
+        rot: prev.rot.slerp(curr.rot.closest_to(prev.rot), alpha),
+        disp: prev.disp.lerp(curr.disp, alpha),
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Best-fit rigid alignment of corresponding point sets, using the [Kabsch
+//! algorithm](https://en.wikipedia.org/wiki/Kabsch_algorithm).
+
+use rust_num::traits::cast;
+
+use isometry::Isometry3;
+use matrix::{Matrix, SquareMatrix, Matrix3};
+use num::BaseFloat;
+use point::{Point, Point3};
+use quaternion::Quaternion;
+use vector::Vector3;
+
+/// The rotation `R` minimizing the sum of the squared distances between
+/// `R * from[i]` and `to[i]`, as a rotation matrix. Returns `None` if the
+/// slices are empty or have different lengths.
+///
+/// The result is always a proper rotation, never a reflection. If the
+/// vectors all lie on a line, the rotation about that line is arbitrary.
+pub fn best_fit_rotation<S: BaseFloat>(from: &[Vector3<S>], to: &[Vector3<S>]) -> Option<Matrix3<S>> {
+    if from.is_empty() || from.len() != to.len() {
+        return None;
+    }
+    let mut covariance = Matrix3::zero();
+    for (a, b) in from.iter().zip(to.iter()) {
+        covariance = covariance + b.outer(*a);
+    }
+    let (mut u, _, v) = covariance.svd();
+    // flip the axis of the smallest singular value to avoid a reflection
+    if u.determinant() * v.determinant() < S::zero() {
+        u.z = -u.z;
+    }
+    Some(u * v.transpose())
+}
+
+/// The rigid transformation minimizing the sum of the squared distances
+/// between the transformed `from[i]` and `to[i]`. Returns `None` if the
+/// slices are empty or have different lengths.
+///
+/// This is the rotation from `best_fit_rotation` of the points about their
+/// centroids, followed by the translation between the centroids.
+pub fn best_fit_isometry<S: BaseFloat>(from: &[Point3<S>], to: &[Point3<S>]) -> Option<Isometry3<S>> {
+    if from.is_empty() || from.len() != to.len() {
+        return None;
+    }
+    let from_centroid = centroid(from);
+    let to_centroid = centroid(to);
+    let from: Vec<Vector3<S>> = from.iter().map(|&p| p - from_centroid).collect();
+    let to: Vec<Vector3<S>> = to.iter().map(|&p| p - to_centroid).collect();
+    best_fit_rotation(&from, &to).map(|r| {
+        let disp = to_centroid.to_vec() - r * from_centroid.to_vec();
+        Isometry3::new(Quaternion::from(r), disp)
+    })
+}
+
+fn centroid<S: BaseFloat>(points: &[Point3<S>]) -> Point3<S> {
+    let mut sum = Vector3::zero();
+    for p in points {
+        sum = sum + p.to_vec();
+    }
+    let n: S = cast(points.len()).unwrap();
+    Point3::from_vec(sum / n)
+}
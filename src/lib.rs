@@ -41,14 +41,42 @@ pub use quaternion::*;
 pub use vector::*;
 
 pub use angle::*;
+pub use euler::*;
 pub use point::*;
 pub use rotation::*;
 pub use transform::*;
 
 pub use projection::*;
 
+pub use color::*;
+pub use decomposition::*;
+pub use dynamic::*;
+pub use half::*;
+pub use interpolate::*;
+pub use noise::*;
+
+pub use aabb::*;
+pub use capsule::*;
+pub use cylinder::*;
+pub use frustum::*;
+pub use gizmo::*;
+pub use gjk::*;
+pub use inertia::*;
+pub use isometry::*;
+pub use kabsch::*;
+pub use line::*;
+pub use obb::*;
+pub use plane::*;
+pub use ray::*;
+pub use shader::*;
+pub use sphere::*;
+pub use spherical_harmonics::*;
+pub use triangle::*;
+pub use unit::*;
+
 pub use approx::ApproxEq;
 pub use num::*;
+pub use parse::ParseError;
 
 pub use rust_num::{One, Zero, one, zero};
 
@@ -56,6 +84,8 @@ pub use rust_num::{One, Zero, one, zero};
 
 mod macros;
 
+pub mod glsl;
+
 mod array;
 
 mod matrix;
@@ -63,11 +93,41 @@ mod quaternion;
 mod vector;
 
 mod angle;
+mod euler;
 mod point;
 mod rotation;
 mod transform;
 
 mod projection;
 
+mod color;
+mod decomposition;
+mod dynamic;
+mod half;
+mod interpolate;
+mod noise;
+mod packing;
+mod random;
+
+mod aabb;
+mod capsule;
+mod cylinder;
+mod frustum;
+mod gizmo;
+mod gjk;
+mod inertia;
+mod isometry;
+mod kabsch;
+mod line;
+mod obb;
+mod plane;
+mod ray;
+mod shader;
+mod sphere;
+mod spherical_harmonics;
+mod triangle;
+mod unit;
+
 mod approx;
 mod num;
+mod parse;
@@ -0,0 +1,220 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use rust_num::{One, Zero};
+
+use num::{BaseFloat, clamp};
+use point::{Point, Point2, Point3};
+use vector::{Vector, EuclideanVector, Vector2, Vector3};
+
+/// A generic infinite line through `origin`, extending in both directions
+/// along `direction`.
+#[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+pub struct Line<P, V> {
+    pub origin: P,
+    pub direction: V,
+}
+
+pub type Line2<S> = Line<Point2<S>, Vector2<S>>;
+pub type Line3<S> = Line<Point3<S>, Vector3<S>>;
+
+impl<P: Point> Line<P, P::Vector> where
+    P::Scalar: BaseFloat,
+    P::Vector: EuclideanVector,
+{
+    /// Create a new line from a point on it and a direction.
+    #[inline]
+    pub fn new(origin: P, direction: P::Vector) -> Line<P, P::Vector> {
+        Line { origin: origin, direction: direction }
+    }
+
+    /// Create the line passing through two points.
+    #[inline]
+    pub fn from_points(a: P, b: P) -> Line<P, P::Vector> {
+        Line::new(a, b - a)
+    }
+
+    /// The point at the parameter `t` along the line, that is
+    /// `origin + direction * t`.
+    #[inline]
+    pub fn at(&self, t: P::Scalar) -> P {
+        self.origin + self.direction * t
+    }
+
+    /// The parameter of the point on the line closest to `point`. Returns
+    /// zero if the line has no direction.
+    #[inline]
+    pub fn closest_parameter(&self, point: P) -> P::Scalar {
+        let length2 = self.direction.length2();
+        if length2 == P::Scalar::zero() {
+            P::Scalar::zero()
+        } else {
+            (point - self.origin).dot(self.direction) / length2
+        }
+    }
+
+    /// The point on the line closest to `point`.
+    #[inline]
+    pub fn closest_point(&self, point: P) -> P {
+        self.at(self.closest_parameter(point))
+    }
+
+    /// The squared distance from `point` to the line.
+    #[inline]
+    pub fn distance2(&self, point: P) -> P::Scalar {
+        (point - self.closest_point(point)).length2()
+    }
+
+    /// The distance from `point` to the line.
+    #[inline]
+    pub fn distance(&self, point: P) -> P::Scalar {
+        (point - self.closest_point(point)).length()
+    }
+}
+
+/// A generic line segment from `start` to `end`.
+#[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+pub struct Segment<P> {
+    pub start: P,
+    pub end: P,
+}
+
+pub type Segment2<S> = Segment<Point2<S>>;
+pub type Segment3<S> = Segment<Point3<S>>;
+
+impl<P: Point> Segment<P> where
+    P::Scalar: BaseFloat,
+    P::Vector: EuclideanVector,
+{
+    /// Create a new line segment.
+    #[inline]
+    pub fn new(start: P, end: P) -> Segment<P> {
+        Segment { start: start, end: end }
+    }
+
+    /// The vector from the start of the segment to the end.
+    #[inline]
+    pub fn direction(&self) -> P::Vector {
+        self.end - self.start
+    }
+
+    /// The length of the segment.
+    #[inline]
+    pub fn length(&self) -> P::Scalar {
+        self.direction().length()
+    }
+
+    /// The point at the parameter `t` along the segment, where `0` is the
+    /// start and `1` is the end.
+    #[inline]
+    pub fn at(&self, t: P::Scalar) -> P {
+        self.start + self.direction() * t
+    }
+
+    /// The line that the segment lies on.
+    #[inline]
+    pub fn line(&self) -> Line<P, P::Vector> {
+        Line::from_points(self.start, self.end)
+    }
+
+    /// The point on the segment closest to `point`.
+    #[inline]
+    pub fn closest_point(&self, point: P) -> P {
+        let t = self.line().closest_parameter(point);
+        self.at(clamp(t, P::Scalar::zero(), P::Scalar::one()))
+    }
+
+    /// The squared distance from `point` to the segment.
+    #[inline]
+    pub fn distance2(&self, point: P) -> P::Scalar {
+        (point - self.closest_point(point)).length2()
+    }
+
+    /// The distance from `point` to the segment.
+    #[inline]
+    pub fn distance(&self, point: P) -> P::Scalar {
+        (point - self.closest_point(point)).length()
+    }
+
+    /// The closest pair of points between this segment and `other`, with the
+    /// first on this segment and the second on `other`. When the segments
+    /// are parallel, one of the closest pairs is chosen.
+    ///
+    /// This is the method from Christer Ericson's Real-Time Collision
+    /// Detection.
+    pub fn closest_points(&self, other: &Segment<P>) -> (P, P) {
+        let zero = P::Scalar::zero();
+        let one = P::Scalar::one();
+        let d1 = self.direction();
+        let d2 = other.direction();
+        let r = self.start - other.start;
+        let a = d1.length2();
+        let e = d2.length2();
+        let f = d2.dot(r);
+
+        let (s, t) = if a == zero && e == zero {
+            (zero, zero)
+        } else if a == zero {
+            (zero, clamp(f / e, zero, one))
+        } else {
+            let c = d1.dot(r);
+            if e == zero {
+                (clamp(-c / a, zero, one), zero)
+            } else {
+                let b = d1.dot(d2);
+                let denom = a * e - b * b;
+                let s = if denom == zero { zero } else { clamp((b * f - c * e) / denom, zero, one) };
+                let t = (b * s + f) / e;
+                if t < zero {
+                    (clamp(-c / a, zero, one), zero)
+                } else if t > one {
+                    (clamp((b - c) / a, zero, one), one)
+                } else {
+                    (s, t)
+                }
+            }
+        };
+        (self.at(s), other.at(t))
+    }
+
+    /// The squared distance between the closest points of this segment and
+    /// `other`.
+    #[inline]
+    pub fn segment_distance2(&self, other: &Segment<P>) -> P::Scalar {
+        let (p, q) = self.closest_points(other);
+        (q - p).length2()
+    }
+
+    /// The distance between the closest points of this segment and `other`.
+    #[inline]
+    pub fn segment_distance(&self, other: &Segment<P>) -> P::Scalar {
+        let (p, q) = self.closest_points(other);
+        (q - p).length()
+    }
+}
+
+impl<P: fmt::Debug, V: fmt::Debug> fmt::Debug for Line<P, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Line({:?}, {:?})", self.origin, self.direction)
+    }
+}
+
+impl<P: fmt::Debug> fmt::Debug for Segment<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Segment({:?}, {:?})", self.start, self.end)
+    }
+}
@@ -163,6 +163,20 @@ macro_rules! impl_fixed_array_conversions {
                 unsafe { mem::transmute(v) }
             }
         }
+
+        impl<$S> $ArrayN<$S> {
+            /// View the components as a slice.
+            #[inline]
+            pub fn as_slice<'a>(&'a self) -> &'a [$S] {
+                let v: &[$S; $n] = self.as_ref(); v
+            }
+
+            /// View the components as a mutable slice.
+            #[inline]
+            pub fn as_mut_slice<'a>(&'a mut self) -> &'a mut [$S] {
+                let v: &mut [$S; $n] = self.as_mut(); v
+            }
+        }
     }
 }
 
@@ -213,6 +227,52 @@ macro_rules! impl_tuple_conversions {
     }
 }
 
+/// Generates `Display` and `FromStr` implementations for a compound type,
+/// using the `(x, y, z)` format
+macro_rules! impl_display_parse {
+    ($ArrayN:ident <$S:ident> { $($field:ident),+ }, $n:expr) => {
+        impl<$S: ::std::fmt::Display> ::std::fmt::Display for $ArrayN<$S> {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                ::parse::write_tuple(f, &[$(&self.$field),+])
+            }
+        }
+
+        impl<$S: ::std::str::FromStr> ::std::str::FromStr for $ArrayN<$S> {
+            type Err = ::parse::ParseError;
+
+            fn from_str(s: &str) -> Result<$ArrayN<$S>, ::parse::ParseError> {
+                let mut components = ::parse::parse_list::<$S>(s, '(', ')', $n)?.into_iter();
+                Ok($ArrayN { $($field: components.next().unwrap()),+ })
+            }
+        }
+    }
+}
+
+/// Generates `Eq` and `Hash` implementations for a compound type, for use as
+/// a key in hash maps, with scalars hashed through `HashBits`
+macro_rules! impl_hash {
+    ($ArrayN:ident <$S:ident> { $($field:ident),+ }) => {
+        impl<$S: ::num::HashBits> Eq for $ArrayN<$S> {}
+
+        impl<$S: ::num::HashBits> ::std::hash::Hash for $ArrayN<$S> {
+            #[inline]
+            fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                $(::num::HashBits::hash_bits(&self.$field, state);)+
+            }
+        }
+    };
+    (columns $MatrixN:ident <$S:ident> { $($field:ident),+ }) => {
+        impl<$S: ::num::HashBits> Eq for $MatrixN<$S> {}
+
+        impl<$S: ::num::HashBits> ::std::hash::Hash for $MatrixN<$S> {
+            #[inline]
+            fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                $(::std::hash::Hash::hash(&self.$field, state);)+
+            }
+        }
+    }
+}
+
 /// Generates index operators for a compound type
 macro_rules! impl_index_operators {
     ($VectorN:ident<$S:ident>, $n:expr, $Output:ty, $I:ty) => {
@@ -19,16 +19,19 @@ use std::fmt;
 use std::mem;
 use std::ops::*;
 use std::ptr;
+use std::str::FromStr;
 
 use rand::{Rand, Rng};
 
-use rust_num::{Zero, One};
-use rust_num::traits::cast;
+use rust_num::{Float, NumCast, Zero, One};
 
 use angle::{Angle, Rad};
 use approx::ApproxEq;
+use euler::{Euler, EulerOrder};
 use array::Array;
 use num::BaseFloat;
+use parse::{self, ParseError};
+use plane::Plane;
 use point::{Point, Point3};
 use quaternion::Quaternion;
 use vector::{Vector, EuclideanVector};
@@ -46,6 +49,10 @@ pub struct Matrix3<S> { pub x: Vector3<S>, pub y: Vector3<S>, pub z: Vector3<S>
 #[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
 pub struct Matrix4<S> { pub x: Vector4<S>, pub y: Vector4<S>, pub z: Vector4<S>, pub w: Vector4<S> }
 
+impl_hash!(columns Matrix2<S> { x, y });
+impl_hash!(columns Matrix3<S> { x, y, z });
+impl_hash!(columns Matrix4<S> { x, y, z, w });
+
 
 impl<S: BaseFloat> Matrix2<S> {
     /// Create a new matrix, providing values for each index.
@@ -62,6 +69,27 @@ impl<S: BaseFloat> Matrix2<S> {
         Matrix2 { x: c0, y: c1 }
     }
 
+    /// Create a scale matrix from a scale value.
+    #[inline]
+    pub fn from_scale(value: S) -> Matrix2<S> {
+        Matrix2::from_nonuniform_scale(value, value)
+    }
+
+    /// Create a scale matrix from a set of scale values.
+    #[inline]
+    pub fn from_nonuniform_scale(x: S, y: S) -> Matrix2<S> {
+        Matrix2::new(x, S::zero(),
+                     S::zero(), y)
+    }
+
+    /// Create a shear matrix that adds `xy` times the `y` component to the
+    /// `x` component.
+    #[inline]
+    pub fn from_shear(xy: S) -> Matrix2<S> {
+        Matrix2::new(S::one(), S::zero(),
+                     xy, S::one())
+    }
+
     /// Create a transformation matrix that will cause a vector to point at
     /// `dir`, using `up` for orientation.
     pub fn look_at(dir: Vector2<S>, up: Vector2<S>) -> Matrix2<S> {
@@ -77,6 +105,58 @@ impl<S: BaseFloat> Matrix2<S> {
         Matrix2::new(cos_theta,  sin_theta,
                      -sin_theta, cos_theta)
     }
+
+    /// Extract the angle of a rotation matrix, in the range `[-π, π]`.
+    ///
+    /// Both columns contribute to the result, so small errors from
+    /// accumulated transforms are averaged out.
+    #[inline]
+    pub fn to_angle(&self) -> Rad<S> {
+        Rad::atan2(self.x.y - self.y.x, self.x.x + self.y.y)
+    }
+
+    /// The cofactor of the element in column `c` and row `r`: the element
+    /// in the other column and row, negated when `c + r` is odd.
+    #[inline]
+    pub fn cofactor(&self, c: usize, r: usize) -> S {
+        let minor = self[1 - c][1 - r];
+        if (c + r) & 1 == 1 { -minor } else { minor }
+    }
+
+    /// The [adjugate](https://en.wikipedia.org/wiki/Adjugate_matrix), the
+    /// transpose of the matrix of cofactors. This is the inverse scaled by
+    /// the determinant, so unlike the inverse it exists for every matrix.
+    #[inline]
+    pub fn adjugate(&self) -> Matrix2<S> {
+        Matrix2::new( self[1][1], -self[0][1],
+                     -self[1][0],  self[0][0])
+    }
+
+    /// Invert this matrix without checking that it is invertible, for when
+    /// that is known in advance. The inverse of a singular matrix is full of
+    /// infinities or NaNs.
+    #[inline]
+    #[must_use]
+    pub fn invert_unchecked(&self) -> Matrix2<S> {
+        self.adjugate() / self.determinant()
+    }
+}
+
+/// Constants, which only need a zero and a one.
+impl<S: Zero + One> Matrix2<S> {
+    /// The matrix with every element zero. This is the same as
+    /// `Matrix::zero`, but only needs the scalar to have a zero and a one.
+    #[inline]
+    pub fn zero() -> Matrix2<S> {
+        Matrix2 { x: Vector2::zero(), y: Vector2::zero() }
+    }
+
+    /// The identity matrix. This is the same as `SquareMatrix::identity`,
+    /// but only needs the scalar to have a zero and a one.
+    #[inline]
+    pub fn identity() -> Matrix2<S> {
+        Matrix2 { x: Vector2::unit_x(), y: Vector2::unit_y() }
+    }
 }
 
 impl<S: Copy + Neg<Output = S>> Matrix2<S> {
@@ -105,6 +185,41 @@ impl<S: BaseFloat> Matrix3<S> {
         Matrix3 { x: c0, y: c1, z: c2 }
     }
 
+    /// Create a homogeneous 2D transformation matrix from a translation
+    /// vector.
+    #[inline]
+    pub fn from_translation(v: Vector2<S>) -> Matrix3<S> {
+        Matrix3::new(S::one(), S::zero(), S::zero(),
+                     S::zero(), S::one(), S::zero(),
+                     v.x, v.y, S::one())
+    }
+
+    /// Create a scale matrix from a scale value.
+    #[inline]
+    pub fn from_scale(value: S) -> Matrix3<S> {
+        Matrix3::from_nonuniform_scale(value, value, value)
+    }
+
+    /// Create a scale matrix from a set of scale values. With `z` set to one,
+    /// this is a homogeneous 2D scale.
+    #[inline]
+    pub fn from_nonuniform_scale(x: S, y: S, z: S) -> Matrix3<S> {
+        Matrix3::new(x, S::zero(), S::zero(),
+                     S::zero(), y, S::zero(),
+                     S::zero(), S::zero(), z)
+    }
+
+    /// Create an upper unit triangular shear matrix, which adds `xy` times
+    /// the `y` component and `xz` times the `z` component to the `x`
+    /// component, and `yz` times the `z` component to the `y` component.
+    /// These are the factors reported by `Matrix4::decompose`.
+    #[inline]
+    pub fn from_shear(xy: S, xz: S, yz: S) -> Matrix3<S> {
+        Matrix3::new(S::one(), S::zero(), S::zero(),
+                     xy, S::one(), S::zero(),
+                     xz, yz, S::one())
+    }
+
     /// Create a rotation matrix that will cause a vector to point at
     /// `dir`, using `up` for orientation.
     pub fn look_at(dir: Vector3<S>, up: Vector3<S>) -> Matrix3<S> {
@@ -177,6 +292,103 @@ impl<S: BaseFloat> Matrix3<S> {
                      _1subc * axis.y * axis.z - s * axis.x,
                      _1subc * axis.z * axis.z + c)
     }
+
+    /// Extract the axis and angle of a rotation matrix. The angle is in the
+    /// range `[0, π]`.
+    ///
+    /// Returns the `x` axis for the identity rotation. Near half turns,
+    /// where the antisymmetric part of the matrix vanishes, the axis is
+    /// recovered from the symmetric part instead.
+    pub fn to_axis_angle(&self) -> (Vector3<S>, Rad<S>) {
+        // twice the sine of the angle, scaled by the axis
+        let v = Vector3::new(self[1][2] - self[2][1],
+                             self[2][0] - self[0][2],
+                             self[0][1] - self[1][0]);
+        let angle = Rad::atan2(v.length(), self.trace() - S::one());
+        let cos = Rad::cos(angle);
+
+        if cos >= S::zero() {
+            if v.length2() == S::zero() {
+                (Vector3::unit_x(), angle)
+            } else {
+                (v.normalize(), angle)
+            }
+        } else {
+            // the symmetric part is `(1 - cos) * axis * axis^T`, so any
+            // column is parallel to the axis. Take the largest one.
+            let sym = |c: usize, r: usize| (self[c][r] + self[r][c]) / S::two();
+            let diagonal = Vector3::new(sym(0, 0), sym(1, 1), sym(2, 2));
+            let i = if diagonal.x >= diagonal.y && diagonal.x >= diagonal.z { 0 }
+                    else if diagonal.y >= diagonal.z { 1 }
+                    else { 2 };
+            let mut axis = Vector3::new(sym(i, 0), sym(i, 1), sym(i, 2));
+            axis[i] = axis[i] - cos;
+            let axis = axis.normalize();
+            if axis.dot(v) < S::zero() { (-axis, angle) } else { (axis, angle) }
+        }
+    }
+
+    /// Decompose a rotation matrix into Euler angles applied in `order`. See
+    /// `Euler::from_matrix` for details.
+    #[inline]
+    pub fn to_euler(&self, order: EulerOrder) -> Euler<S> {
+        Euler::from_matrix(*self, order)
+    }
+
+    /// Create a `Matrix2` from the upper-left 2 x 2 block of this matrix,
+    /// dropping the last row and column. Applied to a 2D homogeneous
+    /// transform, this removes the translation.
+    #[inline]
+    pub fn truncate(&self) -> Matrix2<S> {
+        Matrix2::from_cols(self.x.truncate(), self.y.truncate())
+    }
+
+    /// The cofactor of the element in column `c` and row `r`: the
+    /// determinant of the matrix without that column and row, negated when
+    /// `c + r` is odd.
+    pub fn cofactor(&self, c: usize, r: usize) -> S {
+        let others = |i| match i { 0 => (1, 2), 1 => (0, 2), _ => (0, 1) };
+        let (c0, c1) = others(c);
+        let (r0, r1) = others(r);
+        let minor = self[c0][r0] * self[c1][r1] - self[c1][r0] * self[c0][r1];
+        if (c + r) & 1 == 1 { -minor } else { minor }
+    }
+
+    /// The [adjugate](https://en.wikipedia.org/wiki/Adjugate_matrix), the
+    /// transpose of the matrix of cofactors. This is the inverse scaled by
+    /// the determinant, so unlike the inverse it exists for every matrix.
+    #[inline]
+    pub fn adjugate(&self) -> Matrix3<S> {
+        Matrix3::from_cols(self.y.cross(self.z),
+                           self.z.cross(self.x),
+                           self.x.cross(self.y)).transpose()
+    }
+
+    /// Invert this matrix without checking that it is invertible, for when
+    /// that is known in advance. The inverse of a singular matrix is full of
+    /// infinities or NaNs.
+    #[inline]
+    #[must_use]
+    pub fn invert_unchecked(&self) -> Matrix3<S> {
+        self.adjugate() / self.determinant()
+    }
+}
+
+/// Constants, which only need a zero and a one.
+impl<S: Zero + One> Matrix3<S> {
+    /// The matrix with every element zero. This is the same as
+    /// `Matrix::zero`, but only needs the scalar to have a zero and a one.
+    #[inline]
+    pub fn zero() -> Matrix3<S> {
+        Matrix3 { x: Vector3::zero(), y: Vector3::zero(), z: Vector3::zero() }
+    }
+
+    /// The identity matrix. This is the same as `SquareMatrix::identity`,
+    /// but only needs the scalar to have a zero and a one.
+    #[inline]
+    pub fn identity() -> Matrix3<S> {
+        Matrix3 { x: Vector3::unit_x(), y: Vector3::unit_y(), z: Vector3::unit_z() }
+    }
 }
 
 impl<S: Copy + Neg<Output = S>> Matrix3<S> {
@@ -232,6 +444,34 @@ impl<S: BaseFloat> Matrix4<S> {
                      S::zero(), S::zero(), S::zero(), S::one())
     }
 
+    /// Create a homogeneous transformation matrix from a set of shear
+    /// factors. See `Matrix3::from_shear`.
+    #[inline]
+    pub fn from_shear(xy: S, xz: S, yz: S) -> Matrix4<S> {
+        Matrix4::from(Matrix3::from_shear(xy, xz, yz))
+    }
+
+    /// Create a homogeneous transformation matrix from a rotation around the
+    /// `x` axis (pitch).
+    #[inline]
+    pub fn from_angle_x(theta: Rad<S>) -> Matrix4<S> {
+        Matrix4::from(Matrix3::from_angle_x(theta))
+    }
+
+    /// Create a homogeneous transformation matrix from a rotation around the
+    /// `y` axis (yaw).
+    #[inline]
+    pub fn from_angle_y(theta: Rad<S>) -> Matrix4<S> {
+        Matrix4::from(Matrix3::from_angle_y(theta))
+    }
+
+    /// Create a homogeneous transformation matrix from a rotation around the
+    /// `z` axis (roll).
+    #[inline]
+    pub fn from_angle_z(theta: Rad<S>) -> Matrix4<S> {
+        Matrix4::from(Matrix3::from_angle_z(theta))
+    }
+
     /// Create a homogeneous transformation matrix that will cause a vector to point at
     /// `dir`, using `up` for orientation.
     pub fn look_at(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Matrix4<S> {
@@ -244,6 +484,132 @@ impl<S: BaseFloat> Matrix4<S> {
                      s.z.clone(), u.z.clone(), -f.z.clone(), S::zero(),
                      -eye.dot(s), -eye.dot(u), eye.dot(f), S::one())
     }
+
+    /// The camera pose for the view matrix made by `look_at`: the eye, and
+    /// the orientation taking the camera's axes to the world's, with the
+    /// camera looking down its negative `z` axis and `y` up. Camera
+    /// controllers can move the pose, and use `from_camera_pose` to make the
+    /// view matrix when rendering.
+    pub fn look_at_pose(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> (Point3<S>, Quaternion<S>) {
+        let f = (center - eye).normalize();
+        let s = f.cross(up).normalize();
+        let u = s.cross(f);
+        (eye, Quaternion::from(Matrix3::from_cols(s, u, -f)))
+    }
+
+    /// Create a view matrix from a camera pose, as returned by
+    /// `look_at_pose`. This is the inverse of the transformation that
+    /// rotates by `orientation` and then translates to `eye`.
+    pub fn from_camera_pose(eye: Point3<S>, orientation: Quaternion<S>) -> Matrix4<S> {
+        Matrix4::from(orientation.conjugate()).mul_affine(&Matrix4::from_translation(Point3::origin() - eye))
+    }
+
+    /// Create a matrix that projects points onto `plane` along the lines
+    /// through a point light at `light`, flattening geometry into its planar
+    /// shadow. The result is a projective transformation, so divide by `w`
+    /// after applying it. The light must not lie on the plane.
+    pub fn from_planar_projection(plane: &Plane<S>, light: Point3<S>) -> Matrix4<S> {
+        Matrix4::planar_projection(plane, light.to_homogeneous())
+    }
+
+    /// Create a matrix that projects points onto `plane` along `direction`,
+    /// for the shadow of a directional light. The direction must not be
+    /// parallel to the plane.
+    pub fn from_planar_projection_directional(plane: &Plane<S>, direction: Vector3<S>) -> Matrix4<S> {
+        Matrix4::planar_projection(plane, direction.extend(S::zero()))
+    }
+
+    /// The projection onto a plane from the homogeneous light position `l`:
+    /// `(π · l) I - l πᵀ`, where `π` is the plane as a homogeneous vector.
+    fn planar_projection(plane: &Plane<S>, l: Vector4<S>) -> Matrix4<S> {
+        let p = Vector4::new(plane.n.x, plane.n.y, plane.n.z, -plane.d);
+        Matrix4::from_value(p.dot(l)) - Matrix4::from_cols(l * p.x, l * p.y, l * p.z, l * p.w)
+    }
+
+    /// Multiply two affine matrices, whose bottom rows are both `[0, 0, 0, 1]`.
+    ///
+    /// This skips the multiplications by the bottom rows, so it is cheaper
+    /// than the `*` operator. The result is only correct for affine matrices.
+    pub fn mul_affine(&self, other: &Matrix4<S>) -> Matrix4<S> {
+        macro_rules! dot_affine {
+            ($I:expr, $J:expr) => {
+                self[0][$I] * other[$J][0] +
+                self[1][$I] * other[$J][1] +
+                self[2][$I] * other[$J][2]
+            };
+        }
+
+        Matrix4::new(dot_affine!(0, 0), dot_affine!(1, 0), dot_affine!(2, 0), S::zero(),
+                     dot_affine!(0, 1), dot_affine!(1, 1), dot_affine!(2, 1), S::zero(),
+                     dot_affine!(0, 2), dot_affine!(1, 2), dot_affine!(2, 2), S::zero(),
+                     dot_affine!(0, 3) + self[3][0], dot_affine!(1, 3) + self[3][1],
+                     dot_affine!(2, 3) + self[3][2], S::one())
+    }
+
+    /// Create a `Matrix3` from the upper-left 3 x 3 block of this matrix,
+    /// dropping the last row and column. Applied to a homogeneous transform,
+    /// this removes the translation. Call `truncate` twice to get the
+    /// upper-left `Matrix2`.
+    #[inline]
+    pub fn truncate(&self) -> Matrix3<S> {
+        Matrix3::from_cols(self.x.truncate(), self.y.truncate(), self.z.truncate())
+    }
+
+    /// Returns `true` if the bottom row is approximately `[0, 0, 0, 1]`, so
+    /// that the matrix is an affine transformation without a projection.
+    #[inline]
+    pub fn is_affine(&self) -> bool {
+        self.row(3).approx_eq(&Vector4::unit_w())
+    }
+
+    /// The cofactor of the element in column `c` and row `r`: the
+    /// determinant of the matrix without that column and row, negated when
+    /// `c + r` is odd.
+    pub fn cofactor(&self, c: usize, r: usize) -> S {
+        let (c0, c1, c2) = match c { 0 => (1, 2, 3), 1 => (0, 2, 3), 2 => (0, 1, 3), _ => (0, 1, 2) };
+        let row = r as isize;
+        let minor = Matrix3::from_cols(self[c0].truncate_n(row),
+                                       self[c1].truncate_n(row),
+                                       self[c2].truncate_n(row)).determinant();
+        if (c + r) & 1 == 1 { -minor } else { minor }
+    }
+
+    /// The [adjugate](https://en.wikipedia.org/wiki/Adjugate_matrix), the
+    /// transpose of the matrix of cofactors. This is the inverse scaled by
+    /// the determinant, so unlike the inverse it exists for every matrix.
+    pub fn adjugate(&self) -> Matrix4<S> {
+        let cf = |c, r| self.cofactor(c, r);
+        Matrix4::new(cf(0, 0), cf(0, 1), cf(0, 2), cf(0, 3),
+                     cf(1, 0), cf(1, 1), cf(1, 2), cf(1, 3),
+                     cf(2, 0), cf(2, 1), cf(2, 2), cf(2, 3),
+                     cf(3, 0), cf(3, 1), cf(3, 2), cf(3, 3)).transpose()
+    }
+
+    /// Invert this matrix without checking that it is invertible, for when
+    /// that is known in advance. The inverse of a singular matrix is full of
+    /// infinities or NaNs.
+    #[inline]
+    #[must_use]
+    pub fn invert_unchecked(&self) -> Matrix4<S> {
+        self.adjugate() / self.determinant()
+    }
+}
+
+/// Constants, which only need a zero and a one.
+impl<S: Zero + One> Matrix4<S> {
+    /// The matrix with every element zero. This is the same as
+    /// `Matrix::zero`, but only needs the scalar to have a zero and a one.
+    #[inline]
+    pub fn zero() -> Matrix4<S> {
+        Matrix4 { x: Vector4::zero(), y: Vector4::zero(), z: Vector4::zero(), w: Vector4::zero() }
+    }
+
+    /// The identity matrix. This is the same as `SquareMatrix::identity`,
+    /// but only needs the scalar to have a zero and a one.
+    #[inline]
+    pub fn identity() -> Matrix4<S> {
+        Matrix4 { x: Vector4::unit_x(), y: Vector4::unit_y(), z: Vector4::unit_z(), w: Vector4::unit_w() }
+    }
 }
 
 impl<S: Copy + Neg<Output = S>> Matrix4<S> {
@@ -361,6 +727,11 @@ pub trait SquareMatrix where
     /// Invert this matrix, returning a new matrix. `m.mul_m(m.invert())` is
     /// the identity matrix. Returns `None` if this matrix is not invertible
     /// (has a determinant of zero).
+    ///
+    /// The matrix is treated as singular when its determinant is negligible
+    /// compared to the product of the lengths of its columns, which is the
+    /// largest determinant that columns of those lengths can have. Unlike a
+    /// fixed threshold, this does not depend on the scale of the matrix.
     #[must_use]
     fn invert(&self) -> Option<Self>;
 
@@ -386,6 +757,33 @@ pub trait SquareMatrix where
     /// Test if this matrix is symmetric. That is, it is equal to its
     /// transpose.
     fn is_symmetric(&self) -> bool;
+
+    /// The [Frobenius norm](https://en.wikipedia.org/wiki/Matrix_norm#Frobenius_norm)
+    /// of this matrix. That is, the square root of the sum of the squares of
+    /// its elements.
+    fn norm_frobenius(&self) -> Self::Element;
+
+    /// The infinity norm of this matrix. That is, the largest sum of the
+    /// absolute values of the elements in a row.
+    fn norm_inf(&self) -> Self::Element;
+
+    /// The one norm of this matrix. That is, the largest sum of the absolute
+    /// values of the elements in a column.
+    #[inline]
+    fn norm_one(&self) -> Self::Element { self.transpose().norm_inf() }
+
+    /// The [condition number](https://en.wikipedia.org/wiki/Condition_number)
+    /// of this matrix with respect to the infinity norm. This bounds how much
+    /// relative errors in a vector are amplified by multiplying it with the
+    /// inverse of the matrix, so large values mean the inverse is not
+    /// trustworthy. Returns infinity if the matrix is not invertible.
+    #[inline]
+    fn condition_number(&self) -> Self::Element {
+        match self.invert() {
+            Some(inverse) => self.norm_inf() * inverse.norm_inf(),
+            None => Self::Element::infinity(),
+        }
+    }
 }
 
 impl<S: BaseFloat> Matrix for Matrix2<S> {
@@ -430,6 +828,15 @@ impl<S: BaseFloat> Matrix for Matrix2<S> {
     }
 }
 
+/// Returns `true` if the determinant is negligible compared to the product of
+/// the column lengths, given as the product of their squares. By Hadamard's
+/// inequality this is the largest possible magnitude of the determinant, so
+/// the ratio between them does not change when the matrix is scaled.
+#[inline]
+pub(crate) fn is_singular<S: BaseFloat>(det: S, column_length2_product: S) -> bool {
+    !(det.abs() > S::approx_epsilon() * column_length2_product.sqrt())
+}
+
 impl<S: BaseFloat> SquareMatrix for Matrix2<S> {
     type ColumnRow = Vector2<S>;
 
@@ -469,14 +876,18 @@ impl<S: BaseFloat> SquareMatrix for Matrix2<S> {
     #[inline]
     fn invert(&self) -> Option<Matrix2<S>> {
         let det = self.determinant();
-        if det.approx_eq(&S::zero()) {
+        if is_singular(det, self.column_length2_product()) {
             None
         } else {
-            Some(Matrix2::new( self[1][1] / det, -self[0][1] / det,
-                              -self[1][0] / det,  self[0][0] / det))
+            Some(self.adjugate() / det)
         }
     }
 
+    #[inline]
+    fn is_invertible(&self) -> bool {
+        !is_singular(self.determinant(), self.column_length2_product())
+    }
+
     #[inline]
     fn is_diagonal(&self) -> bool {
         self[0][1].approx_eq(&S::zero()) &&
@@ -489,6 +900,16 @@ impl<S: BaseFloat> SquareMatrix for Matrix2<S> {
         self[0][1].approx_eq(&self[1][0]) &&
         self[1][0].approx_eq(&self[0][1])
     }
+
+    #[inline]
+    fn norm_frobenius(&self) -> S {
+        (self.x.length2() + self.y.length2()).sqrt()
+    }
+
+    fn norm_inf(&self) -> S {
+        (0..2).map(|r| self.x[r].abs() + self.y[r].abs())
+              .fold(S::zero(), |max, sum| max.partial_max(sum))
+    }
 }
 
 impl<S: BaseFloat> Matrix for Matrix3<S> {
@@ -581,13 +1002,16 @@ impl<S: BaseFloat> SquareMatrix for Matrix3<S> {
 
     fn invert(&self) -> Option<Matrix3<S>> {
         let det = self.determinant();
-        if det.approx_eq(&S::zero()) { None } else {
-            Some(Matrix3::from_cols(self[1].cross(self[2]) / det,
-                                    self[2].cross(self[0]) / det,
-                                    self[0].cross(self[1]) / det).transpose())
+        if is_singular(det, self.column_length2_product()) { None } else {
+            Some(self.adjugate() / det)
         }
     }
 
+    #[inline]
+    fn is_invertible(&self) -> bool {
+        !is_singular(self.determinant(), self.column_length2_product())
+    }
+
     fn is_diagonal(&self) -> bool {
         self[0][1].approx_eq(&S::zero()) &&
         self[0][2].approx_eq(&S::zero()) &&
@@ -609,6 +1033,16 @@ impl<S: BaseFloat> SquareMatrix for Matrix3<S> {
         self[2][0].approx_eq(&self[0][2]) &&
         self[2][1].approx_eq(&self[1][2])
     }
+
+    #[inline]
+    fn norm_frobenius(&self) -> S {
+        (self.x.length2() + self.y.length2() + self.z.length2()).sqrt()
+    }
+
+    fn norm_inf(&self) -> S {
+        (0..3).map(|r| self.x[r].abs() + self.y[r].abs() + self.z[r].abs())
+              .fold(S::zero(), |max, sum| max.partial_max(sum))
+    }
 }
 
 impl<S: BaseFloat> Matrix for Matrix4<S> {
@@ -724,28 +1158,16 @@ impl<S: BaseFloat> SquareMatrix for Matrix4<S> {
 
     fn invert(&self) -> Option<Matrix4<S>> {
         let det = self.determinant();
-        if det.approx_eq(&S::zero()) { None } else {
-            let inv_det = S::one() / det;
-            let t = self.transpose();
-            let cf = |i, j| {
-                let mat = match i {
-                    0 => Matrix3::from_cols(t.y.truncate_n(j), t.z.truncate_n(j), t.w.truncate_n(j)),
-                    1 => Matrix3::from_cols(t.x.truncate_n(j), t.z.truncate_n(j), t.w.truncate_n(j)),
-                    2 => Matrix3::from_cols(t.x.truncate_n(j), t.y.truncate_n(j), t.w.truncate_n(j)),
-                    3 => Matrix3::from_cols(t.x.truncate_n(j), t.y.truncate_n(j), t.z.truncate_n(j)),
-                    _ => panic!("out of range"),
-                };
-                let sign = if (i + j) & 1 == 1 { -S::one() } else { S::one() };
-                mat.determinant() * sign * inv_det
-            };
-
-            Some(Matrix4::new(cf(0, 0), cf(0, 1), cf(0, 2), cf(0, 3),
-                              cf(1, 0), cf(1, 1), cf(1, 2), cf(1, 3),
-                              cf(2, 0), cf(2, 1), cf(2, 2), cf(2, 3),
-                              cf(3, 0), cf(3, 1), cf(3, 2), cf(3, 3)))
+        if is_singular(det, self.column_length2_product()) { None } else {
+            Some(self.adjugate() / det)
         }
     }
 
+    #[inline]
+    fn is_invertible(&self) -> bool {
+        !is_singular(self.determinant(), self.column_length2_product())
+    }
+
     fn is_diagonal(&self) -> bool {
         self[0][1].approx_eq(&S::zero()) &&
         self[0][2].approx_eq(&S::zero()) &&
@@ -781,6 +1203,16 @@ impl<S: BaseFloat> SquareMatrix for Matrix4<S> {
         self[3][1].approx_eq(&self[1][3]) &&
         self[3][2].approx_eq(&self[2][3])
     }
+
+    #[inline]
+    fn norm_frobenius(&self) -> S {
+        (self.x.length2() + self.y.length2() + self.z.length2() + self.w.length2()).sqrt()
+    }
+
+    fn norm_inf(&self) -> S {
+        (0..4).map(|r| self.x[r].abs() + self.y[r].abs() + self.z[r].abs() + self.w[r].abs())
+              .fold(S::zero(), |max, sum| max.partial_max(sum))
+    }
 }
 
 impl<S: BaseFloat> ApproxEq for Matrix2<S> {
@@ -791,6 +1223,18 @@ impl<S: BaseFloat> ApproxEq for Matrix2<S> {
         self[0].approx_eq_eps(&other[0], epsilon) &&
         self[1].approx_eq_eps(&other[1], epsilon)
     }
+
+    #[inline]
+    fn relative_eq(&self, other: &Matrix2<S>, epsilon: &S, max_relative: &S) -> bool {
+        self[0].relative_eq(&other[0], epsilon, max_relative) &&
+        self[1].relative_eq(&other[1], epsilon, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Matrix2<S>, epsilon: &S, max_ulps: u32) -> bool {
+        self[0].ulps_eq(&other[0], epsilon, max_ulps) &&
+        self[1].ulps_eq(&other[1], epsilon, max_ulps)
+    }
 }
 
 impl<S: BaseFloat> ApproxEq for Matrix3<S> {
@@ -802,6 +1246,20 @@ impl<S: BaseFloat> ApproxEq for Matrix3<S> {
         self[1].approx_eq_eps(&other[1], epsilon) &&
         self[2].approx_eq_eps(&other[2], epsilon)
     }
+
+    #[inline]
+    fn relative_eq(&self, other: &Matrix3<S>, epsilon: &S, max_relative: &S) -> bool {
+        self[0].relative_eq(&other[0], epsilon, max_relative) &&
+        self[1].relative_eq(&other[1], epsilon, max_relative) &&
+        self[2].relative_eq(&other[2], epsilon, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Matrix3<S>, epsilon: &S, max_ulps: u32) -> bool {
+        self[0].ulps_eq(&other[0], epsilon, max_ulps) &&
+        self[1].ulps_eq(&other[1], epsilon, max_ulps) &&
+        self[2].ulps_eq(&other[2], epsilon, max_ulps)
+    }
 }
 
 impl<S: BaseFloat> ApproxEq for Matrix4<S> {
@@ -814,10 +1272,26 @@ impl<S: BaseFloat> ApproxEq for Matrix4<S> {
         self[2].approx_eq_eps(&other[2], epsilon) &&
         self[3].approx_eq_eps(&other[3], epsilon)
     }
+
+    #[inline]
+    fn relative_eq(&self, other: &Matrix4<S>, epsilon: &S, max_relative: &S) -> bool {
+        self[0].relative_eq(&other[0], epsilon, max_relative) &&
+        self[1].relative_eq(&other[1], epsilon, max_relative) &&
+        self[2].relative_eq(&other[2], epsilon, max_relative) &&
+        self[3].relative_eq(&other[3], epsilon, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Matrix4<S>, epsilon: &S, max_ulps: u32) -> bool {
+        self[0].ulps_eq(&other[0], epsilon, max_ulps) &&
+        self[1].ulps_eq(&other[1], epsilon, max_ulps) &&
+        self[2].ulps_eq(&other[2], epsilon, max_ulps) &&
+        self[3].ulps_eq(&other[3], epsilon, max_ulps)
+    }
 }
 
 macro_rules! impl_operators {
-    ($MatrixN:ident, $VectorN:ident { $($field:ident : $row_index:expr),+ }) => {
+    ($MatrixN:ident { $($field:ident),+ }) => {
         impl_operator!(<S: BaseFloat> Neg for $MatrixN<S> {
             fn neg(matrix) -> $MatrixN<S> { $MatrixN { $($field: -matrix.$field),+ } }
         });
@@ -855,29 +1329,40 @@ macro_rules! impl_operators {
         impl<S: BaseFloat + SubAssign<S>> SubAssign<$MatrixN<S>> for $MatrixN<S> {
             fn sub_assign(&mut self, other: $MatrixN<S>) { $(self.$field -= other.$field);+ }
         }
-
-        impl_operator!(<S: BaseFloat> Mul<$VectorN<S> > for $MatrixN<S> {
-            fn mul(matrix, vector) -> $VectorN<S> { $VectorN::new($(matrix.row($row_index).dot(vector.clone())),+) }
-        });
     }
 }
 
-impl_operators!(Matrix2, Vector2 { x: 0, y: 1 });
-impl_operators!(Matrix3, Vector3 { x: 0, y: 1, z: 2 });
-impl_operators!(Matrix4, Vector4 { x: 0, y: 1, z: 2, w: 3 });
+impl_operators!(Matrix2 { x, y });
+impl_operators!(Matrix3 { x, y, z });
+impl_operators!(Matrix4 { x, y, z, w });
+
+// Summing the scaled columns reads them in place, rather than gathering
+// each row from every column.
+impl_operator!(<S: BaseFloat> Mul<Vector2<S> > for Matrix2<S> {
+    fn mul(matrix, vector) -> Vector2<S> { matrix.x * vector.x + matrix.y * vector.y }
+});
+
+impl_operator!(<S: BaseFloat> Mul<Vector3<S> > for Matrix3<S> {
+    fn mul(matrix, vector) -> Vector3<S> {
+        matrix.x * vector.x + matrix.y * vector.y + matrix.z * vector.z
+    }
+});
+
+impl_operator!(<S: BaseFloat> Mul<Vector4<S> > for Matrix4<S> {
+    fn mul(matrix, vector) -> Vector4<S> {
+        matrix.x * vector.x + matrix.y * vector.y + matrix.z * vector.z + matrix.w * vector.w
+    }
+});
 
 impl_operator!(<S: BaseFloat> Mul<Matrix2<S> > for Matrix2<S> {
     fn mul(lhs, rhs) -> Matrix2<S> {
-        Matrix2::new(lhs.row(0).dot(rhs[0]), lhs.row(1).dot(rhs[0]),
-                     lhs.row(0).dot(rhs[1]), lhs.row(1).dot(rhs[1]))
+        Matrix2::from_cols(lhs * rhs.x, lhs * rhs.y)
     }
 });
 
 impl_operator!(<S: BaseFloat> Mul<Matrix3<S> > for Matrix3<S> {
     fn mul(lhs, rhs) -> Matrix3<S> {
-        Matrix3::new(lhs.row(0).dot(rhs[0]), lhs.row(1).dot(rhs[0]), lhs.row(2).dot(rhs[0]),
-                     lhs.row(0).dot(rhs[1]), lhs.row(1).dot(rhs[1]), lhs.row(2).dot(rhs[1]),
-                     lhs.row(0).dot(rhs[2]), lhs.row(1).dot(rhs[2]), lhs.row(2).dot(rhs[2]))
+        Matrix3::from_cols(lhs * rhs.x, lhs * rhs.y, lhs * rhs.z)
     }
 });
 
@@ -904,42 +1389,36 @@ impl_operator!(<S: BaseFloat> Mul<Matrix4<S> > for Matrix4<S> {
 });
 
 macro_rules! index_operators {
-    ($MatrixN:ident<$S:ident>, $n:expr, $Output:ty, $I:ty) => {
-        impl<$S> Index<$I> for $MatrixN<$S> {
+    ($MatrixN:ident<$S:ident> { $($field:ident : $index:tt),+ }, $n:expr, $Output:ty) => {
+        // matching on the index borrows the column in place, so that chains
+        // like `m[c][r]` compile down to a single load
+        impl<$S> Index<usize> for $MatrixN<$S> {
             type Output = $Output;
 
             #[inline]
-            fn index<'a>(&'a self, i: $I) -> &'a $Output {
-                let v: &[[$S; $n]; $n] = self.as_ref();
-                From::from(&v[i])
+            fn index<'a>(&'a self, i: usize) -> &'a $Output {
+                match i {
+                    $($index => &self.$field,)+
+                    _ => panic!("index out of bounds: the len is {} but the index is {}", $n, i),
+                }
             }
         }
 
-        impl<$S> IndexMut<$I> for $MatrixN<$S> {
+        impl<$S> IndexMut<usize> for $MatrixN<$S> {
             #[inline]
-            fn index_mut<'a>(&'a mut self, i: $I) -> &'a mut $Output {
-                let v: &mut [[$S; $n]; $n] = self.as_mut();
-                From::from(&mut v[i])
+            fn index_mut<'a>(&'a mut self, i: usize) -> &'a mut $Output {
+                match i {
+                    $($index => &mut self.$field,)+
+                    _ => panic!("index out of bounds: the len is {} but the index is {}", $n, i),
+                }
             }
         }
     }
 }
 
-index_operators!(Matrix2<S>, 2, Vector2<S>, usize);
-index_operators!(Matrix3<S>, 3, Vector3<S>, usize);
-index_operators!(Matrix4<S>, 4, Vector4<S>, usize);
-// index_operators!(Matrix2<S>, 2, [Vector2<S>], Range<usize>);
-// index_operators!(Matrix3<S>, 3, [Vector3<S>], Range<usize>);
-// index_operators!(Matrix4<S>, 4, [Vector4<S>], Range<usize>);
-// index_operators!(Matrix2<S>, 2, [Vector2<S>], RangeTo<usize>);
-// index_operators!(Matrix3<S>, 3, [Vector3<S>], RangeTo<usize>);
-// index_operators!(Matrix4<S>, 4, [Vector4<S>], RangeTo<usize>);
-// index_operators!(Matrix2<S>, 2, [Vector2<S>], RangeFrom<usize>);
-// index_operators!(Matrix3<S>, 3, [Vector3<S>], RangeFrom<usize>);
-// index_operators!(Matrix4<S>, 4, [Vector4<S>], RangeFrom<usize>);
-// index_operators!(Matrix2<S>, 2, [Vector2<S>], RangeFull);
-// index_operators!(Matrix3<S>, 3, [Vector3<S>], RangeFull);
-// index_operators!(Matrix4<S>, 4, [Vector4<S>], RangeFull);
+index_operators!(Matrix2<S> { x: 0, y: 1 }, 2, Vector2<S>);
+index_operators!(Matrix3<S> { x: 0, y: 1, z: 2 }, 3, Vector3<S>);
+index_operators!(Matrix4<S> { x: 0, y: 1, z: 2, w: 3 }, 4, Vector4<S>);
 
 macro_rules! fixed_array_conversions {
     ($MatrixN:ident <$S:ident> { $($field:ident : $index:expr),+ }, $n:expr) => {
@@ -1029,6 +1508,91 @@ macro_rules! fixed_array_conversions {
                 unsafe { mem::transmute(m) }
             }
         }
+
+        impl<$S> $MatrixN<$S> {
+            /// View the elements as a slice, in column-major order, for
+            /// example to copy them into a mapped GPU buffer.
+            #[inline]
+            pub fn as_slice<'a>(&'a self) -> &'a [$S] {
+                let m: &[$S; ($n * $n)] = self.as_ref(); m
+            }
+
+            /// View the elements as a mutable slice, in column-major order.
+            #[inline]
+            pub fn as_mut_slice<'a>(&'a mut self) -> &'a mut [$S] {
+                let m: &mut [$S; ($n * $n)] = self.as_mut(); m
+            }
+        }
+
+        impl<$S: Copy> $MatrixN<$S> {
+            /// Construct a matrix from a slice of its elements, in
+            /// column-major order. Returns `None` unless the slice has
+            /// exactly as many elements as the matrix.
+            #[inline]
+            pub fn from_slice(slice: &[$S]) -> Option<$MatrixN<$S>> {
+                if slice.len() == $n * $n {
+                    Some(unsafe { $MatrixN::from_ptr(slice.as_ptr()) })
+                } else {
+                    None
+                }
+            }
+
+            /// Construct a matrix by copying its elements, in column-major
+            /// order, from a raw pointer.
+            ///
+            /// # Safety
+            ///
+            /// `ptr` must point to enough initialized elements to fill the
+            /// matrix, and be aligned for the element type.
+            #[inline]
+            pub unsafe fn from_ptr(ptr: *const $S) -> $MatrixN<$S> {
+                ptr::read(ptr as *const $MatrixN<$S>)
+            }
+
+            /// Construct a matrix from an array of its rows, as used by
+            /// row-major libraries and file formats.
+            #[inline]
+            pub fn from_row_major(rows: [[$S; $n]; $n]) -> $MatrixN<$S> {
+                let mut m = rows;
+                for c in 0..$n {
+                    for r in (c + 1)..$n {
+                        let tmp = m[c][r];
+                        m[c][r] = m[r][c];
+                        m[r][c] = tmp;
+                    }
+                }
+                $MatrixN::from(m)
+            }
+
+            /// Convert the matrix to an array of its rows, as used by
+            /// row-major libraries and file formats. This is the transpose of
+            /// converting it with `Into<[[S; N]; N]>`, which gives the columns.
+            #[inline]
+            pub fn to_row_major_array(&self) -> [[$S; $n]; $n] {
+                // reading the columns as rows gives the transpose
+                let columns: &[[$S; $n]; $n] = self.as_ref();
+                $MatrixN::from_row_major(*columns).into()
+            }
+        }
+
+        impl<$S: BaseFloat> $MatrixN<$S> {
+            /// Construct the permutation matrix `P` for which row `i` of
+            /// `P * A` is row `perm[i]` of `A`, as for the `perm` of an LU
+            /// decomposition. Returns `None` unless `perm` contains each
+            /// index exactly once.
+            pub fn from_permutation(perm: [usize; $n]) -> Option<$MatrixN<$S>> {
+                let mut m = [[$S::zero(); $n]; $n];
+                let mut seen = [false; $n];
+                for (i, &j) in perm.iter().enumerate() {
+                    if j >= $n || seen[j] {
+                        return None;
+                    }
+                    seen[j] = true;
+                    m[j][i] = $S::one();
+                }
+                Some($MatrixN::from(m))
+            }
+        }
     }
 }
 
@@ -1036,6 +1600,298 @@ fixed_array_conversions!(Matrix2<S> { x:0, y:1 }, 2);
 fixed_array_conversions!(Matrix3<S> { x:0, y:1, z:2 }, 3);
 fixed_array_conversions!(Matrix4<S> { x:0, y:1, z:2, w:3 }, 4);
 
+macro_rules! impl_componentwise {
+    ($MatrixN:ident { $($field:ident),+ }) => {
+        impl<S> $MatrixN<S> {
+            /// Apply a function to each element of the matrix, returning a
+            /// matrix of the results.
+            #[inline]
+            pub fn map<U, F: FnMut(S) -> U>(self, mut f: F) -> $MatrixN<U> {
+                $MatrixN { $($field: self.$field.map(&mut f)),+ }
+            }
+
+            /// Apply a function to each pair of corresponding elements of
+            /// this matrix and `other`, returning a matrix of the results.
+            #[inline]
+            pub fn zip<T, U, F: FnMut(S, T) -> U>(self, other: $MatrixN<T>, mut f: F) -> $MatrixN<U> {
+                $MatrixN { $($field: self.$field.zip(other.$field, &mut f)),+ }
+            }
+        }
+
+        impl<S: BaseFloat> $MatrixN<S> {
+            /// Returns `true` if every element is exactly zero.
+            #[inline]
+            pub fn is_zero(&self) -> bool {
+                $(self.$field.is_zero())&&+
+            }
+
+            /// Returns `true` if none of the elements are infinite or NaN.
+            #[inline]
+            pub fn is_finite(&self) -> bool {
+                $(self.$field.is_finite())&&+
+            }
+        }
+    }
+}
+
+impl_componentwise!(Matrix2 { x, y });
+impl_componentwise!(Matrix3 { x, y, z });
+impl_componentwise!(Matrix4 { x, y, z, w });
+
+macro_rules! impl_column_operations {
+    ($MatrixN:ident, $VectorN:ident { $($field:ident : $index:expr),+ }) => {
+        impl<S: BaseFloat> $MatrixN<S> {
+            /// Returns `true` if the matrix is a proper rotation: its columns
+            /// are orthonormal and its determinant is one. Matrices with a
+            /// scale, shear, translation, or reflection are not rotations.
+            ///
+            /// A rotating `Matrix4` can also mix in the `w` coordinate, so use
+            /// `is_affine` as well to test for a rotation in three dimensions.
+            #[inline]
+            pub fn is_rotation(&self) -> bool {
+                self.is_orthonormal(S::approx_epsilon()) && self.determinant() > S::zero()
+            }
+
+            /// Returns `true` if the columns are mutually orthogonal, which is
+            /// when the cosine of the angle between each pair is within
+            /// `epsilon` of zero. Such a matrix is a rotation or reflection
+            /// combined with a scale along each axis, without any shear.
+            pub fn is_orthogonal(&self, epsilon: S) -> bool {
+                let cols = [$(self.$field),+];
+                for i in 0..cols.len() {
+                    for j in (i + 1)..cols.len() {
+                        let bound = epsilon * (cols[i].length2() * cols[j].length2()).sqrt();
+                        if !(cols[i].dot(cols[j]).abs() <= bound) {
+                            return false;
+                        }
+                    }
+                }
+                true
+            }
+
+            /// Returns `true` if the columns are mutually orthogonal and of
+            /// unit length, within `epsilon`. Such a matrix is a rotation or
+            /// a reflection, and its inverse is its transpose.
+            pub fn is_orthonormal(&self, epsilon: S) -> bool {
+                let cols = [$(self.$field),+];
+                for i in 0..cols.len() {
+                    if !((cols[i].length2() - S::one()).abs() <= epsilon) {
+                        return false;
+                    }
+                    for j in (i + 1)..cols.len() {
+                        if !(cols[i].dot(cols[j]).abs() <= epsilon) {
+                            return false;
+                        }
+                    }
+                }
+                true
+            }
+
+            /// Returns `true` if the matrix reverses orientation, which is
+            /// when its determinant is negative.
+            #[inline]
+            pub fn is_reflection(&self) -> bool {
+                self.determinant() < S::zero()
+            }
+
+            /// Multiply the transpose of this matrix by `other`, without
+            /// building the transpose. Each element is the dot product of a
+            /// column of this matrix with a column of `other`, as in the
+            /// normal equations `AᵀA x = Aᵀb`.
+            pub fn transpose_mul(&self, other: &$MatrixN<S>) -> $MatrixN<S> {
+                $MatrixN { $($field: self.transpose_mul_vector(other.$field)),+ }
+            }
+
+            /// Multiply this matrix by the transpose of `other`, without
+            /// building the transpose. `a.mul_transpose(&a)` sums the outer
+            /// products of the columns of `a`, as for a covariance matrix.
+            pub fn mul_transpose(&self, other: &$MatrixN<S>) -> $MatrixN<S> {
+                $MatrixN { $($field: *self * other.row($index)),+ }
+            }
+
+            /// The transpose of this matrix multiplied by `v`: the dot
+            /// products of each column with `v`.
+            #[inline]
+            fn transpose_mul_vector(&self, v: $VectorN<S>) -> $VectorN<S> {
+                $VectorN::new($(self.$field.dot(v)),+)
+            }
+
+            /// The product of the squared lengths of the columns.
+            #[inline]
+            pub(crate) fn column_length2_product(&self) -> S {
+                S::one() $(* self.$field.length2())+
+            }
+        }
+    }
+}
+
+impl_column_operations!(Matrix2, Vector2 { x: 0, y: 1 });
+impl_column_operations!(Matrix3, Vector3 { x: 0, y: 1, z: 2 });
+impl_column_operations!(Matrix4, Vector4 { x: 0, y: 1, z: 2, w: 3 });
+
+macro_rules! mixed_precision {
+    ($MatrixN:ident { $($field:ident),+ }) => {
+        impl<S: NumCast + Copy> $MatrixN<S> {
+            /// Component-wise casting to another type
+            #[inline]
+            pub fn cast<T: NumCast>(&self) -> $MatrixN<T> {
+                $MatrixN { $($field: self.$field.cast()),+ }
+            }
+        }
+
+        impl $MatrixN<f32> {
+            /// The determinant of the matrix, computed in `f64` precision and
+            /// rounded to `f32`.
+            #[inline]
+            pub fn precise_determinant(&self) -> f32 {
+                self.cast::<f64>().determinant() as f32
+            }
+
+            /// The inverse of the matrix, computed in `f64` precision and
+            /// rounded to `f32`. Returns `None` if the matrix is not
+            /// invertible.
+            #[inline]
+            pub fn precise_invert(&self) -> Option<$MatrixN<f32>> {
+                self.cast::<f64>().invert().map(|m| m.cast())
+            }
+
+            /// The product of two matrices, computed in `f64` precision and
+            /// rounded to `f32`. Useful for long chains of transforms where
+            /// the rounding errors of `f32` arithmetic would accumulate.
+            #[inline]
+            pub fn precise_mul(&self, other: &$MatrixN<f32>) -> $MatrixN<f32> {
+                (self.cast::<f64>() * other.cast::<f64>()).cast()
+            }
+        }
+    }
+}
+
+mixed_precision!(Matrix2 { x, y });
+mixed_precision!(Matrix3 { x, y, z });
+mixed_precision!(Matrix4 { x, y, z, w });
+
+/// An iterator over the columns of a matrix, created by the `columns` method.
+#[derive(Copy, Clone)]
+pub struct Columns<'a, M: 'a> {
+    matrix: &'a M,
+    index: usize,
+    len: usize,
+}
+
+impl<'a, M: Matrix> Iterator for Columns<'a, M> {
+    type Item = &'a M::Column;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a M::Column> {
+        if self.index < self.len {
+            self.index += 1;
+            Some(&self.matrix[self.index - 1])
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, M: Matrix> ExactSizeIterator for Columns<'a, M> {}
+
+/// An iterator over the rows of a matrix, created by the `rows` method.
+#[derive(Copy, Clone)]
+pub struct Rows<'a, M: 'a> {
+    matrix: &'a M,
+    index: usize,
+    len: usize,
+}
+
+impl<'a, M: Matrix> Iterator for Rows<'a, M> {
+    type Item = M::Row;
+
+    #[inline]
+    fn next(&mut self) -> Option<M::Row> {
+        if self.index < self.len {
+            self.index += 1;
+            Some(self.matrix.row(self.index - 1))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, M: Matrix> ExactSizeIterator for Rows<'a, M> {}
+
+/// An iterator over the elements of a matrix in column-major order, created
+/// by the `elements` method.
+#[derive(Copy, Clone)]
+pub struct Elements<'a, M: 'a> {
+    matrix: &'a M,
+    index: usize,
+    rows: usize,
+    len: usize,
+}
+
+impl<'a, M: Matrix> Iterator for Elements<'a, M> {
+    type Item = &'a M::Element;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a M::Element> {
+        if self.index < self.len {
+            let (c, r) = (self.index / self.rows, self.index % self.rows);
+            self.index += 1;
+            Some(&self.matrix[c][r])
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, M: Matrix> ExactSizeIterator for Elements<'a, M> {}
+
+macro_rules! impl_iterators {
+    ($MatrixN:ident, $n:expr) => {
+        impl<S: BaseFloat> $MatrixN<S> {
+            /// Iterate over the columns of the matrix.
+            #[inline]
+            pub fn columns<'a>(&'a self) -> Columns<'a, $MatrixN<S>> {
+                Columns { matrix: self, index: 0, len: $n }
+            }
+
+            /// Iterate over the rows of the matrix.
+            #[inline]
+            pub fn rows<'a>(&'a self) -> Rows<'a, $MatrixN<S>> {
+                Rows { matrix: self, index: 0, len: $n }
+            }
+
+            /// Iterate over the elements of the matrix in column-major order,
+            /// the same order they are laid out in memory.
+            #[inline]
+            pub fn elements<'a>(&'a self) -> Elements<'a, $MatrixN<S>> {
+                Elements { matrix: self, index: 0, rows: $n, len: $n * $n }
+            }
+        }
+    }
+}
+
+impl_iterators!(Matrix2, 2);
+impl_iterators!(Matrix3, 3);
+impl_iterators!(Matrix4, 4);
+
 impl<S: BaseFloat> From<Matrix2<S>> for Matrix3<S> {
     /// Clone the elements of a 2-dimensional matrix into the top-left corner
     /// of a 3-dimensional identity matrix.
@@ -1069,11 +1925,13 @@ impl<S: BaseFloat> From<Matrix3<S>> for Matrix4<S> {
 }
 
 impl<S: BaseFloat> From<Matrix3<S>> for Quaternion<S> {
-    /// Convert the matrix to a quaternion
+    /// Convert a rotation matrix to a quaternion. The matrix is not checked;
+    /// use `Quaternion::from_rotation_matrix` or `from_scaled_matrix` for
+    /// matrices that may not be rotations.
     fn from(mat: Matrix3<S>) -> Quaternion<S> {
         // http://www.cs.ucr.edu/~vbz/resources/quatut.pdf
         let trace = mat.trace();
-        let half: S = cast(0.5f64).unwrap();
+        let half = S::half();
 
         if trace >= S::zero() {
             let s = (S::one() + trace).sqrt();
@@ -1084,33 +1942,64 @@ impl<S: BaseFloat> From<Matrix3<S>> for Quaternion<S> {
             let z = (mat[0][1] - mat[1][0]) * s;
             Quaternion::new(w, x, y, z)
         } else if (mat[0][0] > mat[1][1]) && (mat[0][0] > mat[2][2]) {
-            let s = (half + (mat[0][0] - mat[1][1] - mat[2][2])).sqrt();
-            let w = half * s;
+            let s = (S::one() + mat[0][0] - mat[1][1] - mat[2][2]).sqrt();
+            let x = half * s;
             let s = half / s;
-            let x = (mat[0][1] - mat[1][0]) * s;
-            let y = (mat[2][0] - mat[0][2]) * s;
-            let z = (mat[1][2] - mat[2][1]) * s;
+            let w = (mat[1][2] - mat[2][1]) * s;
+            let y = (mat[1][0] + mat[0][1]) * s;
+            let z = (mat[2][0] + mat[0][2]) * s;
             Quaternion::new(w, x, y, z)
         } else if mat[1][1] > mat[2][2] {
-            let s = (half + (mat[1][1] - mat[0][0] - mat[2][2])).sqrt();
-            let w = half * s;
+            let s = (S::one() + mat[1][1] - mat[0][0] - mat[2][2]).sqrt();
+            let y = half * s;
             let s = half / s;
-            let x = (mat[0][1] - mat[1][0]) * s;
-            let y = (mat[1][2] - mat[2][1]) * s;
-            let z = (mat[2][0] - mat[0][2]) * s;
+            let w = (mat[2][0] - mat[0][2]) * s;
+            let x = (mat[1][0] + mat[0][1]) * s;
+            let z = (mat[2][1] + mat[1][2]) * s;
             Quaternion::new(w, x, y, z)
         } else {
-            let s = (half + (mat[2][2] - mat[0][0] - mat[1][1])).sqrt();
-            let w = half * s;
+            let s = (S::one() + mat[2][2] - mat[0][0] - mat[1][1]).sqrt();
+            let z = half * s;
             let s = half / s;
-            let x = (mat[2][0] - mat[0][2]) * s;
-            let y = (mat[1][2] - mat[2][1]) * s;
-            let z = (mat[0][1] - mat[1][0]) * s;
+            let w = (mat[0][1] - mat[1][0]) * s;
+            let x = (mat[2][0] + mat[0][2]) * s;
+            let y = (mat[2][1] + mat[1][2]) * s;
             Quaternion::new(w, x, y, z)
         }
     }
 }
 
+macro_rules! impl_matrix_display_parse {
+    ($MatrixN:ident { $($field:ident),+ }, $n:expr) => {
+        impl<S: fmt::Display> fmt::Display for $MatrixN<S> {
+            /// Write the matrix one row per line, with the columns aligned.
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let mut elements = Vec::with_capacity($n * $n);
+                for r in 0..$n {
+                    $(elements.push(&self.$field[r]);)+
+                }
+                parse::write_rows(f, $n, &elements)
+            }
+        }
+
+        impl<S: Copy + FromStr> FromStr for $MatrixN<S> {
+            type Err = ParseError;
+
+            /// Parse a matrix written one bracketed row at a time, as it is
+            /// formatted by `Display`.
+            fn from_str(s: &str) -> Result<$MatrixN<S>, ParseError> {
+                let rows: Vec<S> = parse::parse_rows(s, $n)?;
+                let columns: Vec<S> = (0..$n * $n).map(|i| rows[(i % $n) * $n + i / $n]).collect();
+                Ok($MatrixN::from_slice(&columns).unwrap())
+            }
+        }
+    }
+}
+
+impl_matrix_display_parse!(Matrix2 { x, y }, 2);
+impl_matrix_display_parse!(Matrix3 { x, y, z }, 3);
+impl_matrix_display_parse!(Matrix4 { x, y, z, w }, 4);
+
 impl<S: BaseFloat> fmt::Debug for Matrix2<S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "[[{:?}, {:?}], [{:?}, {:?}]]",
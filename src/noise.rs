@@ -0,0 +1,175 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Gradient noise for procedural terrain and textures.
+//!
+//! The noise functions are Ken Perlin's [improved noise]
+//! (http://mrl.nyu.edu/~perlin/noise/), which is smooth, repeats every 256
+//! units along each axis, and is zero at every integer lattice point. The
+//! `fbm` and `turbulence` combinators sum octaves of any of them.
+//!
+//! The noise is NaN if any coordinate is NaN or infinite.
+
+use std::ops::Mul;
+
+use rust_num::traits::cast;
+
+use num::BaseFloat;
+use vector::{Vector2, Vector3};
+
+/// Ken Perlin's reference permutation of the integers `0..256`.
+static PERMUTATION: [u8; 256] = [
+    151, 160, 137, 91, 90, 15, 131, 13, 201, 95, 96, 53, 194, 233, 7, 225, 140,
+    36, 103, 30, 69, 142, 8, 99, 37, 240, 21, 10, 23, 190, 6, 148, 247, 120,
+    234, 75, 0, 26, 197, 62, 94, 252, 219, 203, 117, 35, 11, 32, 57, 177, 33,
+    88, 237, 149, 56, 87, 174, 20, 125, 136, 171, 168, 68, 175, 74, 165, 71,
+    134, 139, 48, 27, 166, 77, 146, 158, 231, 83, 111, 229, 122, 60, 211, 133,
+    230, 220, 105, 92, 41, 55, 46, 245, 40, 244, 102, 143, 54, 65, 25, 63, 161,
+    1, 216, 80, 73, 209, 76, 132, 187, 208, 89, 18, 169, 200, 196, 135, 130,
+    116, 188, 159, 86, 164, 100, 109, 198, 173, 186, 3, 64, 52, 217, 226, 250,
+    124, 123, 5, 202, 38, 147, 118, 126, 255, 82, 85, 212, 207, 206, 59, 227,
+    47, 16, 58, 17, 182, 189, 28, 42, 223, 183, 170, 213, 119, 248, 152, 2, 44,
+    154, 163, 70, 221, 153, 101, 155, 167, 43, 172, 9, 129, 22, 39, 253, 19, 98,
+    108, 110, 79, 113, 224, 232, 178, 185, 112, 104, 218, 246, 97, 228, 251, 34,
+    242, 193, 238, 210, 144, 12, 191, 179, 162, 241, 81, 51, 145, 235, 249, 14,
+    239, 107, 49, 192, 214, 31, 181, 199, 106, 157, 184, 84, 204, 176, 115, 121,
+    50, 45, 127, 4, 150, 254, 138, 236, 205, 93, 222, 114, 67, 29, 24, 72, 243,
+    141, 128, 195, 78, 66, 215, 61, 156, 180
+];
+
+#[inline]
+fn perm(i: usize) -> usize {
+    PERMUTATION[i & 255] as usize
+}
+
+/// Split a coordinate into its lattice cell, wrapped to `0..256`, and the
+/// fractional offset within the cell. The cell is wrapped before converting
+/// it, so that coordinates too large for an integer still work. Non-finite
+/// coordinates are put in cell zero, and their offset is NaN.
+#[inline]
+fn lattice<S: BaseFloat>(x: S) -> (usize, S) {
+    let floor = x.floor();
+    let period: S = cast(256).unwrap();
+    let cell = floor - (floor / period).floor() * period;
+    (cast(cell).unwrap_or(0), x - floor)
+}
+
+/// The quintic `6t⁵ - 15t⁴ + 10t³`, which has zero first and second
+/// derivatives at both ends, so the noise is smooth across cells.
+#[inline]
+fn fade<S: BaseFloat>(t: S) -> S {
+    let six: S = cast(6).unwrap();
+    let fifteen: S = cast(15).unwrap();
+    let ten: S = cast(10).unwrap();
+    t * t * t * (t * (t * six - fifteen) + ten)
+}
+
+#[inline]
+fn lerp<S: BaseFloat>(t: S, a: S, b: S) -> S {
+    a + t * (b - a)
+}
+
+/// The dot product of the offset with one of twelve gradients along the edges
+/// of a cube, picked by the hash.
+#[inline]
+fn grad<S: BaseFloat>(hash: usize, x: S, y: S, z: S) -> S {
+    let h = hash & 15;
+    let u = if h < 8 { x } else { y };
+    let v = if h < 4 { y } else if h == 12 || h == 14 { x } else { z };
+    (if h & 1 == 0 { u } else { -u }) + (if h & 2 == 0 { v } else { -v })
+}
+
+/// Two dimensional gradient noise, approximately in the range `[-1, 1]`.
+///
+/// This is the same as `perlin3` in the plane `z = 0`.
+pub fn perlin2<S: BaseFloat>(p: Vector2<S>) -> S {
+    let (xi, x) = lattice(p.x);
+    let (yi, y) = lattice(p.y);
+    let (u, v) = (fade(x), fade(y));
+    let (x1, y1) = (x - S::one(), y - S::one());
+
+    let a = perm(xi) + yi;
+    let b = perm(xi + 1) + yi;
+    let (aa, ab) = (perm(perm(a)), perm(perm(a + 1)));
+    let (ba, bb) = (perm(perm(b)), perm(perm(b + 1)));
+
+    lerp(v, lerp(u, grad(aa, x, y, S::zero()), grad(ba, x1, y, S::zero())),
+            lerp(u, grad(ab, x, y1, S::zero()), grad(bb, x1, y1, S::zero())))
+}
+
+/// Three dimensional gradient noise, approximately in the range `[-1, 1]`.
+pub fn perlin3<S: BaseFloat>(p: Vector3<S>) -> S {
+    let (xi, x) = lattice(p.x);
+    let (yi, y) = lattice(p.y);
+    let (zi, z) = lattice(p.z);
+    let (u, v, w) = (fade(x), fade(y), fade(z));
+    let (x1, y1, z1) = (x - S::one(), y - S::one(), z - S::one());
+
+    let a = perm(xi) + yi;
+    let b = perm(xi + 1) + yi;
+    let (aa, ab) = (perm(a) + zi, perm(a + 1) + zi);
+    let (ba, bb) = (perm(b) + zi, perm(b + 1) + zi);
+
+    lerp(w, lerp(v, lerp(u, grad(perm(aa), x, y, z), grad(perm(ba), x1, y, z)),
+                    lerp(u, grad(perm(ab), x, y1, z), grad(perm(bb), x1, y1, z))),
+            lerp(v, lerp(u, grad(perm(aa + 1), x, y, z1), grad(perm(ba + 1), x1, y, z1)),
+                    lerp(u, grad(perm(ab + 1), x, y1, z1), grad(perm(bb + 1), x1, y1, z1))))
+}
+
+/// Fractional Brownian motion: the sum of `octaves` samples of `noise`, where
+/// each octave's frequency is multiplied by `lacunarity`, and its amplitude by
+/// `gain`. The first octave is sampled at `p` with an amplitude of one.
+///
+/// A `lacunarity` of `2` and a `gain` of `0.5` are typical.
+///
+/// ```rust
+/// use cgmath::{Vector2, fbm, perlin2};
+///
+/// let height = fbm(perlin2, Vector2::new(0.3f32, 1.7), 6, 2.0, 0.5);
+/// ```
+pub fn fbm<V, S, F>(noise: F, p: V, octaves: u32, lacunarity: S, gain: S) -> S where
+    V: Copy + Mul<S, Output = V>,
+    S: BaseFloat,
+    F: Fn(V) -> S,
+{
+    octaves_sum(noise, p, octaves, lacunarity, gain, |n| n)
+}
+
+/// Like `fbm`, but summing the absolute values of the octaves, which gives
+/// creases where the noise crosses zero, as in flames and marble.
+pub fn turbulence<V, S, F>(noise: F, p: V, octaves: u32, lacunarity: S, gain: S) -> S where
+    V: Copy + Mul<S, Output = V>,
+    S: BaseFloat,
+    F: Fn(V) -> S,
+{
+    octaves_sum(noise, p, octaves, lacunarity, gain, |n| n.abs())
+}
+
+fn octaves_sum<V, S, F, G>(noise: F, p: V, octaves: u32, lacunarity: S, gain: S, shape: G) -> S where
+    V: Copy + Mul<S, Output = V>,
+    S: BaseFloat,
+    F: Fn(V) -> S,
+    G: Fn(S) -> S,
+{
+    let mut sum = S::zero();
+    let mut frequency = S::one();
+    let mut amplitude = S::one();
+    for _ in 0..octaves {
+        sum = sum + shape(noise(p * frequency)) * amplitude;
+        frequency = frequency * lacunarity;
+        amplitude = amplitude * gain;
+    }
+    sum
+}
@@ -16,9 +16,12 @@
 use approx::ApproxEq;
 
 use std::cmp;
+use std::cmp::Ordering;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 
 use rust_num::{Float, Num, NumCast};
+use rust_num::traits::cast;
 
 /// A trait providing a [partial ordering](http://mathworld.wolfram.com/PartialOrder.html).
 pub trait PartialOrd {
@@ -58,6 +61,108 @@ macro_rules! partial_ord_float (
 partial_ord_float!(f32);
 partial_ord_float!(f64);
 
+/// Restrict `value` to the range `[lo, hi]`.
+#[inline]
+pub fn clamp<S: PartialOrd>(value: S, lo: S, hi: S) -> S {
+    value.partial_max(lo).partial_min(hi)
+}
+
+/// A trait providing a [total ordering](https://en.wikipedia.org/wiki/Total_order).
+///
+/// For floating point numbers this is the `totalOrder` predicate from IEEE
+/// 754-2008, which orders `-NaN < -∞ < ... < -0.0 < +0.0 < ... < +∞ < +NaN`.
+/// Unlike `cmp::PartialOrd` it never fails, so it can be used to sort and
+/// deduplicate values that might contain signed zeroes or NaNs.
+pub trait TotalOrd {
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+macro_rules! total_ord_int (
+    ($T:ident) => (
+        impl TotalOrd for $T {
+            #[inline]
+            fn total_cmp(&self, other: &$T) -> Ordering { cmp::Ord::cmp(self, other) }
+        }
+    )
+);
+
+total_ord_int!(isize);
+total_ord_int!(i8);
+total_ord_int!(i16);
+total_ord_int!(i32);
+total_ord_int!(i64);
+total_ord_int!(usize);
+total_ord_int!(u8);
+total_ord_int!(u16);
+total_ord_int!(u32);
+total_ord_int!(u64);
+
+macro_rules! total_ord_float (
+    ($T:ident, $I:ident, $U:ident, $bits:expr) => (
+        impl TotalOrd for $T {
+            #[inline]
+            fn total_cmp(&self, other: &$T) -> Ordering {
+                // Flip the magnitude bits of negative numbers so that the bit
+                // patterns compare in the same order as the values.
+                let key = |x: $T| -> $I {
+                    let bits = x.to_bits() as $I;
+                    bits ^ ((((bits >> ($bits - 1)) as $U) >> 1) as $I)
+                };
+                key(*self).cmp(&key(*other))
+            }
+        }
+    )
+);
+
+total_ord_float!(f32, i32, u32, 32);
+total_ord_float!(f64, i64, u64, 64);
+
+
+/// Scalars that can be hashed, so that the vector, point, matrix, and
+/// quaternion types can be used as keys in hash maps and sets.
+///
+/// Floating point numbers are hashed by their exact bit patterns, except that
+/// `-0.0` is hashed like `+0.0`, since they compare equal. A NaN compares
+/// unequal to everything, including itself, so a key containing one can never
+/// be found again.
+pub trait HashBits: PartialEq {
+    fn hash_bits<H: Hasher>(&self, state: &mut H);
+}
+
+macro_rules! hash_bits_int (
+    ($T:ident) => (
+        impl HashBits for $T {
+            #[inline]
+            fn hash_bits<H: Hasher>(&self, state: &mut H) { self.hash(state) }
+        }
+    )
+);
+
+hash_bits_int!(isize);
+hash_bits_int!(i8);
+hash_bits_int!(i16);
+hash_bits_int!(i32);
+hash_bits_int!(i64);
+hash_bits_int!(usize);
+hash_bits_int!(u8);
+hash_bits_int!(u16);
+hash_bits_int!(u32);
+hash_bits_int!(u64);
+
+macro_rules! hash_bits_float (
+    ($T:ident) => (
+        impl HashBits for $T {
+            #[inline]
+            fn hash_bits<H: Hasher>(&self, state: &mut H) {
+                let value = if *self == 0.0 { 0.0 } else { *self };
+                value.to_bits().hash(state)
+            }
+        }
+    )
+);
+
+hash_bits_float!(f32);
+hash_bits_float!(f64);
 
 /// Base numeric types with partial ordering
 pub trait BaseNum:
@@ -109,7 +214,25 @@ impl BaseInt for u64 {}
 impl BaseInt for usize {}
 
 /// Base floating point types
-pub trait BaseFloat : BaseNum + Float + ApproxEq<Epsilon = Self> {}
+pub trait BaseFloat : BaseNum + Float + ApproxEq<Epsilon = Self> {
+    /// One half. The default converts it with `cast` on every call, which
+    /// does not always fold away, so the built-in floats return a literal.
+    #[inline]
+    fn half() -> Self { cast(0.5f64).unwrap() }
+
+    /// Two. See `half` for why this is a method.
+    #[inline]
+    fn two() -> Self { cast(2.0f64).unwrap() }
+}
+
+macro_rules! impl_basefloat (
+    ($T: ident) => (
+        impl BaseFloat for $T {
+            #[inline] fn half() -> $T { 0.5 }
+            #[inline] fn two() -> $T { 2.0 }
+        }
+    )
+);
 
-impl BaseFloat for f32 {}
-impl BaseFloat for f64 {}
+impl_basefloat!(f32);
+impl_basefloat!(f64);
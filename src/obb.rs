@@ -0,0 +1,46 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use matrix::Matrix3;
+use num::BaseFloat;
+use point::Point3;
+use vector::Vector3;
+
+/// An oriented bounding box.
+#[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+pub struct Obb3<S> {
+    pub center: Point3<S>,
+    /// The local axes of the box, stored as the orthonormal columns of a
+    /// rotation matrix.
+    pub axes: Matrix3<S>,
+    /// Half of the box's size along each of its local axes.
+    pub half_extents: Vector3<S>,
+}
+
+impl<S: BaseFloat> Obb3<S> {
+    /// Create a new oriented bounding box.
+    #[inline]
+    pub fn new(center: Point3<S>, axes: Matrix3<S>, half_extents: Vector3<S>) -> Obb3<S> {
+        Obb3 { center: center, axes: axes, half_extents: half_extents }
+    }
+}
+
+impl<S: BaseFloat> fmt::Debug for Obb3<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Obb3({:?}, {:?}, {:?})", self.center, self.axes, self.half_extents)
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Packing of vectors into normalized integers, for compressing vertex
+//! attributes and colors.
+//!
+//! The layouts match the GLSL `pack*` and `unpack*` functions: the first
+//! component is stored in the least significant bits. Components are clamped
+//! to the representable range and rounded to the nearest integer.
+
+use vector::{Vector2, Vector4};
+
+/// Pack a value in `[0, 1]` into an unsigned integer in `[0, max]`.
+#[inline]
+fn pack_unorm(value: f32, max: f32) -> u32 {
+    (value.max(0.0).min(1.0) * max).round() as u32
+}
+
+/// Pack a value in `[-1, 1]` into the low `bits` bits of a two's complement
+/// integer in `[-max, max]`.
+#[inline]
+fn pack_snorm(value: f32, max: f32, bits: u32) -> u32 {
+    let packed = (value.max(-1.0).min(1.0) * max).round() as i32;
+    packed as u32 & ((1 << bits) - 1)
+}
+
+/// Unpack an unsigned integer in `[0, max]` to a value in `[0, 1]`.
+#[inline]
+fn unpack_unorm(packed: u32, max: f32) -> f32 {
+    packed as f32 / max
+}
+
+/// Unpack the two's complement integer in the low `bits` bits of `packed` to
+/// a value in `[-1, 1]`. Both `-max - 1` and `-max` unpack to `-1`.
+#[inline]
+fn unpack_snorm(packed: u32, max: f32, bits: u32) -> f32 {
+    let shift = 32 - bits;
+    let value = ((packed << shift) as i32 >> shift) as f32;
+    (value / max).max(-1.0)
+}
+
+impl Vector4<f32> {
+    /// Pack the components, clamped to `[0, 1]`, into 8 bits each, as for an
+    /// `RGBA8` color.
+    pub fn pack_unorm4x8(&self) -> u32 {
+        pack_unorm(self.x, 255.0) |
+        pack_unorm(self.y, 255.0) << 8 |
+        pack_unorm(self.z, 255.0) << 16 |
+        pack_unorm(self.w, 255.0) << 24
+    }
+
+    /// Unpack four 8-bit unsigned normalized components.
+    pub fn unpack_unorm4x8(packed: u32) -> Vector4<f32> {
+        Vector4::new(unpack_unorm(packed & 0xff, 255.0),
+                     unpack_unorm(packed >> 8 & 0xff, 255.0),
+                     unpack_unorm(packed >> 16 & 0xff, 255.0),
+                     unpack_unorm(packed >> 24, 255.0))
+    }
+
+    /// Pack the components, clamped to `[-1, 1]`, into 8 bits each.
+    pub fn pack_snorm4x8(&self) -> u32 {
+        pack_snorm(self.x, 127.0, 8) |
+        pack_snorm(self.y, 127.0, 8) << 8 |
+        pack_snorm(self.z, 127.0, 8) << 16 |
+        pack_snorm(self.w, 127.0, 8) << 24
+    }
+
+    /// Unpack four 8-bit signed normalized components.
+    pub fn unpack_snorm4x8(packed: u32) -> Vector4<f32> {
+        Vector4::new(unpack_snorm(packed, 127.0, 8),
+                     unpack_snorm(packed >> 8, 127.0, 8),
+                     unpack_snorm(packed >> 16, 127.0, 8),
+                     unpack_snorm(packed >> 24, 127.0, 8))
+    }
+
+    /// Pack the components, clamped to `[-1, 1]`, into 10 bits each for `x`,
+    /// `y` and `z`, and 2 bits for `w`. This is commonly used for normals and
+    /// tangents, with the sign of the bitangent in `w`.
+    pub fn pack_snorm_10_10_10_2(&self) -> u32 {
+        pack_snorm(self.x, 511.0, 10) |
+        pack_snorm(self.y, 511.0, 10) << 10 |
+        pack_snorm(self.z, 511.0, 10) << 20 |
+        pack_snorm(self.w, 1.0, 2) << 30
+    }
+
+    /// Unpack three 10-bit and one 2-bit signed normalized components.
+    pub fn unpack_snorm_10_10_10_2(packed: u32) -> Vector4<f32> {
+        Vector4::new(unpack_snorm(packed, 511.0, 10),
+                     unpack_snorm(packed >> 10, 511.0, 10),
+                     unpack_snorm(packed >> 20, 511.0, 10),
+                     unpack_snorm(packed >> 30, 1.0, 2))
+    }
+}
+
+impl Vector2<f32> {
+    /// Pack the components, clamped to `[0, 1]`, into 16 bits each.
+    pub fn pack_unorm2x16(&self) -> u32 {
+        pack_unorm(self.x, 65535.0) | pack_unorm(self.y, 65535.0) << 16
+    }
+
+    /// Unpack two 16-bit unsigned normalized components.
+    pub fn unpack_unorm2x16(packed: u32) -> Vector2<f32> {
+        Vector2::new(unpack_unorm(packed & 0xffff, 65535.0),
+                     unpack_unorm(packed >> 16, 65535.0))
+    }
+
+    /// Pack the components, clamped to `[-1, 1]`, into 16 bits each.
+    pub fn pack_snorm2x16(&self) -> u32 {
+        pack_snorm(self.x, 32767.0, 16) | pack_snorm(self.y, 32767.0, 16) << 16
+    }
+
+    /// Unpack two 16-bit signed normalized components.
+    pub fn unpack_snorm2x16(packed: u32) -> Vector2<f32> {
+        Vector2::new(unpack_snorm(packed, 32767.0, 16),
+                     unpack_snorm(packed >> 16, 32767.0, 16))
+    }
+}
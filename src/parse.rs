@@ -0,0 +1,105 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Formatting and parsing of vectors, points, and matrices as text.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// An error returned when parsing a vector, point, or matrix from a string.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The string was missing a bracket or parenthesis.
+    Syntax,
+    /// The string had the wrong number of components or rows.
+    Length,
+    /// A component could not be parsed as a scalar.
+    Component,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            ParseError::Syntax => "missing bracket or parenthesis",
+            ParseError::Length => "wrong number of components",
+            ParseError::Component => "invalid component",
+        })
+    }
+}
+
+impl Error for ParseError {}
+
+/// Write the components of a vector or point as `(x, y, z)`, passing the
+/// formatter's flags on to each component.
+pub fn write_tuple<S: fmt::Display>(f: &mut fmt::Formatter, components: &[S]) -> fmt::Result {
+    f.write_str("(")?;
+    for (i, c) in components.iter().enumerate() {
+        if i > 0 { f.write_str(", ")?; }
+        fmt::Display::fmt(c, f)?;
+    }
+    f.write_str(")")
+}
+
+/// Write the elements of an `n`-by-`n` matrix, given in row-major order, as
+/// one bracketed row per line, with each column right-aligned.
+pub fn write_rows<S: fmt::Display>(f: &mut fmt::Formatter, n: usize, elements: &[S]) -> fmt::Result {
+    let strings: Vec<String> = elements.iter().map(|e| match f.precision() {
+        Some(p) => format!("{:.*}", p, e),
+        None => format!("{}", e),
+    }).collect();
+    let widths: Vec<usize> = (0..n).map(|c| {
+        (0..n).map(|r| strings[r * n + c].chars().count()).max().unwrap_or(0)
+    }).collect();
+
+    for r in 0..n {
+        if r > 0 { f.write_str("\n")?; }
+        f.write_str("[")?;
+        for c in 0..n {
+            if c > 0 { f.write_str(", ")?; }
+            write!(f, "{:>1$}", strings[r * n + c], widths[c])?;
+        }
+        f.write_str("]")?;
+    }
+    Ok(())
+}
+
+/// Parse `n` comma-separated components enclosed by `open` and `close`.
+pub fn parse_list<S: FromStr>(s: &str, open: char, close: char, n: usize) -> Result<Vec<S>, ParseError> {
+    let s = s.trim();
+    if !s.starts_with(open) || !s.ends_with(close) || s.len() < 2 {
+        return Err(ParseError::Syntax);
+    }
+    let components: Vec<S> = s[1..s.len() - 1].split(',')
+        .map(|c| c.trim().parse().map_err(|_| ParseError::Component))
+        .collect::<Result<_, _>>()?;
+    if components.len() == n { Ok(components) } else { Err(ParseError::Length) }
+}
+
+/// Parse an `n`-by-`n` matrix written as `n` bracketed rows, as produced by
+/// `write_rows`, returning the elements in row-major order.
+pub fn parse_rows<S: FromStr>(s: &str, n: usize) -> Result<Vec<S>, ParseError> {
+    let mut elements = Vec::with_capacity(n * n);
+    let mut rest = s.trim();
+    while !rest.is_empty() {
+        let end = match rest.find(']') {
+            Some(end) => end + 1,
+            None => return Err(ParseError::Syntax),
+        };
+        elements.extend(parse_list::<S>(&rest[..end], '[', ']', n)?);
+        rest = rest[end..].trim_start();
+    }
+    if elements.len() == n * n { Ok(elements) } else { Err(ParseError::Length) }
+}
@@ -0,0 +1,134 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use approx::ApproxEq;
+use matrix::{Matrix, SquareMatrix, Matrix4};
+use num::BaseFloat;
+use point::{Point, Point3};
+use triangle;
+use vector::{EuclideanVector, Vector3, Vector4};
+
+/// A 3-dimensional plane, made up of the points `p` satisfying the equation
+/// `n.dot(p) = d`, or `a*x + b*y + c*z - d = 0`.
+///
+/// The normal `n` does not have to be a unit vector, but when it is, `d` is
+/// the distance of the plane from the origin along `n`, and `distance` gives
+/// the true signed distance of a point from the plane.
+#[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+pub struct Plane<S> {
+    pub n: Vector3<S>,
+    pub d: S,
+}
+
+impl<S: BaseFloat> Plane<S> {
+    /// Create a plane from a normal vector and its offset along it.
+    #[inline]
+    pub fn new(n: Vector3<S>, d: S) -> Plane<S> {
+        Plane { n: n, d: d }
+    }
+
+    /// Create a plane from the coefficients of its equation
+    /// `a*x + b*y + c*z - d = 0`.
+    #[inline]
+    pub fn from_abcd(a: S, b: S, c: S, d: S) -> Plane<S> {
+        Plane::new(Vector3::new(a, b, c), d)
+    }
+
+    /// Create the plane through `point` with the normal `n`.
+    #[inline]
+    pub fn from_point_normal(point: Point3<S>, n: Vector3<S>) -> Plane<S> {
+        Plane::new(n, point.dot(n))
+    }
+
+    /// Create the plane through three points, with a unit normal facing the
+    /// side from which they are wound counter-clockwise. Returns `None` if
+    /// the points are collinear, or so close to collinear that the area of
+    /// their triangle is lost in rounding error, as in `barycentric`.
+    pub fn from_points(a: Point3<S>, b: Point3<S>, c: Point3<S>) -> Option<Plane<S>> {
+        let (ab, ac) = (b - a, c - a);
+        let n = ab.cross(ac);
+        let longest = ab.length2().partial_max(ac.length2()).partial_max((c - b).length2());
+        if triangle::is_degenerate(n.length2(), longest) {
+            None
+        } else {
+            Some(Plane::from_point_normal(a, n.normalize()))
+        }
+    }
+
+    /// Scale the plane equation so that the normal is a unit vector. Returns
+    /// `None` if the normal is zero.
+    pub fn normalize(&self) -> Option<Plane<S>> {
+        let length = self.n.length();
+        if length == S::zero() {
+            None
+        } else {
+            Some(Plane::new(self.n / length, self.d / length))
+        }
+    }
+
+    /// The signed distance of `point` from the plane, positive on the side
+    /// the normal points to. This is scaled by the length of the normal when
+    /// it is not a unit vector.
+    #[inline]
+    pub fn distance(&self, point: Point3<S>) -> S {
+        point.dot(self.n) - self.d
+    }
+
+    /// Transform the plane by the same matrix as the points on it, so that
+    /// `m * p` is on the result for every point `p` on this plane. Returns
+    /// `None` if the matrix is singular.
+    ///
+    /// The plane equation is multiplied by the inverse transpose of `m`,
+    /// which keeps the normal perpendicular to the plane under non-uniform
+    /// scales and shears. The normal of the result is not normalized, even if
+    /// this one is; call `normalize` to get true distances from it.
+    pub fn transform(&self, m: &Matrix4<S>) -> Option<Plane<S>> {
+        m.invert().map(|inverse| {
+            let v = inverse.transpose() * Vector4::new(self.n.x, self.n.y, self.n.z, -self.d);
+            Plane::new(v.truncate(), -v.w)
+        })
+    }
+}
+
+impl<S: BaseFloat> ApproxEq for Plane<S> {
+    type Epsilon = S;
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Plane<S>, epsilon: &S) -> bool {
+        self.n.approx_eq_eps(&other.n, epsilon) &&
+        self.d.approx_eq_eps(&other.d, epsilon)
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Plane<S>, epsilon: &S, max_relative: &S) -> bool {
+        self.n.relative_eq(&other.n, epsilon, max_relative) &&
+        self.d.relative_eq(&other.d, epsilon, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Plane<S>, epsilon: &S, max_ulps: u32) -> bool {
+        self.n.ulps_eq(&other.n, epsilon, max_ulps) &&
+        self.d.ulps_eq(&other.d, epsilon, max_ulps)
+    }
+}
+
+impl<S: BaseFloat> fmt::Debug for Plane<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}x + {:?}y + {:?}z - {:?} = 0",
+               self.n.x, self.n.y, self.n.z, self.d)
+    }
+}
@@ -17,26 +17,31 @@
 //! disinguishes them from vectors, which have a length and direction, but do
 //! not have a fixed position.
 
+use std::cmp;
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt;
 use std::mem;
 use std::ops::*;
 
-use rust_num::{One, Zero};
+use rust_num::traits::cast;
 
 use approx::ApproxEq;
 use array::Array;
-use matrix::Matrix;
-use num::{BaseNum, BaseFloat};
+use num::{BaseNum, BaseFloat, TotalOrd};
 use vector::*;
 
 /// A point in 2-dimensional space.
-#[derive(PartialEq, Eq, Copy, Clone, Hash, RustcEncodable, RustcDecodable)]
+#[derive(PartialEq, Copy, Clone, RustcEncodable, RustcDecodable)]
 pub struct Point2<S> { pub x: S, pub y: S }
 
 /// A point in 3-dimensional space.
-#[derive(PartialEq, Eq, Copy, Clone, Hash, RustcEncodable, RustcDecodable)]
+#[derive(PartialEq, Copy, Clone, RustcEncodable, RustcDecodable)]
 pub struct Point3<S> { pub x: S, pub y: S, pub z: S }
 
+impl_hash!(Point2<S> { x, y });
+impl_hash!(Point3<S> { x, y, z });
+
 
 impl<S: BaseNum> Point2<S> {
     #[inline]
@@ -99,6 +104,42 @@ pub trait Point: Copy + Clone where
 
 macro_rules! impl_point {
     ($PointN:ident { $($field:ident),+ }, $VectorN:ident, $n:expr) => {
+        impl<S: cmp::PartialOrd> $PointN<S> {
+            /// Compare this point with `other` lexicographically, component by
+            /// component. Returns `None` if the first pair of components that
+            /// differ is unordered, for example if one of them is `NaN`.
+            #[inline]
+            pub fn lex_partial_cmp(&self, other: &$PointN<S>) -> Option<Ordering> {
+                let (a, b): (&[S; $n], &[S; $n]) = (self.as_ref(), other.as_ref());
+                a.partial_cmp(b)
+            }
+        }
+
+        impl<S: cmp::Ord> $PointN<S> {
+            /// Compare this point with `other` lexicographically, component by
+            /// component.
+            #[inline]
+            pub fn lex_cmp(&self, other: &$PointN<S>) -> Ordering {
+                let (a, b): (&[S; $n], &[S; $n]) = (self.as_ref(), other.as_ref());
+                a.cmp(b)
+            }
+        }
+
+        impl<S: TotalOrd> $PointN<S> {
+            /// Compare this point with `other` lexicographically, using the
+            /// total ordering of the components. This never fails, so it can be
+            /// used to sort points of floats, for example along the sweep line
+            /// of a plane sweep algorithm.
+            #[inline]
+            pub fn total_cmp(&self, other: &$PointN<S>) -> Ordering {
+                $(match self.$field.total_cmp(&other.$field) {
+                    Ordering::Equal => {},
+                    ordering => return ordering,
+                })+
+                Ordering::Equal
+            }
+        }
+
         impl<S: BaseNum> Array for $PointN<S> {
             type Element = S;
 
@@ -133,6 +174,81 @@ macro_rules! impl_point {
             }
         }
 
+        impl<S: BaseFloat> $PointN<S> {
+            /// Returns the squared distance between the point and `other`.
+            /// This avoids a square root, so it is cheaper for comparing
+            /// distances.
+            #[inline]
+            pub fn distance2(self, other: $PointN<S>) -> S {
+                (other - self).length2()
+            }
+
+            /// The distance between the point and `other`.
+            #[inline]
+            pub fn distance(self, other: $PointN<S>) -> S {
+                (other - self).length()
+            }
+
+            /// Merge the points that lie within `epsilon` of one another.
+            ///
+            /// Returns the unique points, in order of first appearance, along
+            /// with a table mapping each of the input points to the index of the
+            /// unique point that it was merged into. Points are bucketed in a
+            /// uniform grid with a cell size of `epsilon`, so only neighbouring
+            /// cells have to be searched for each point.
+            ///
+            /// Points that are not finite, or so far away that their cell is out
+            /// of the range of `i64`, are not merged with any other point.
+            pub fn weld(points: &[$PointN<S>], epsilon: S) -> (Vec<$PointN<S>>, Vec<usize>) {
+                assert!(epsilon > S::zero(), "The welding tolerance must be greater than zero, found: {:?}", epsilon);
+
+                let epsilon2 = epsilon * epsilon;
+                let mut unique: Vec<$PointN<S>> = Vec::new();
+                let mut remap = Vec::with_capacity(points.len());
+                let mut grid: HashMap<[i64; $n], Vec<usize>> = HashMap::new();
+
+                for &point in points {
+                    let cell: [i64; $n] = match [$(cast((point.$field / epsilon).floor())),+] {
+                        [$(Some($field)),+] => [$($field),+],
+                        _ => {
+                            unique.push(point);
+                            remap.push(unique.len() - 1);
+                            continue;
+                        }
+                    };
+
+                    let mut found = None;
+                    'search: for k in 0..3usize.pow($n) {
+                        let mut neighbour = cell;
+                        for i in 0..$n {
+                            let offset = ((k / 3usize.pow(i as u32)) % 3) as i64 - 1;
+                            neighbour[i] = neighbour[i].wrapping_add(offset);
+                        }
+                        if let Some(indices) = grid.get(&neighbour) {
+                            for &index in indices {
+                                if unique[index].distance2(point) <= epsilon2 {
+                                    found = Some(index);
+                                    break 'search;
+                                }
+                            }
+                        }
+                    }
+
+                    let index = match found {
+                        Some(index) => index,
+                        None => {
+                            unique.push(point);
+                            grid.entry(cell).or_insert_with(Vec::new).push(unique.len() - 1);
+                            unique.len() - 1
+                        }
+                    };
+                    remap.push(index);
+                }
+
+                (unique, remap)
+            }
+        }
+
         impl<S: BaseFloat> ApproxEq for $PointN<S> {
             type Epsilon = S;
 
@@ -140,6 +256,16 @@ macro_rules! impl_point {
             fn approx_eq_eps(&self, other: &$PointN<S>, epsilon: &S) -> bool {
                 $(self.$field.approx_eq_eps(&other.$field, epsilon))&&+
             }
+
+            #[inline]
+            fn relative_eq(&self, other: &$PointN<S>, epsilon: &S, max_relative: &S) -> bool {
+                $(self.$field.relative_eq(&other.$field, epsilon, max_relative))&&+
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &$PointN<S>, epsilon: &S, max_ulps: u32) -> bool {
+                $(self.$field.ulps_eq(&other.$field, epsilon, max_ulps))&&+
+            }
         }
 
         impl_operator!(<S: BaseNum> Add<$VectorN<S> > for $PointN<S> {
@@ -186,6 +312,9 @@ impl_point!(Point3 { x, y, z }, Vector3, 3);
 impl_fixed_array_conversions!(Point2<S> { x: 0, y: 1 }, 2);
 impl_fixed_array_conversions!(Point3<S> { x: 0, y: 1, z: 2 }, 3);
 
+impl_display_parse!(Point2<S> { x, y }, 2);
+impl_display_parse!(Point3<S> { x, y, z }, 3);
+
 impl_tuple_conversions!(Point2<S> { x, y }, (S, S));
 impl_tuple_conversions!(Point3<S> { x, y, z }, (S, S, S));
 
@@ -13,12 +13,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use rust_num::{Zero, One};
-use rust_num::traits::cast;
-
 use angle::{Angle, Rad};
-use matrix::Matrix4;
+use matrix::{Matrix4, SquareMatrix};
 use num::BaseFloat;
+use point::{Point2, Point3};
+use vector::{Vector2, Vector4};
 
 /// Create a perspective projection matrix.
 ///
@@ -33,6 +32,43 @@ pub fn perspective<S: BaseFloat, A: Into<Rad<S>>>(fovy: A, aspect: S, near: S, f
     }.into()
 }
 
+/// Create a perspective projection matrix with the far plane at infinity.
+///
+/// The limit of `perspective` as `far` goes to infinity. Points in front of
+/// the near plane map to depths in `[-1, 1)`.
+pub fn perspective_infinite<S: BaseFloat, A: Into<Rad<S>>>(fovy: A, aspect: S, near: S) -> Matrix4<S> {
+    infinite_perspective(fovy.into(), aspect, near, DepthRange::NegativeOneToOne)
+}
+
+/// Create a reversed-Z perspective projection matrix with the far plane at
+/// infinity.
+///
+/// The near plane maps to a depth of `1`, and depth approaches `0` with
+/// distance. See `DepthRange::ReversedZeroToOne`.
+pub fn perspective_infinite_reversed<S: BaseFloat, A: Into<Rad<S>>>(fovy: A, aspect: S, near: S) -> Matrix4<S> {
+    infinite_perspective(fovy.into(), aspect, near, DepthRange::ReversedZeroToOne)
+}
+
+fn infinite_perspective<S: BaseFloat>(fovy: Rad<S>, aspect: S, near: S, depth: DepthRange) -> Matrix4<S> {
+    assert!(fovy   > Rad::zero(), "The vertical field of view cannot be below zero, found: {:?}", fovy);
+    assert!(fovy   < Rad::turn_div_2(), "The vertical field of view cannot be greater than a half turn, found: {:?}", fovy);
+    assert!(aspect > S::zero(), "The aspect ratio cannot be below zero, found: {:?}", aspect);
+    assert!(near   > S::zero(), "The near plane distance cannot be below zero, found: {:?}", near);
+
+    let two = S::two();
+    let f = Rad::cot(fovy / two);
+    let (c2r2, c3r2) = match depth {
+        DepthRange::NegativeOneToOne => (-S::one(), -two * near),
+        DepthRange::ZeroToOne => (-S::one(), -near),
+        DepthRange::ReversedZeroToOne => (S::zero(), near),
+    };
+
+    Matrix4::new(f / aspect, S::zero(), S::zero(), S::zero(),
+                 S::zero(), f, S::zero(), S::zero(),
+                 S::zero(), S::zero(), c2r2, -S::one(),
+                 S::zero(), S::zero(), c3r2, S::zero())
+}
+
 /// Create a perspective matrix from a view frustrum.
 ///
 /// This is the equivalent of the now deprecated [glFrustrum]
@@ -63,6 +99,150 @@ pub fn ortho<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far: S
     }.into()
 }
 
+/// Create a matrix mapping normalized device coordinates to window
+/// coordinates for the viewport rectangle at `(x, y)` with the given size.
+///
+/// Window depths are in the range `[0, 1]`. For `DepthRange::NegativeOneToOne`
+/// the normalized depth is remapped to this range, like the OpenGL viewport
+/// transform, and for the other depth ranges it is passed through unchanged.
+pub fn viewport<S: BaseFloat>(x: S, y: S, width: S, height: S, depth: DepthRange) -> Matrix4<S> {
+    let half = S::half();
+    let (depth_scale, depth_offset) = match depth {
+        DepthRange::NegativeOneToOne => (half, half),
+        DepthRange::ZeroToOne | DepthRange::ReversedZeroToOne => (S::one(), S::zero()),
+    };
+    Matrix4::new(width * half, S::zero(), S::zero(), S::zero(),
+                 S::zero(), height * half, S::zero(), S::zero(),
+                 S::zero(), S::zero(), depth_scale, S::zero(),
+                 x + width * half, y + height * half, depth_offset, S::one())
+}
+
+/// Create a matrix mapping window coordinates back to normalized device
+/// coordinates. This is the inverse of `viewport`.
+pub fn viewport_inverse<S: BaseFloat>(x: S, y: S, width: S, height: S, depth: DepthRange) -> Matrix4<S> {
+    let two = S::two();
+    let (depth_scale, depth_offset) = match depth {
+        DepthRange::NegativeOneToOne => (two, -S::one()),
+        DepthRange::ZeroToOne | DepthRange::ReversedZeroToOne => (S::one(), S::zero()),
+    };
+    Matrix4::new(two / width, S::zero(), S::zero(), S::zero(),
+                 S::zero(), two / height, S::zero(), S::zero(),
+                 S::zero(), S::zero(), depth_scale, S::zero(),
+                 -two * x / width - S::one(), -two * y / height - S::one(), depth_offset, S::one())
+}
+
+/// Create a matrix restricting a projection to a small region of the
+/// viewport, by scaling the region centred on `center` with the given `size`
+/// in window coordinates up to fill the whole viewport. Multiply it on the
+/// left of the projection matrix.
+///
+/// `viewport` is the `[x, y, width, height]` of the window rectangle.
+///
+/// This is the equivalent of the [gluPickMatrix]
+/// (https://www.opengl.org/sdk/docs/man2/xhtml/gluPickMatrix.xml) function.
+pub fn pick_matrix<S: BaseFloat>(center: Point2<S>, size: Vector2<S>, viewport: [S; 4]) -> Matrix4<S> {
+    assert!(size.x > S::zero() && size.y > S::zero(), "The pick region must have a positive size, found: {:?}", size);
+
+    let two = S::two();
+    let sx = viewport[2] / size.x;
+    let sy = viewport[3] / size.y;
+    let tx = (viewport[2] - two * (center.x - viewport[0])) / size.x;
+    let ty = (viewport[3] - two * (center.y - viewport[1])) / size.y;
+    Matrix4::new(sx, S::zero(), S::zero(), S::zero(),
+                 S::zero(), sy, S::zero(), S::zero(),
+                 S::zero(), S::zero(), S::one(), S::zero(),
+                 tx, ty, S::zero(), S::one())
+}
+
+/// Map object coordinates to window coordinates.
+///
+/// `viewport` is the `[x, y, width, height]` of the window rectangle. The
+/// depth of the result is in the range `[0, 1]` for points between the near
+/// and far planes.
+///
+/// This is the equivalent of the [gluProject]
+/// (https://www.opengl.org/sdk/docs/man2/xhtml/gluProject.xml) function.
+pub fn project<S: BaseFloat>(world: Point3<S>, modelview: &Matrix4<S>, proj: &Matrix4<S>,
+                             viewport: [S; 4]) -> Point3<S> {
+    let half = S::half();
+    let clip = *proj * (*modelview * world.to_homogeneous());
+    let ndc = clip.truncate() / clip.w;
+    Point3::new(viewport[0] + viewport[2] * (ndc.x + S::one()) * half,
+                viewport[1] + viewport[3] * (ndc.y + S::one()) * half,
+                (ndc.z + S::one()) * half)
+}
+
+/// Map window coordinates to object coordinates. This is the inverse of
+/// `project`.
+///
+/// Returns `None` if the combined projection is not invertible, or if the
+/// window coordinates correspond to a point at infinity.
+///
+/// This is the equivalent of the [gluUnProject]
+/// (https://www.opengl.org/sdk/docs/man2/xhtml/gluUnProject.xml) function.
+pub fn unproject<S: BaseFloat>(window: Point3<S>, modelview: &Matrix4<S>, proj: &Matrix4<S>,
+                               viewport: [S; 4]) -> Option<Point3<S>> {
+    let two = S::two();
+    let inverse = match (*proj * *modelview).invert() {
+        Some(inverse) => inverse,
+        None => return None,
+    };
+    let ndc = Vector4::new((window.x - viewport[0]) / viewport[2] * two - S::one(),
+                           (window.y - viewport[1]) / viewport[3] * two - S::one(),
+                           window.z * two - S::one(),
+                           S::one());
+    let obj = inverse * ndc;
+    if obj.w == S::zero() {
+        None
+    } else {
+        Some(Point3::from_homogeneous(obj))
+    }
+}
+
+/// The range of normalized device depth that a projection matrix maps the
+/// near and far planes to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, RustcEncodable, RustcDecodable)]
+pub enum DepthRange {
+    /// The near plane maps to `-1` and the far plane to `1`. This is the
+    /// OpenGL convention, and the one used when converting a projection with
+    /// `From`.
+    NegativeOneToOne,
+    /// The near plane maps to `0` and the far plane to `1`. This is the
+    /// Direct3D, Metal, and Vulkan convention.
+    ZeroToOne,
+    /// The near plane maps to `1` and the far plane to `0`. Combined with a
+    /// floating point depth buffer, this spreads the depth precision much
+    /// more evenly over the frustum. The depth test must be reversed to
+    /// `GREATER`.
+    ReversedZeroToOne,
+}
+
+/// The third row of a perspective matrix, `(c2r2, c3r2)`, mapping the eye
+/// space depths `-near` and `-far` to `depth`.
+fn perspective_depth<S: BaseFloat>(near: S, far: S, depth: DepthRange) -> (S, S) {
+    match depth {
+        DepthRange::NegativeOneToOne => {
+            let two = S::two();
+            ((far + near) / (near - far), (two * far * near) / (near - far))
+        }
+        DepthRange::ZeroToOne => (far / (near - far), (far * near) / (near - far)),
+        DepthRange::ReversedZeroToOne => (near / (far - near), (far * near) / (far - near)),
+    }
+}
+
+/// The third row of an orthographic matrix, `(c2r2, c3r2)`, mapping the eye
+/// space depths `-near` and `-far` to `depth`.
+fn ortho_depth<S: BaseFloat>(near: S, far: S, depth: DepthRange) -> (S, S) {
+    match depth {
+        DepthRange::NegativeOneToOne => {
+            let two = S::two();
+            (-two / (far - near), -(far + near) / (far - near))
+        }
+        DepthRange::ZeroToOne => (S::one() / (near - far), near / (near - far)),
+        DepthRange::ReversedZeroToOne => (S::one() / (far - near), far / (far - near)),
+    }
+}
+
 /// A perspective projection based on a vertical field-of-view angle.
 #[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
 pub struct PerspectiveFov<S> {
@@ -74,7 +254,7 @@ pub struct PerspectiveFov<S> {
 
 impl<S: BaseFloat> PerspectiveFov<S> {
     pub fn to_perspective(&self) -> Perspective<S> {
-        let two: S = cast(2).unwrap();
+        let two = S::two();
         let angle = self.fovy / two;
         let ymax = self.near * Rad::tan(angle);
         let xmax = ymax * self.aspect;
@@ -92,6 +272,16 @@ impl<S: BaseFloat> PerspectiveFov<S> {
 
 impl<S: BaseFloat> From<PerspectiveFov<S>> for Matrix4<S> {
     fn from(persp: PerspectiveFov<S>) -> Matrix4<S> {
+        persp.to_matrix(DepthRange::NegativeOneToOne)
+    }
+}
+
+impl<S: BaseFloat> PerspectiveFov<S> {
+    /// Create a projection matrix mapping the view frustum's depth to
+    /// `depth`. Converting with `From` is the same as using
+    /// `DepthRange::NegativeOneToOne`.
+    pub fn to_matrix(&self, depth: DepthRange) -> Matrix4<S> {
+        let persp = *self;
         assert!(persp.fovy   > Rad::zero(), "The vertical field of view cannot be below zero, found: {:?}", persp.fovy);
         assert!(persp.fovy   < Rad::turn_div_2(), "The vertical field of view cannot be greater than a half turn, found: {:?}", persp.fovy);
         assert!(persp.aspect > S::zero(), "The aspect ratio cannot be below zero, found: {:?}", persp.aspect);
@@ -99,7 +289,7 @@ impl<S: BaseFloat> From<PerspectiveFov<S>> for Matrix4<S> {
         assert!(persp.far    > S::zero(), "The far plane distance cannot be below zero, found: {:?}", persp.far);
         assert!(persp.far    > persp.near, "The far plane cannot be closer than the near plane, found: far: {:?}, near: {:?}", persp.far, persp.near);
 
-        let two: S = cast(2).unwrap();
+        let two = S::two();
         let f = Rad::cot(persp.fovy / two);
 
         let c0r0 = f / persp.aspect;
@@ -112,14 +302,14 @@ impl<S: BaseFloat> From<PerspectiveFov<S>> for Matrix4<S> {
         let c1r2 = S::zero();
         let c1r3 = S::zero();
 
+        let (c2r2, c3r2) = perspective_depth(persp.near, persp.far, depth);
+
         let c2r0 = S::zero();
         let c2r1 = S::zero();
-        let c2r2 = (persp.far + persp.near) / (persp.near - persp.far);
         let c2r3 = -S::one();
 
         let c3r0 = S::zero();
         let c3r1 = S::zero();
-        let c3r2 = (two * persp.far * persp.near) / (persp.near - persp.far);
         let c3r3 = S::zero();
 
         Matrix4::new(c0r0, c0r1, c0r2, c0r3,
@@ -142,11 +332,44 @@ pub struct Perspective<S> {
 
 impl<S: BaseFloat> From<Perspective<S>> for Matrix4<S> {
     fn from(persp: Perspective<S>) -> Matrix4<S> {
+        persp.to_matrix(DepthRange::NegativeOneToOne)
+    }
+}
+
+impl<S: BaseFloat> Perspective<S> {
+    /// Create an off-axis frustum from the tangents of the angles between
+    /// the view direction and each edge, as reported by VR runtimes for each
+    /// eye. The `left` and `bottom` tangents are usually negative.
+    pub fn from_tangents(left: S, right: S, bottom: S, top: S, near: S, far: S) -> Perspective<S> {
+        Perspective {
+            left:   left * near,
+            right:  right * near,
+            bottom: bottom * near,
+            top:    top * near,
+            near:   near,
+            far:    far,
+        }
+    }
+
+    /// Create an off-axis frustum from the signed angles between the view
+    /// direction and each edge. Angles to the left and below the view
+    /// direction are negative, following the OpenXR convention.
+    pub fn from_angles<A: Into<Rad<S>>>(left: A, right: A, bottom: A, top: A, near: S, far: S) -> Perspective<S> {
+        Perspective::from_tangents(Rad::tan(left.into()), Rad::tan(right.into()),
+                                   Rad::tan(bottom.into()), Rad::tan(top.into()),
+                                   near, far)
+    }
+
+    /// Create a projection matrix mapping the view frustum's depth to
+    /// `depth`. Converting with `From` is the same as using
+    /// `DepthRange::NegativeOneToOne`.
+    pub fn to_matrix(&self, depth: DepthRange) -> Matrix4<S> {
+        let persp = *self;
         assert!(persp.left   <= persp.right, "`left` cannot be greater than `right`, found: left: {:?} right: {:?}", persp.left, persp.right);
         assert!(persp.bottom <= persp.top,   "`bottom` cannot be greater than `top`, found: bottom: {:?} top: {:?}", persp.bottom, persp.top);
         assert!(persp.near   <= persp.far,   "`near` cannot be greater than `far`, found: near: {:?} far: {:?}", persp.near, persp.far);
 
-        let two: S = cast(2i8).unwrap();
+        let two = S::two();
 
         let c0r0 = (two * persp.near) / (persp.right - persp.left);
         let c0r1 = S::zero();
@@ -160,12 +383,11 @@ impl<S: BaseFloat> From<Perspective<S>> for Matrix4<S> {
 
         let c2r0 = (persp.right + persp.left) / (persp.right - persp.left);
         let c2r1 = (persp.top + persp.bottom) / (persp.top - persp.bottom);
-        let c2r2 = -(persp.far + persp.near) / (persp.far - persp.near);
+        let (c2r2, c3r2) = perspective_depth(persp.near, persp.far, depth);
         let c2r3 = -S::one();
 
         let c3r0 = S::zero();
         let c3r1 = S::zero();
-        let c3r2 = -(two * persp.far * persp.near) / (persp.far - persp.near);
         let c3r3 = S::zero();
 
         Matrix4::new(c0r0, c0r1, c0r2, c0r3,
@@ -188,7 +410,17 @@ pub struct Ortho<S> {
 
 impl<S: BaseFloat> From<Ortho<S>> for Matrix4<S> {
     fn from(ortho: Ortho<S>) -> Matrix4<S> {
-        let two: S = cast(2).unwrap();
+        ortho.to_matrix(DepthRange::NegativeOneToOne)
+    }
+}
+
+impl<S: BaseFloat> Ortho<S> {
+    /// Create a projection matrix mapping the view volume's depth to
+    /// `depth`. Converting with `From` is the same as using
+    /// `DepthRange::NegativeOneToOne`.
+    pub fn to_matrix(&self, depth: DepthRange) -> Matrix4<S> {
+        let ortho = *self;
+        let two = S::two();
 
         let c0r0 = two / (ortho.right - ortho.left);
         let c0r1 = S::zero();
@@ -202,14 +434,14 @@ impl<S: BaseFloat> From<Ortho<S>> for Matrix4<S> {
 
         let c2r0 = S::zero();
         let c2r1 = S::zero();
-        let c2r2 = -two / (ortho.far - ortho.near);
         let c2r3 = S::zero();
 
         let c3r0 = -(ortho.right + ortho.left) / (ortho.right - ortho.left);
         let c3r1 = -(ortho.top + ortho.bottom) / (ortho.top - ortho.bottom);
-        let c3r2 = -(ortho.far + ortho.near) / (ortho.far - ortho.near);
         let c3r3 = S::one();
 
+        let (c2r2, c3r2) = ortho_depth(ortho.near, ortho.far, depth);
+
         Matrix4::new(c0r0, c0r1, c0r2, c0r3,
                      c1r0, c1r1, c1r2, c1r3,
                      c2r0, c2r1, c2r2, c2r3,
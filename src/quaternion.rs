@@ -14,17 +14,18 @@
 // limitations under the License.
 
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::mem;
 use std::ops::*;
 
 use rand::{Rand, Rng};
-use rust_num::{Float, One, Zero};
+use rust_num::{NumCast, One, Zero};
 use rust_num::traits::cast;
 
 use angle::{Angle, Rad};
 use approx::ApproxEq;
-use matrix::{Matrix3, Matrix4};
-use num::BaseFloat;
+use matrix::{Matrix3, Matrix4, SquareMatrix, is_singular};
+use num::{BaseFloat, HashBits};
 use point::Point3;
 use rotation::{Rotation, Rotation3, Basis3};
 use vector::{Vector3, Vector, EuclideanVector};
@@ -38,30 +39,49 @@ pub struct Quaternion<S> {
     pub v: Vector3<S>,
 }
 
-impl<S: BaseFloat> Quaternion<S> {
-    /// Construct a new quaternion from one scalar component and three
-    /// imaginary components
-    #[inline]
-    pub fn new(w: S, xi: S, yj: S, zk: S) -> Quaternion<S> {
-        Quaternion::from_sv(w, Vector3::new(xi, yj, zk))
-    }
+impl<S: HashBits> Eq for Quaternion<S> {}
 
-    /// Construct a new quaternion from a scalar and a vector
+impl<S: HashBits> Hash for Quaternion<S> {
     #[inline]
-    pub fn from_sv(s: S, v: Vector3<S>) -> Quaternion<S> {
-        Quaternion { s: s, v: v }
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.s.hash_bits(state);
+        self.v.hash(state);
     }
+}
 
+/// Constants, which only need a zero and a one.
+impl<S: Zero + One> Quaternion<S> {
     /// The additive identity, ie: `q = 0 + 0i + 0j + 0i`
     #[inline]
     pub fn zero() -> Quaternion<S> {
-        Quaternion::new(S::zero(), S::zero(), S::zero(), S::zero())
+        Quaternion { s: S::zero(), v: Vector3::zero() }
     }
 
     /// The multiplicative identity, ie: `q = 1 + 0i + 0j + 0i`
     #[inline]
     pub fn one() -> Quaternion<S> {
-        Quaternion::from_sv(S::one(), Vector3::zero())
+        Quaternion { s: S::one(), v: Vector3::zero() }
+    }
+
+    /// The identity rotation. This is the same as `one`.
+    #[inline]
+    pub fn identity() -> Quaternion<S> {
+        Quaternion::one()
+    }
+}
+
+impl<S: BaseFloat> Quaternion<S> {
+    /// Construct a new quaternion from one scalar component and three
+    /// imaginary components
+    #[inline]
+    pub fn new(w: S, xi: S, yj: S, zk: S) -> Quaternion<S> {
+        Quaternion::from_sv(w, Vector3::new(xi, yj, zk))
+    }
+
+    /// Construct a new quaternion from a scalar and a vector
+    #[inline]
+    pub fn from_sv(s: S, v: Vector3<S>) -> Quaternion<S> {
+        Quaternion { s: s, v: v }
     }
 
     /// The dot product of the quaternion and `q`.
@@ -96,12 +116,47 @@ impl<S: BaseFloat> Quaternion<S> {
         self.magnitude2().sqrt()
     }
 
+    /// The squared length of the quaternion. This is the same as
+    /// `magnitude2`, named to match the vector types.
+    #[inline]
+    pub fn length2(self) -> S {
+        self.magnitude2()
+    }
+
+    /// The length of the quaternion. This is the same as `magnitude`, named
+    /// to match the vector types.
+    #[inline]
+    pub fn length(self) -> S {
+        self.magnitude()
+    }
+
     /// Normalize this quaternion, returning the new quaternion.
     #[inline]
     pub fn normalize(self) -> Quaternion<S> {
         self * (S::one() / self.magnitude())
     }
 
+    /// The multiplicative inverse of the quaternion, such that
+    /// `q * q.inverse()` is the identity. For a unit quaternion this is the
+    /// same as the conjugate, but it is divided by the squared magnitude so
+    /// that it holds for any non-zero quaternion.
+    #[inline]
+    pub fn inverse(self) -> Quaternion<S> {
+        self.conjugate() / self.magnitude2()
+    }
+
+    /// Rotate a vector by the quaternion, without converting it to a matrix.
+    ///
+    /// This computes `q * v * q.conjugate()` as `v + s * t + u × t`, where
+    /// `t = 2 * (u × v)` and `q = s + u`, which takes two cross products.
+    /// The quaternion should be normalized first.
+    #[inline]
+    pub fn rotate_vector(self, vec: Vector3<S>) -> Vector3<S> {
+        let two = S::two();
+        let t = self.v.cross(vec) * two;
+        vec + t * self.s + self.v.cross(t)
+    }
+
     /// Do a normalized linear interpolation with `other`, by `amount`.
     pub fn nlerp(self, other: Quaternion<S>, amount: S) -> Quaternion<S> {
         (self * (S::one() - amount) + other * amount).normalize()
@@ -133,12 +188,7 @@ impl_assignment_operator!(<S: BaseFloat> DivAssign<S> for Quaternion<S> {
 });
 
 impl_operator!(<S: BaseFloat> Mul<Vector3<S> > for Quaternion<S> {
-    fn mul(lhs, rhs) -> Vector3<S> {{
-        let rhs = rhs.clone();
-        let two: S = cast(2i8).unwrap();
-        let tmp = lhs.v.cross(rhs) + (rhs * lhs.s);
-        (lhs.v.cross(tmp) * two) + rhs
-    }}
+    fn mul(lhs, rhs) -> Vector3<S> { lhs.rotate_vector(rhs.clone()) }
 });
 
 impl_operator!(<S: BaseFloat> Add<Quaternion<S> > for Quaternion<S> {
@@ -176,6 +226,18 @@ impl<S: BaseFloat> ApproxEq for Quaternion<S> {
         self.s.approx_eq_eps(&other.s, epsilon) &&
         self.v.approx_eq_eps(&other.v, epsilon)
     }
+
+    #[inline]
+    fn relative_eq(&self, other: &Quaternion<S>, epsilon: &S, max_relative: &S) -> bool {
+        self.s.relative_eq(&other.s, epsilon, max_relative) &&
+        self.v.relative_eq(&other.v, epsilon, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Quaternion<S>, epsilon: &S, max_ulps: u32) -> bool {
+        self.s.ulps_eq(&other.s, epsilon, max_ulps) &&
+        self.v.ulps_eq(&other.v, epsilon, max_ulps)
+    }
 }
 
 impl<S: BaseFloat> Quaternion<S> {
@@ -222,6 +284,207 @@ impl<S: BaseFloat> Quaternion<S> {
         }
     }
 
+    /// Returns `true` if every component is exactly zero.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.s == S::zero() && self.v.is_zero()
+    }
+
+    /// Returns `true` if the quaternion has approximately unit magnitude, so
+    /// it represents a rotation.
+    #[inline]
+    pub fn is_normalized(&self) -> bool {
+        self.magnitude2().approx_eq(&S::one())
+    }
+
+    /// Returns `true` if none of the components are infinite or NaN.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.s.is_finite() && self.v.is_finite()
+    }
+
+    /// Returns `true` if the quaternions represent approximately the same
+    /// rotation. Unlike `approx_eq`, this treats `q` and `-q` as equal.
+    #[inline]
+    pub fn rotation_eq(&self, other: &Quaternion<S>) -> bool {
+        self.rotation_eq_eps(other, &S::approx_epsilon())
+    }
+
+    /// Returns `true` if the quaternions represent the same rotation, to
+    /// within `epsilon` in each component, treating `q` and `-q` as equal.
+    #[inline]
+    pub fn rotation_eq_eps(&self, other: &Quaternion<S>, epsilon: &S) -> bool {
+        self.approx_eq_eps(other, epsilon) || self.approx_eq_eps(&-*other, epsilon)
+    }
+
+    /// The angle of the rotation taking the orientation represented by this
+    /// quaternion to the one represented by `other`, in the range `[0, π]`.
+    ///
+    /// Both quaternions represent the same rotations as their negations, so
+    /// this is always the shorter way round. The result does not depend on
+    /// the magnitudes of the quaternions, and uses `atan2` rather than `acos`
+    /// to stay accurate for small angles.
+    pub fn angle(self, other: Quaternion<S>) -> Rad<S> {
+        let two = S::two();
+        let diff = self.conjugate() * other;
+        Rad::atan2(diff.v.length(), diff.s.abs()) * two
+    }
+
+    /// Rotate towards `target` by at most `max_angle`, along the shortest
+    /// path, without overshooting. Returns `target` once it is within reach.
+    ///
+    /// Both quaternions should be normalized first.
+    pub fn rotate_towards(self, target: Quaternion<S>, max_angle: Rad<S>) -> Quaternion<S> {
+        let angle = self.angle(target);
+        if angle.s <= max_angle.s {
+            return target;
+        }
+        self.slerp(target.closest_to(self), max_angle.s / angle.s)
+    }
+
+    /// `q` and `-q` represent the same rotation. Returns whichever of the two
+    /// is closer to `other`, so that interpolating between them takes the
+    /// shortest path.
+    #[inline]
+    pub(crate) fn closest_to(self, other: Quaternion<S>) -> Quaternion<S> {
+        if self.dot(other) < S::zero() { -self } else { self }
+    }
+
+    /// Limit the rotation to at most `max_angle`, keeping its axis, as for a
+    /// joint limit. Rotations within the limit are returned unchanged.
+    ///
+    /// The quaternion should be normalized first.
+    #[inline]
+    pub fn clamp_angle(self, max_angle: Rad<S>) -> Quaternion<S> {
+        Quaternion::one().rotate_towards(self, max_angle)
+    }
+
+    /// Limit the rotation to within `max_angle` of `reference`, returning the
+    /// closest orientation on the shortest path between them, as for keeping
+    /// a camera near a rest orientation. Orientations within the limit are
+    /// returned unchanged.
+    ///
+    /// Both quaternions should be normalized first.
+    #[inline]
+    pub fn clamp_angle_from(self, reference: Quaternion<S>, max_angle: Rad<S>) -> Quaternion<S> {
+        reference.rotate_towards(self, max_angle)
+    }
+
+    /// Decompose the rotation into a swing and a twist, such that
+    /// `self = swing * twist`, where `twist` is a rotation around `axis`, and
+    /// `swing` is a rotation around an axis perpendicular to it.
+    ///
+    /// `axis` should be a unit vector, and the quaternion normalized. If the
+    /// rotation is a half turn around an axis perpendicular to `axis`, the
+    /// twist is undefined and the identity is returned for it.
+    pub fn swing_twist(self, axis: Vector3<S>) -> (Quaternion<S>, Quaternion<S>) {
+        let projected = Quaternion::from_sv(self.s, axis * self.v.dot(axis));
+        let magnitude2 = projected.magnitude2();
+        let twist = if magnitude2.approx_eq(&S::zero()) {
+            Quaternion::one()
+        } else {
+            projected / magnitude2.sqrt()
+        };
+        (self * twist.conjugate(), twist)
+    }
+
+    /// Advance the orientation by rotating it with the world-space
+    /// `angular_velocity`, in radians per unit time, over the time step `dt`.
+    ///
+    /// This takes an explicit Euler step of `dq/dt = ½ωq`, and renormalizes
+    /// the result to stop the error from accumulating as drift in the
+    /// magnitude.
+    pub fn integrate(self, angular_velocity: Vector3<S>, dt: S) -> Quaternion<S> {
+        let half = S::half();
+        let spin = Quaternion::from_sv(S::zero(), angular_velocity) * self;
+        (self + spin * (half * dt)).normalize()
+    }
+
+    /// The constant world-space angular velocity, in radians per unit time,
+    /// that carries this orientation to `other` over the time step `dt`,
+    /// along the shortest path. This is the counterpart of `integrate`.
+    ///
+    /// Both quaternions should be normalized first.
+    pub fn angular_velocity_to(self, other: Quaternion<S>, dt: S) -> Vector3<S> {
+        let two = S::two();
+        let diff = (other * self.conjugate()).closest_to(Quaternion::one());
+        let sin = diff.v.length();
+        if sin.approx_eq(&S::zero()) {
+            // for small angles, sin(θ/2) ≈ θ/2
+            diff.v * (two / dt)
+        } else {
+            let angle = Rad::atan2(sin, diff.s).s * two;
+            diff.v * (angle / (sin * dt))
+        }
+    }
+
+    /// The natural logarithm of the quaternion.
+    ///
+    /// For a unit quaternion representing a rotation of `2θ` around the axis
+    /// `n`, this is the pure quaternion `0 + θn`.
+    pub fn ln(self) -> Quaternion<S> {
+        let magnitude = self.magnitude();
+        let v_magnitude = self.v.length();
+        if v_magnitude.approx_eq(&S::zero()) {
+            Quaternion::from_sv(magnitude.ln(), Vector3::zero())
+        } else {
+            let theta = (self.s / magnitude).max(-S::one()).min(S::one()).acos();
+            Quaternion::from_sv(magnitude.ln(), self.v * (theta / v_magnitude))
+        }
+    }
+
+    /// The exponential of the quaternion. This is the inverse of `ln`.
+    pub fn exp(self) -> Quaternion<S> {
+        let scale = self.s.exp();
+        let theta = self.v.length();
+        if theta.approx_eq(&S::zero()) {
+            Quaternion::from_sv(scale, self.v * scale)
+        } else {
+            let (s, c) = theta.sin_cos();
+            Quaternion::from_sv(scale * c, self.v * (scale * s / theta))
+        }
+    }
+
+    /// Spherical Cubic Interpolation
+    ///
+    /// Interpolate between the quaternion and `other` along a spherical cubic
+    /// curve, using `a` and `b` as the control points at each end of the
+    /// segment. When the control points are computed with
+    /// `squad_control_points`, consecutive segments join with a continuous
+    /// angular velocity. All of the quaternions should be normalized first.
+    ///
+    /// - [Shoemake, K. "Quaternion Calculus for Animation"]
+    ///   (http://www.cs.ucr.edu/~vbz/resources/quatut.pdf)
+    pub fn squad(self, other: Quaternion<S>, a: Quaternion<S>, b: Quaternion<S>, amount: S) -> Quaternion<S> {
+        let two = S::two();
+        let outer = self.slerp(other, amount);
+        let inner = a.slerp(b, amount);
+        outer.slerp(inner, two * amount * (S::one() - amount))
+    }
+
+    /// Compute the `squad` control point for each quaternion in a sequence of
+    /// keyframes. The control points for the first and last keyframes are the
+    /// keyframes themselves.
+    ///
+    /// The keyframes should be normalized, and neighbouring keyframes should
+    /// lie in the same hemisphere (have a non-negative dot product) so that
+    /// the curve takes the shortest path between them.
+    pub fn squad_control_points(keyframes: &[Quaternion<S>]) -> Vec<Quaternion<S>> {
+        let quarter = S::half() * S::half();
+        let n = keyframes.len();
+
+        (0..n).map(|i| {
+            if i == 0 || i == n - 1 {
+                keyframes[i]
+            } else {
+                let inv = keyframes[i].conjugate();
+                let next = (inv * keyframes[i + 1]).ln();
+                let prev = (inv * keyframes[i - 1]).ln();
+                keyframes[i] * ((next + prev) * -quarter).exp()
+            }
+        }).collect()
+    }
+
     /// Convert a Quaternion to Eular angles
     ///     This is a polar singularity aware conversion
     ///
@@ -230,8 +493,8 @@ impl<S: BaseFloat> Quaternion<S> {
     ///   (http://www.euclideanspace.com/maths/geometry/rotations/conversions/quaternionToEuler/)
     pub fn to_euler(self) -> (Rad<S>, Rad<S>, Rad<S>) {
         let sig: S = cast(0.499f64).unwrap();
-        let two: S = cast(2f64).unwrap();
-        let one: S = cast(1f64).unwrap();
+        let two = S::two();
+        let one = S::one();
 
         let (qw, qx, qy, qz) = (self.s, self.v.x, self.v.y, self.v.z);
         let (sqw, sqx, sqy, sqz) = (qw * qw, qx * qx, qy * qy, qz * qz);
@@ -259,6 +522,56 @@ impl<S: BaseFloat> Quaternion<S> {
             )
         }
     }
+
+    /// Convert a rotation matrix to a quaternion, returning `None` if the
+    /// matrix is not a proper rotation, as tested by `Matrix3::is_rotation`.
+    ///
+    /// Converting with `Quaternion::from` doesn't check the matrix, and gives
+    /// a meaningless result for a matrix with a scale, shear, or reflection.
+    pub fn from_rotation_matrix(mat: Matrix3<S>) -> Option<Quaternion<S>> {
+        if mat.is_rotation() { Some(Quaternion::from(mat)) } else { None }
+    }
+
+    /// Convert a matrix that scales along each axis and then rotates,
+    /// returning the rotation and the scale. A reflection is represented by
+    /// negating the scale. Returns `None` if the matrix is singular or has a
+    /// shear.
+    pub fn from_scaled_matrix(mat: Matrix3<S>) -> Option<(Quaternion<S>, Vector3<S>)> {
+        if is_singular(mat.determinant(), mat.column_length2_product()) {
+            return None;
+        }
+        let mut scale = Vector3::new(mat.x.length(), mat.y.length(), mat.z.length());
+        let mut rot = Matrix3::from_cols(mat.x / scale.x, mat.y / scale.y, mat.z / scale.z);
+        if rot.determinant() < S::zero() {
+            rot = -rot;
+            scale = -scale;
+        }
+        Quaternion::from_rotation_matrix(rot).map(|q| (q, scale))
+    }
+}
+
+impl<S: NumCast + Copy> Quaternion<S> {
+    /// Component-wise casting to another type
+    #[inline]
+    pub fn cast<T: NumCast>(&self) -> Quaternion<T> {
+        Quaternion { s: NumCast::from(self.s).unwrap(), v: self.v.cast() }
+    }
+}
+
+impl Quaternion<f32> {
+    /// Spherical linear interpolation, computed in `f64` precision and
+    /// rounded to `f32`.
+    #[inline]
+    pub fn precise_slerp(self, other: Quaternion<f32>, amount: f32) -> Quaternion<f32> {
+        self.cast::<f64>().slerp(other.cast(), amount as f64).cast()
+    }
+
+    /// The Hamilton product of two quaternions, computed in `f64` precision
+    /// and rounded to `f32`.
+    #[inline]
+    pub fn precise_mul(self, other: Quaternion<f32>) -> Quaternion<f32> {
+        (self.cast::<f64>() * other.cast::<f64>()).cast()
+    }
 }
 
 impl<S: BaseFloat> From<Quaternion<S>> for Matrix3<S> {
@@ -346,7 +659,7 @@ impl<S: BaseFloat> Rotation<Point3<S>> for Quaternion<S> {
     }
 
     #[inline]
-    fn rotate_vector(&self, vec: Vector3<S>) -> Vector3<S> { self * vec }
+    fn rotate_vector(&self, vec: Vector3<S>) -> Vector3<S> { Quaternion::rotate_vector(*self, vec) }
 
     #[inline]
     fn concat(&self, other: &Quaternion<S>) -> Quaternion<S> { self * other }
@@ -355,7 +668,7 @@ impl<S: BaseFloat> Rotation<Point3<S>> for Quaternion<S> {
     fn concat_self(&mut self, other: &Quaternion<S>) { *self = &*self * other; }
 
     #[inline]
-    fn invert(&self) -> Quaternion<S> { self.conjugate() / self.magnitude2() }
+    fn invert(&self) -> Quaternion<S> { self.inverse() }
 
     #[inline]
     fn invert_self(&mut self) { *self = self.invert() }
@@ -364,16 +677,16 @@ impl<S: BaseFloat> Rotation<Point3<S>> for Quaternion<S> {
 impl<S: BaseFloat> Rotation3<S> for Quaternion<S> {
     #[inline]
     fn from_axis_angle(axis: Vector3<S>, angle: Rad<S>) -> Quaternion<S> {
-        let (s, c) = Rad::sin_cos(angle * cast(0.5f64).unwrap());
+        let (s, c) = Rad::sin_cos(angle * S::half());
         Quaternion::from_sv(c, axis * s)
     }
 
     /// - [Maths - Conversion Euler to Quaternion]
     ///   (http://www.euclideanspace.com/maths/geometry/rotations/conversions/eulerToQuaternion/index.htm)
     fn from_euler(x: Rad<S>, y: Rad<S>, z: Rad<S>) -> Quaternion<S> {
-        let (s1, c1) = Rad::sin_cos(x * cast(0.5f64).unwrap());
-        let (s2, c2) = Rad::sin_cos(y * cast(0.5f64).unwrap());
-        let (s3, c3) = Rad::sin_cos(z * cast(0.5f64).unwrap());
+        let (s1, c1) = Rad::sin_cos(x * S::half());
+        let (s2, c2) = Rad::sin_cos(y * S::half());
+        let (s3, c3) = Rad::sin_cos(z * S::half());
 
         Quaternion::new(c1 * c2 * c3 - s1 * s2 * s3,
                         s1 * s2 * c3 + c1 * c2 * s3,
@@ -0,0 +1,85 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sampling of uniformly distributed directions, offsets, and rotations.
+//!
+//! The `Rand` implementations for vectors and quaternions generate each
+//! component independently, which is fine for fuzzing but is biased towards
+//! the corners of the unit cube when normalized. These functions produce
+//! correctly distributed samples instead.
+
+use std::f64;
+
+use rand::{Rand, Rng};
+
+use rust_num::traits::cast;
+
+use angle::{Angle, Rad};
+use num::BaseFloat;
+use quaternion::Quaternion;
+use vector::{Vector2, Vector3};
+
+/// A uniformly distributed angle in the range `[0, 2π)`.
+fn random_turn<S: BaseFloat + Rand, R: Rng>(rng: &mut R) -> Rad<S> {
+    let tau: S = cast(2.0 * f64::consts::PI).unwrap();
+    Rad::new(tau * rng.gen())
+}
+
+impl<S: BaseFloat + Rand> Vector2<S> {
+    /// A unit vector uniformly distributed on the circle.
+    pub fn random_unit<R: Rng>(rng: &mut R) -> Vector2<S> {
+        Vector2::from_polar(S::one(), random_turn(rng))
+    }
+
+    /// A vector uniformly distributed in the disk of radius one.
+    pub fn random_in_unit_disk<R: Rng>(rng: &mut R) -> Vector2<S> {
+        let radius = rng.gen::<S>().sqrt();
+        Vector2::from_polar(radius, random_turn(rng))
+    }
+}
+
+impl<S: BaseFloat + Rand> Vector3<S> {
+    /// A unit vector uniformly distributed on the sphere.
+    pub fn random_unit<R: Rng>(rng: &mut R) -> Vector3<S> {
+        // Archimedes' hat-box theorem: the height is uniformly distributed
+        let two = S::two();
+        let z = two * rng.gen::<S>() - S::one();
+        let radius = (S::one() - z * z).max(S::zero()).sqrt();
+        Vector2::from_polar(radius, random_turn(rng)).extend(z)
+    }
+
+    /// A vector uniformly distributed in the ball of radius one.
+    pub fn random_in_unit_ball<R: Rng>(rng: &mut R) -> Vector3<S> {
+        let radius = rng.gen::<S>().cbrt();
+        Vector3::random_unit(rng) * radius
+    }
+}
+
+impl<S: BaseFloat + Rand> Quaternion<S> {
+    /// A unit quaternion representing a rotation uniformly distributed over
+    /// all rotations.
+    ///
+    /// This uses the method from Ken Shoemake's [Uniform Random Rotations]
+    /// (http://www.sciencedirect.com/science/article/pii/B9780080507552500361),
+    /// Graphics Gems III.
+    pub fn random_rotation<R: Rng>(rng: &mut R) -> Quaternion<S> {
+        let u = rng.gen::<S>();
+        let r1 = (S::one() - u).sqrt();
+        let r2 = u.sqrt();
+        let (sin1, cos1) = Rad::sin_cos(random_turn(rng));
+        let (sin2, cos2) = Rad::sin_cos(random_turn(rng));
+        Quaternion::new(r2 * cos2, r1 * sin1, r1 * cos1, r2 * sin2)
+    }
+}
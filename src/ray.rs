@@ -0,0 +1,192 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use rust_num::traits::cast;
+
+use num::BaseFloat;
+use plane::Plane;
+use point::{Point, Point2, Point3};
+use sphere::Sphere;
+use vector::{Vector, EuclideanVector, Vector2, Vector3};
+
+/// A generic ray starting at `origin` and extending infinitely in
+/// `direction`.
+#[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+pub struct Ray<P, V> {
+    pub origin: P,
+    pub direction: V,
+}
+
+impl<P: Point> Ray<P, P::Vector> {
+    /// Create a new ray from an origin and a direction.
+    #[inline]
+    pub fn new(origin: P, direction: P::Vector) -> Ray<P, P::Vector> {
+        Ray { origin: origin, direction: direction }
+    }
+
+    /// The point at the parameter `t` along the ray, that is
+    /// `origin + direction * t`.
+    #[inline]
+    pub fn at(&self, t: P::Scalar) -> P {
+        self.origin + self.direction * t
+    }
+}
+
+pub type Ray2<S> = Ray<Point2<S>, Vector2<S>>;
+pub type Ray3<S> = Ray<Point3<S>, Vector3<S>>;
+
+impl<S: BaseFloat> Ray<Point3<S>, Vector3<S>> {
+    /// Step through the cells of a grid of cubes with sides of `cell_size`,
+    /// in the order that the ray passes through them. Cell `(i, j, k)` covers
+    /// the points from `(i, j, k) * cell_size` to `(i + 1, j + 1, k + 1) *
+    /// cell_size`.
+    ///
+    /// The iterator yields each cell along with the ray parameter at which
+    /// the ray enters it, and never ends unless the ray has no direction.
+    /// Use `take_while` to stop at a maximum distance. When the origin lies
+    /// on a boundary between cells, the first cell is the one that the ray
+    /// moves into.
+    ///
+    /// The iterator is empty if the origin or direction of the ray is not
+    /// finite, or the cell of the origin is out of the range of `i64`, and
+    /// ends at the last cell in that range.
+    ///
+    /// This is the traversal algorithm from [A Fast Voxel Traversal Algorithm
+    /// for Ray Tracing](http://www.cse.yorku.ca/~amana/research/grid.pdf) by
+    /// Amanatides and Woo.
+    pub fn voxels(&self, cell_size: S) -> VoxelTraversal<S> {
+        let mut traversal = VoxelTraversal {
+            cell: Vector3::new(0, 0, 0),
+            step: Vector3::new(0, 0, 0),
+            t_max: Vector3::new(S::infinity(), S::infinity(), S::infinity()),
+            t_delta: Vector3::new(S::infinity(), S::infinity(), S::infinity()),
+            t: S::zero(),
+        };
+        for i in 0..3 {
+            let origin = self.origin[i] / cell_size;
+            let direction = self.direction[i] / cell_size;
+            let cell = if direction < S::zero() { origin.ceil() - S::one() } else { origin.floor() };
+            match cast(cell) {
+                Some(cell) if origin.is_finite() && direction.is_finite() => traversal.cell[i] = cell,
+                _ => {
+                    traversal.t = S::infinity();
+                    return traversal;
+                }
+            }
+            if direction > S::zero() {
+                traversal.step[i] = 1;
+                traversal.t_max[i] = (cell + S::one() - origin) / direction;
+                traversal.t_delta[i] = direction.recip();
+            } else if direction < S::zero() {
+                traversal.step[i] = -1;
+                traversal.t_max[i] = (cell - origin) / direction;
+                traversal.t_delta[i] = -direction.recip();
+            }
+        }
+        traversal
+    }
+
+    /// Find where the ray hits `plane`, returning the ray parameter and the
+    /// point of intersection.
+    ///
+    /// Returns `None` if the ray is parallel to the plane, or if the plane
+    /// lies behind the origin of the ray. Neither the direction of the ray
+    /// nor the normal of the plane need to be normalized.
+    pub fn intersect_plane(&self, plane: &Plane<S>) -> Option<(S, Point3<S>)> {
+        let denom = self.direction.dot(plane.n);
+        // compare the cosine of the angle between the ray and the normal
+        if !(denom.abs() > S::approx_epsilon() * (self.direction.length2() * plane.n.length2()).sqrt()) {
+            return None;
+        }
+        let t = -plane.distance(self.origin) / denom;
+        if t < S::zero() {
+            None
+        } else {
+            Some((t, self.at(t)))
+        }
+    }
+
+    /// Find where the ray first hits the surface of `sphere`, returning the
+    /// ray parameter and the point of intersection. If the ray starts inside
+    /// the sphere this is where it leaves.
+    ///
+    /// Returns `None` if the ray misses the sphere, or if the sphere lies
+    /// behind the origin of the ray.
+    pub fn intersect_sphere(&self, sphere: &Sphere<S>) -> Option<(S, Point3<S>)> {
+        let a = self.direction.length2();
+        if a == S::zero() {
+            return None;
+        }
+        let m = self.origin - sphere.center;
+        let b = m.dot(self.direction);
+        let c = m.length2() - sphere.radius * sphere.radius;
+        let discriminant = b * b - a * c;
+        if discriminant < S::zero() {
+            return None;
+        }
+        let root = discriminant.sqrt();
+        let near = (-b - root) / a;
+        let t = if near >= S::zero() { near } else { (-b + root) / a };
+        if t < S::zero() {
+            None
+        } else {
+            Some((t, self.at(t)))
+        }
+    }
+}
+
+/// An iterator over the grid cells that a ray passes through, created by
+/// `Ray3::voxels`.
+#[derive(Copy, Clone)]
+pub struct VoxelTraversal<S> {
+    cell: Vector3<i64>,
+    step: Vector3<i64>,
+    t_max: Vector3<S>,
+    t_delta: Vector3<S>,
+    t: S,
+}
+
+impl<S: BaseFloat> Iterator for VoxelTraversal<S> {
+    type Item = (Vector3<i64>, S);
+
+    fn next(&mut self) -> Option<(Vector3<i64>, S)> {
+        if self.t == S::infinity() {
+            return None;
+        }
+        let result = (self.cell, self.t);
+
+        let axis = if self.t_max.x < self.t_max.y {
+            if self.t_max.x < self.t_max.z { 0 } else { 2 }
+        } else {
+            if self.t_max.y < self.t_max.z { 1 } else { 2 }
+        };
+        self.t = self.t_max[axis];
+        match self.cell[axis].checked_add(self.step[axis]) {
+            Some(cell) => self.cell[axis] = cell,
+            None => self.t = S::infinity(),
+        }
+        self.t_max[axis] = self.t_max[axis] + self.t_delta[axis];
+
+        Some(result)
+    }
+}
+
+impl<P: fmt::Debug, V: fmt::Debug> fmt::Debug for Ray<P, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Ray({:?}, {:?})", self.origin, self.direction)
+    }
+}
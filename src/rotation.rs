@@ -216,6 +216,16 @@ impl<S: BaseFloat> ApproxEq for Basis2<S> {
     fn approx_eq_eps(&self, other: &Basis2<S>, epsilon: &S) -> bool {
         self.mat.approx_eq_eps(&other.mat, epsilon)
     }
+
+    #[inline]
+    fn relative_eq(&self, other: &Basis2<S>, epsilon: &S, max_relative: &S) -> bool {
+        self.mat.relative_eq(&other.mat, epsilon, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Basis2<S>, epsilon: &S, max_ulps: u32) -> bool {
+        self.mat.ulps_eq(&other.mat, epsilon, max_ulps)
+    }
 }
 
 impl<S: BaseFloat> Rotation2<S> for Basis2<S> {
@@ -300,6 +310,16 @@ impl<S: BaseFloat> ApproxEq for Basis3<S> {
     fn approx_eq_eps(&self, other: &Basis3<S>, epsilon: &S) -> bool {
         self.mat.approx_eq_eps(&other.mat, epsilon)
     }
+
+    #[inline]
+    fn relative_eq(&self, other: &Basis3<S>, epsilon: &S, max_relative: &S) -> bool {
+        self.mat.relative_eq(&other.mat, epsilon, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Basis3<S>, epsilon: &S, max_ulps: u32) -> bool {
+        self.mat.ulps_eq(&other.mat, epsilon, max_ulps)
+    }
 }
 
 impl<S: BaseFloat> Rotation3<S> for Basis3<S> {
@@ -323,3 +343,76 @@ impl<S: BaseFloat> Rotation3<S> for Basis3<S> {
         Basis3 { mat: Matrix3::from_angle_z(theta) }
     }
 }
+
+/// Composes a long sequence of small rotations, such as mouse-look deltas or
+/// gyroscope samples, without drifting.
+///
+/// Rounding errors make a quaternion that is repeatedly multiplied slowly
+/// lose its unit length, which shows up as a visible skew or scale. The
+/// accumulator renormalizes the rotation after every `interval` updates.
+#[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+pub struct AccumulatedRotation<S> {
+    rot: Quaternion<S>,
+    interval: usize,
+    pending: usize,
+}
+
+impl<S: BaseFloat> AccumulatedRotation<S> {
+    /// Start from the identity rotation, renormalizing after every `interval`
+    /// updates. An `interval` of one renormalizes after every update.
+    #[inline]
+    pub fn new(interval: usize) -> AccumulatedRotation<S> {
+        AccumulatedRotation::from_rotation(Quaternion::one(), interval)
+    }
+
+    /// Start from an existing rotation, renormalizing after every `interval`
+    /// updates.
+    #[inline]
+    pub fn from_rotation(rot: Quaternion<S>, interval: usize) -> AccumulatedRotation<S> {
+        assert!(interval > 0, "the renormalization interval must be positive");
+        AccumulatedRotation { rot: rot.normalize(), interval: interval, pending: 0 }
+    }
+
+    /// Apply `delta` after the current rotation, about the axes of the parent
+    /// space.
+    #[inline]
+    pub fn rotate(&mut self, delta: Quaternion<S>) {
+        self.rot = delta * self.rot;
+        self.updated();
+    }
+
+    /// Apply `delta` before the current rotation, that is about the rotated
+    /// local axes.
+    #[inline]
+    pub fn rotate_local(&mut self, delta: Quaternion<S>) {
+        self.rot = self.rot * delta;
+        self.updated();
+    }
+
+    #[inline]
+    fn updated(&mut self) {
+        self.pending += 1;
+        if self.pending >= self.interval {
+            self.renormalize();
+        }
+    }
+
+    /// Renormalize the rotation immediately.
+    #[inline]
+    pub fn renormalize(&mut self) {
+        self.rot = self.rot.normalize();
+        self.pending = 0;
+    }
+
+    /// The accumulated rotation.
+    #[inline]
+    pub fn quaternion(&self) -> Quaternion<S> {
+        self.rot
+    }
+
+    /// The accumulated rotation, as a matrix.
+    #[inline]
+    pub fn matrix(&self) -> Matrix3<S> {
+        self.rot.into()
+    }
+}
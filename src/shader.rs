@@ -0,0 +1,133 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Formatting of vectors and matrices as GLSL and HLSL source literals.
+
+use std::fmt;
+
+use matrix::{Matrix2, Matrix3, Matrix4};
+use vector::{Vector2, Vector3, Vector4};
+
+/// A scalar type that has an equivalent in shading languages.
+pub trait ShaderScalar: Copy + fmt::Display {
+    /// The prefix of GLSL vector and matrix types, for example `d` in
+    /// `dvec3`.
+    fn glsl_prefix() -> &'static str;
+    /// The suffix of GLSL literals, for example `lf` in `1.0lf`.
+    fn glsl_suffix() -> &'static str;
+    /// The HLSL scalar type, for example `float`.
+    fn hlsl_type() -> &'static str;
+    /// The suffix of HLSL literals, for example `L` in `1.0L`.
+    fn hlsl_suffix() -> &'static str;
+}
+
+impl ShaderScalar for f32 {
+    fn glsl_prefix() -> &'static str { "" }
+    fn glsl_suffix() -> &'static str { "" }
+    fn hlsl_type() -> &'static str { "float" }
+    fn hlsl_suffix() -> &'static str { "" }
+}
+
+impl ShaderScalar for f64 {
+    fn glsl_prefix() -> &'static str { "d" }
+    fn glsl_suffix() -> &'static str { "lf" }
+    fn hlsl_type() -> &'static str { "double" }
+    fn hlsl_suffix() -> &'static str { "L" }
+}
+
+/// Values that can be written as GLSL or HLSL constructor expressions, for
+/// embedding constants in generated shader source.
+///
+/// The scalars are written at full precision, and must be finite.
+pub trait ShaderLiteral {
+    /// Format as a GLSL constructor, for example `vec3(1.0, 2.0, 3.0)`.
+    ///
+    /// Matrix elements are listed column by column, matching the GLSL
+    /// constructor, so the resulting matrix has the same layout.
+    fn to_glsl_literal(&self) -> String;
+
+    /// Format as an HLSL constructor, for example `float3(1.0, 2.0, 3.0)`.
+    ///
+    /// Matrix elements are listed row by row, matching the HLSL
+    /// constructor, so `m[r][c]` in HLSL is element `self[c][r]`.
+    fn to_hlsl_literal(&self) -> String;
+}
+
+/// Format a scalar, making sure that it is parsed as a floating point
+/// literal.
+fn scalar<S: ShaderScalar>(x: S, suffix: &str) -> String {
+    let mut s = format!("{}", x);
+    if !s.contains('.') && !s.contains('e') {
+        s.push_str(".0");
+    }
+    s.push_str(suffix);
+    s
+}
+
+fn join<S: ShaderScalar>(elements: &[S], suffix: &str) -> String {
+    elements.iter().map(|&x| scalar(x, suffix)).collect::<Vec<_>>().join(", ")
+}
+
+macro_rules! impl_vector_literal {
+    ($VectorN:ident { $($field:ident),+ }, $n:expr) => {
+        impl<S: ShaderScalar> ShaderLiteral for $VectorN<S> {
+            fn to_glsl_literal(&self) -> String {
+                format!("{}vec{}({})", S::glsl_prefix(), $n,
+                        join(&[$(self.$field),+], S::glsl_suffix()))
+            }
+
+            fn to_hlsl_literal(&self) -> String {
+                format!("{}{}({})", S::hlsl_type(), $n,
+                        join(&[$(self.$field),+], S::hlsl_suffix()))
+            }
+        }
+    }
+}
+
+impl_vector_literal!(Vector2 { x, y }, 2);
+impl_vector_literal!(Vector3 { x, y, z }, 3);
+impl_vector_literal!(Vector4 { x, y, z, w }, 4);
+
+macro_rules! impl_matrix_literal {
+    ($MatrixN:ident, $n:expr) => {
+        impl<S: ShaderScalar> ShaderLiteral for $MatrixN<S> {
+            fn to_glsl_literal(&self) -> String {
+                let mut elements = Vec::with_capacity($n * $n);
+                for c in 0..$n {
+                    for r in 0..$n {
+                        elements.push(self[c][r]);
+                    }
+                }
+                format!("{}mat{}({})", S::glsl_prefix(), $n,
+                        join(&elements, S::glsl_suffix()))
+            }
+
+            fn to_hlsl_literal(&self) -> String {
+                let mut elements = Vec::with_capacity($n * $n);
+                for r in 0..$n {
+                    for c in 0..$n {
+                        elements.push(self[c][r]);
+                    }
+                }
+                format!("{}{}x{}({})", S::hlsl_type(), $n, $n,
+                        join(&elements, S::hlsl_suffix()))
+            }
+        }
+    }
+}
+
+impl_matrix_literal!(Matrix2, 2);
+impl_matrix_literal!(Matrix3, 3);
+impl_matrix_literal!(Matrix4, 4);
@@ -0,0 +1,47 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use num::BaseFloat;
+use point::Point3;
+use vector::EuclideanVector;
+
+/// A sphere, defined by its center and radius.
+#[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+pub struct Sphere<S> {
+    pub center: Point3<S>,
+    pub radius: S,
+}
+
+impl<S: BaseFloat> Sphere<S> {
+    /// Create a new sphere.
+    #[inline]
+    pub fn new(center: Point3<S>, radius: S) -> Sphere<S> {
+        Sphere { center: center, radius: radius }
+    }
+
+    /// Whether the point is inside the sphere or on its surface.
+    #[inline]
+    pub fn contains(&self, point: Point3<S>) -> bool {
+        (point - self.center).length2() <= self.radius * self.radius
+    }
+}
+
+impl<S: BaseFloat> fmt::Debug for Sphere<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Sphere({:?}, {:?})", self.center, self.radius)
+    }
+}
@@ -0,0 +1,142 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Real spherical harmonics of the first two bands (L1) and the first three
+//! bands (L2), as commonly used for storing low frequency lighting.
+//!
+//! The coefficients are ordered by band, and within each band from `m = -l`
+//! to `m = l`, following [Stupid Spherical Harmonics (SH) Tricks]
+//! (http://www.ppsloan.org/publications/StupidSH36.pdf) by Peter-Pike Sloan.
+
+use std::fmt;
+
+use rust_num::traits::cast;
+
+use num::BaseFloat;
+use vector::Vector3;
+
+macro_rules! impl_spherical_harmonics {
+    ($SH:ident, $n:expr) => {
+        impl<S: BaseFloat> $SH<S> {
+            /// Construct a set of coefficients.
+            #[inline]
+            pub fn new(coefficients: [S; $n]) -> $SH<S> {
+                $SH { coefficients: coefficients }
+            }
+
+            /// The coefficients of the zero function.
+            #[inline]
+            pub fn zero() -> $SH<S> {
+                $SH::new([S::zero(); $n])
+            }
+
+            /// Project a set of samples of a function on the sphere, taken in
+            /// uniformly distributed unit directions, into the basis.
+            pub fn project(samples: &[(Vector3<S>, S)]) -> $SH<S> {
+                let mut result = $SH::zero();
+                if samples.is_empty() {
+                    return result;
+                }
+                let four_pi: S = cast(4.0 * ::std::f64::consts::PI).unwrap();
+                let weight = four_pi / cast(samples.len()).unwrap();
+                for &(direction, value) in samples.iter() {
+                    result.add_sample(direction, value * weight);
+                }
+                result
+            }
+
+            /// Accumulate a single weighted sample in the unit `direction`.
+            #[inline]
+            pub fn add_sample(&mut self, direction: Vector3<S>, value: S) {
+                let basis = $SH::basis(direction);
+                for (c, b) in self.coefficients.iter_mut().zip(basis.coefficients.iter()) {
+                    *c = *c + *b * value;
+                }
+            }
+
+            /// The inner product of two projected functions, which is the
+            /// integral of their product over the sphere.
+            #[inline]
+            pub fn dot(&self, other: &$SH<S>) -> S {
+                self.coefficients.iter().zip(other.coefficients.iter())
+                    .fold(S::zero(), |acc, (&a, &b)| acc + a * b)
+            }
+
+            /// Reconstruct the projected function in the unit `direction`.
+            #[inline]
+            pub fn evaluate(&self, direction: Vector3<S>) -> S {
+                self.dot(&$SH::basis(direction))
+            }
+        }
+
+        impl<S: fmt::Debug> fmt::Debug for $SH<S> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}({:?})", stringify!($SH), &self.coefficients[..])
+            }
+        }
+    }
+}
+
+/// The coefficients of a function projected onto the first two bands of
+/// the spherical harmonics.
+#[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+pub struct SphericalHarmonicsL1<S> {
+    pub coefficients: [S; 4],
+}
+
+impl<S: BaseFloat> SphericalHarmonicsL1<S> {
+    /// The basis functions evaluated in the unit `direction`.
+    pub fn basis(direction: Vector3<S>) -> SphericalHarmonicsL1<S> {
+        let Vector3 { x, y, z } = direction;
+        let c0: S = cast(0.282094791773878f64).unwrap();
+        let c1: S = cast(0.488602511902920f64).unwrap();
+        SphericalHarmonicsL1::new([c0, c1 * y, c1 * z, c1 * x])
+    }
+}
+
+impl_spherical_harmonics!(SphericalHarmonicsL1, 4);
+
+/// The coefficients of a function projected onto the first three bands of
+/// the spherical harmonics.
+#[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+pub struct SphericalHarmonicsL2<S> {
+    pub coefficients: [S; 9],
+}
+
+impl<S: BaseFloat> SphericalHarmonicsL2<S> {
+    /// The basis functions evaluated in the unit `direction`.
+    pub fn basis(direction: Vector3<S>) -> SphericalHarmonicsL2<S> {
+        let Vector3 { x, y, z } = direction;
+        let c0: S = cast(0.282094791773878f64).unwrap();
+        let c1: S = cast(0.488602511902920f64).unwrap();
+        let c2: S = cast(1.092548430592079f64).unwrap();
+        let c3: S = cast(0.315391565252520f64).unwrap();
+        let c4: S = cast(0.546274215296040f64).unwrap();
+        let three: S = cast(3.0f64).unwrap();
+        SphericalHarmonicsL2::new([c0,
+                                   c1 * y, c1 * z, c1 * x,
+                                   c2 * x * y, c2 * y * z, c3 * (three * z * z - S::one()),
+                                   c2 * x * z, c4 * (x * x - y * y)])
+    }
+
+    /// Truncate to the first two bands.
+    #[inline]
+    pub fn truncate(&self) -> SphericalHarmonicsL1<S> {
+        let c = &self.coefficients;
+        SphericalHarmonicsL1::new([c[0], c[1], c[2], c[3]])
+    }
+}
+
+impl_spherical_harmonics!(SphericalHarmonicsL2, 9);
@@ -17,10 +17,12 @@ use std::fmt;
 
 use rust_num::{Zero, One};
 
+use angle::{Angle, Rad};
 use approx::ApproxEq;
 use matrix::*;
 use num::*;
 use point::*;
+use quaternion::Quaternion;
 use rotation::*;
 use vector::*;
 
@@ -170,6 +172,225 @@ impl<S: BaseFloat, R: fmt::Debug + Rotation3<S>> fmt::Debug for Decomposed<Vecto
     }
 }
 
+impl<S: BaseFloat> Decomposed<Vector3<S>, Quaternion<S>> {
+    /// Interpolate between two transforms, for blending animations or
+    /// smoothing networked state. The scale and displacement are
+    /// interpolated linearly, and the rotation spherically along the
+    /// shortest path.
+    pub fn interpolate(&self, other: &Decomposed<Vector3<S>, Quaternion<S>>, amount: S)
+                       -> Decomposed<Vector3<S>, Quaternion<S>> {
+        Decomposed {
+            scale: self.scale + (other.scale - self.scale) * amount,
+            rot: self.rot.slerp(other.rot.closest_to(self.rot), amount),
+            disp: self.disp.lerp(other.disp, amount),
+        }
+    }
+}
+
+impl<S: BaseFloat> Matrix4<S> {
+    /// Interpolate between two affine transformation matrices.
+    ///
+    /// Each matrix is decomposed into a translation, a rotation, and a
+    /// stretch, using the polar decomposition of its upper-left 3x3 block.
+    /// The rotations are interpolated spherically, and the translations and
+    /// stretches linearly, before being recomposed. Unlike interpolating the
+    /// elements directly, this doesn't shrink the result of interpolating
+    /// between two rotations.
+    ///
+    /// Returns `None` if either matrix is singular. The bottom row of both
+    /// matrices is assumed to be `[0, 0, 0, 1]`.
+    pub fn interpolate_affine(&self, other: &Matrix4<S>, amount: S) -> Option<Matrix4<S>> {
+        let (r0, s0) = match decompose_affine(self) { Some(d) => d, None => return None };
+        let (r1, s1) = match decompose_affine(other) { Some(d) => d, None => return None };
+
+        let q0 = Quaternion::from(r0);
+        let q1 = Quaternion::from(r1).closest_to(q0);
+        let rot = Matrix3::from(q0.slerp(q1, amount));
+        let stretch = s0 + (s1 - s0) * amount;
+
+        let mut m: Matrix4<S> = (rot * stretch).into();
+        m.w = self.w.truncate().lerp(other.w.truncate(), amount).extend(S::one());
+        Some(m)
+    }
+}
+
+/// Split the linear part of an affine transformation into a proper rotation
+/// and a stretch, with any reflection moved into the stretch.
+fn decompose_affine<S: BaseFloat>(m: &Matrix4<S>) -> Option<(Matrix3<S>, Matrix3<S>)> {
+    m.truncate().polar_decompose().map(|(r, s)| {
+        if r.determinant() < S::zero() { (-r, -s) } else { (r, s) }
+    })
+}
+
+/// A 2D transformation made up of a non-uniform scale, followed by a
+/// rotation, followed by a translation, as used for sprites and other 2D
+/// scene nodes.
+///
+/// Unlike `Decomposed`, the scale can differ along the `x` and `y` axes.
+/// Composing such transforms can produce a shear, which this type cannot
+/// represent, so `concat` and `invert` are only exact when the scales
+/// involved are uniform or the rotations are multiples of 90 degrees. In other
+/// cases the shear is dropped. Convert to a `Matrix3` to compose exactly.
+#[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+pub struct Decomposed2<S> {
+    pub position: Vector2<S>,
+    pub rotation: Rad<S>,
+    pub scale: Vector2<S>,
+}
+
+impl<S: BaseFloat> Decomposed2<S> {
+    /// Create a new transform.
+    #[inline]
+    pub fn new(position: Vector2<S>, rotation: Rad<S>, scale: Vector2<S>) -> Decomposed2<S> {
+        Decomposed2 { position: position, rotation: rotation, scale: scale }
+    }
+
+    /// Decompose an affine matrix into a scale, rotation, and translation.
+    /// Any shear in the matrix is dropped, and a reflection is represented by
+    /// a negative `y` scale.
+    pub fn from_matrix(mat: Matrix3<S>) -> Decomposed2<S> {
+        let x_axis = mat.x.truncate();
+        let sx = x_axis.length();
+        let rotation = Rad::atan2(x_axis.y, x_axis.x);
+        let sy = if sx == S::zero() {
+            mat.y.truncate().length()
+        } else {
+            x_axis.perp_dot(mat.y.truncate()) / sx
+        };
+        Decomposed2::new(mat.z.truncate(), rotation, Vector2::new(sx, sy))
+    }
+}
+
+impl<S: BaseFloat> Transform<Point2<S>> for Decomposed2<S> {
+    #[inline]
+    fn one() -> Decomposed2<S> {
+        Decomposed2::new(Vector2::zero(), Rad::zero(), Vector2::from_value(S::one()))
+    }
+
+    /// The transform taking `eye` to the origin and turning the direction
+    /// from `eye` to `center` onto the `y` axis. In two dimensions the
+    /// direction alone fixes the rotation, so `up` is unused.
+    fn look_at(eye: Point2<S>, center: Point2<S>, _up: Vector2<S>) -> Decomposed2<S> {
+        let dir = center - eye;
+        let rotation = Rad::atan2(dir.x, dir.y);
+        let position = Matrix2::from_angle(rotation) * (Point2::origin() - eye);
+        Decomposed2::new(position, rotation, Vector2::from_value(S::one()))
+    }
+
+    #[inline]
+    fn transform_vector(&self, vec: Vector2<S>) -> Vector2<S> {
+        Matrix2::from_angle(self.rotation) * (vec * self.scale)
+    }
+
+    #[inline]
+    fn transform_point(&self, point: Point2<S>) -> Point2<S> {
+        Point2::from_vec(self.transform_vector(point.to_vec()) + self.position)
+    }
+
+    #[inline]
+    fn concat(&self, other: &Decomposed2<S>) -> Decomposed2<S> {
+        Decomposed2::from_matrix(Matrix3::from(*self) * Matrix3::from(*other))
+    }
+
+    #[inline]
+    fn invert(&self) -> Option<Decomposed2<S>> {
+        Matrix3::from(*self).invert().map(Decomposed2::from_matrix)
+    }
+}
+
+impl<S: BaseFloat> From<Decomposed2<S>> for Matrix3<S> {
+    fn from(dec: Decomposed2<S>) -> Matrix3<S> {
+        let rot = Matrix2::from_angle(dec.rotation);
+        Matrix3::from_cols((rot.x * dec.scale.x).extend(S::zero()),
+                           (rot.y * dec.scale.y).extend(S::zero()),
+                           dec.position.extend(S::one()))
+    }
+}
+
+impl<S: BaseFloat> Transform2<S> for Decomposed2<S> {}
+
+impl<S: BaseFloat> ApproxEq for Decomposed2<S> {
+    type Epsilon = S;
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Decomposed2<S>, epsilon: &S) -> bool {
+        self.position.approx_eq_eps(&other.position, epsilon) &&
+        self.rotation.approx_eq_eps(&other.rotation, epsilon) &&
+        self.scale.approx_eq_eps(&other.scale, epsilon)
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Decomposed2<S>, epsilon: &S, max_relative: &S) -> bool {
+        self.position.relative_eq(&other.position, epsilon, max_relative) &&
+        self.rotation.relative_eq(&other.rotation, epsilon, max_relative) &&
+        self.scale.relative_eq(&other.scale, epsilon, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Decomposed2<S>, epsilon: &S, max_ulps: u32) -> bool {
+        self.position.ulps_eq(&other.position, epsilon, max_ulps) &&
+        self.rotation.ulps_eq(&other.rotation, epsilon, max_ulps) &&
+        self.scale.ulps_eq(&other.scale, epsilon, max_ulps)
+    }
+}
+
+impl<S: BaseFloat> fmt::Debug for Decomposed2<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(scale{:?}, rot({:?}), disp{:?})",
+            self.scale, self.rotation, self.position)
+    }
+}
+
+/// A homogeneous transformation matrix in 2-dimensional space.
+#[derive(Copy, Clone, RustcEncodable, RustcDecodable)]
+pub struct AffineMatrix2<S> {
+    pub mat: Matrix3<S>,
+}
+
+impl<S: BaseFloat> Transform<Point2<S>> for AffineMatrix2<S> {
+    #[inline]
+    fn one() -> AffineMatrix2<S> {
+        AffineMatrix2 { mat: Matrix3::identity() }
+    }
+
+    /// Create a 2D view matrix, rotating `center - eye` onto the positive
+    /// y-axis. `up` is unused, since a direction fixes the rotation in 2D.
+    fn look_at(eye: Point2<S>, center: Point2<S>, _up: Vector2<S>) -> AffineMatrix2<S> {
+        let dir = center - eye;
+        let rot = Matrix2::from_angle(Rad::atan2(dir.x, dir.y));
+        let mut mat: Matrix3<S> = rot.into();
+        mat.z = (rot * (Point2::origin() - eye)).extend(S::one());
+        AffineMatrix2 { mat: mat }
+    }
+
+    #[inline]
+    fn transform_vector(&self, vec: Vector2<S>) -> Vector2<S> {
+        (self.mat * vec.extend(S::zero())).truncate()
+    }
+
+    #[inline]
+    fn transform_point(&self, point: Point2<S>) -> Point2<S> {
+        let v = self.mat * point.to_vec().extend(S::one());
+        Point2::from_vec(v.truncate() / v.z)
+    }
+
+    #[inline]
+    fn concat(&self, other: &AffineMatrix2<S>) -> AffineMatrix2<S> {
+        AffineMatrix2 { mat: self.mat * other.mat }
+    }
+
+    #[inline]
+    fn invert(&self) -> Option<AffineMatrix2<S>> {
+        self.mat.invert().map(|m| AffineMatrix2 { mat: m })
+    }
+}
+
+impl<S: BaseNum> From<AffineMatrix2<S>> for Matrix3<S> {
+    #[inline] fn from(aff: AffineMatrix2<S>) -> Matrix3<S> { aff.mat }
+}
+
+impl<S: BaseFloat> Transform2<S> for AffineMatrix2<S> {}
+
 /// A homogeneous transformation matrix.
 #[derive(Copy, Clone, RustcEncodable, RustcDecodable)]
 pub struct AffineMatrix3<S> {
@@ -204,7 +425,7 @@ impl<S: BaseFloat> Transform<Point3<S>> for AffineMatrix3<S> {
 
     #[inline]
     fn invert(&self) -> Option<AffineMatrix3<S>> {
-        self.mat.invert().map(|m| AffineMatrix3{ mat: m })
+        SquareMatrix::invert(&self.mat).map(|m| AffineMatrix3{ mat: m })
     }
 }
 
@@ -213,3 +434,147 @@ impl<S: BaseNum> From<AffineMatrix3<S>> for Matrix4<S> {
 }
 
 impl<S: BaseFloat> Transform3<S> for AffineMatrix3<S> {}
+
+impl<S: BaseFloat> Transform<Point3<S>> for Matrix4<S> {
+    #[inline]
+    fn one() -> Matrix4<S> {
+        Matrix4::identity()
+    }
+
+    #[inline]
+    fn look_at(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Matrix4<S> {
+        Matrix4::look_at(eye, center, up)
+    }
+
+    #[inline]
+    fn transform_vector(&self, vec: Vector3<S>) -> Vector3<S> {
+        (self * vec.extend(S::zero())).truncate()
+    }
+
+    #[inline]
+    fn transform_point(&self, point: Point3<S>) -> Point3<S> {
+        Point3::from_homogeneous(self * point.to_homogeneous())
+    }
+
+    #[inline]
+    fn concat(&self, other: &Matrix4<S>) -> Matrix4<S> {
+        self * other
+    }
+
+    #[inline]
+    fn invert(&self) -> Option<Matrix4<S>> {
+        SquareMatrix::invert(self)
+    }
+}
+
+impl<S: BaseFloat> Transform3<S> for Matrix4<S> {}
+
+impl<S: BaseFloat> Transform<Point3<S>> for Quaternion<S> {
+    #[inline]
+    fn one() -> Quaternion<S> {
+        <Quaternion<S> as Rotation<Point3<S>>>::one()
+    }
+
+    /// Create a rotation that looks along `center - eye`. A quaternion can't
+    /// hold a translation, so the position of `eye` is otherwise ignored.
+    #[inline]
+    fn look_at(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Quaternion<S> {
+        <Quaternion<S> as Rotation<Point3<S>>>::look_at(center - eye, up)
+    }
+
+    #[inline]
+    fn transform_vector(&self, vec: Vector3<S>) -> Vector3<S> {
+        self.rotate_vector(vec)
+    }
+
+    #[inline]
+    fn transform_point(&self, point: Point3<S>) -> Point3<S> {
+        self.rotate_point(point)
+    }
+
+    #[inline]
+    fn concat(&self, other: &Quaternion<S>) -> Quaternion<S> {
+        self * other
+    }
+
+    #[inline]
+    fn invert(&self) -> Option<Quaternion<S>> {
+        if self.magnitude2() == S::zero() {
+            None
+        } else {
+            Some(Rotation::invert(self))
+        }
+    }
+}
+
+impl<S: BaseFloat> Transform3<S> for Quaternion<S> {}
+
+/// A builder for model matrices, composed from a sequence of translations,
+/// rotations and scales.
+///
+/// The operations are applied to points in the order that the methods are
+/// called, so each call multiplies its matrix onto the *left* of the
+/// matrix built so far. A typical model matrix, which scales, then rotates,
+/// then translates an object (`T * R * S`), is built with:
+///
+/// ```
+/// use cgmath::{Matrix4, Quaternion, TransformBuilder, Vector3};
+///
+/// let t = Vector3::new(1.0f32, 2.0, 3.0);
+/// let r = Quaternion::one();
+/// let m: Matrix4<f32> = TransformBuilder::new().scale(2.0).rotate(r).translate(t).build();
+/// ```
+#[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+pub struct TransformBuilder<S> {
+    mat: Matrix4<S>,
+}
+
+impl<S: BaseFloat> TransformBuilder<S> {
+    /// Start with the identity transform.
+    #[inline]
+    pub fn new() -> TransformBuilder<S> {
+        TransformBuilder { mat: Matrix4::identity() }
+    }
+
+    /// Apply a translation after the current transform.
+    #[inline]
+    pub fn translate(self, v: Vector3<S>) -> TransformBuilder<S> {
+        self.then(Matrix4::from_translation(v))
+    }
+
+    /// Apply a rotation about the origin after the current transform.
+    #[inline]
+    pub fn rotate<R: Into<Matrix3<S>>>(self, rotation: R) -> TransformBuilder<S> {
+        self.then(Matrix4::from(rotation.into()))
+    }
+
+    /// Apply a uniform scale about the origin after the current transform.
+    #[inline]
+    pub fn scale(self, value: S) -> TransformBuilder<S> {
+        self.then(Matrix4::from_scale(value))
+    }
+
+    /// Apply a non-uniform scale about the origin after the current
+    /// transform.
+    #[inline]
+    pub fn nonuniform_scale(self, x: S, y: S, z: S) -> TransformBuilder<S> {
+        self.then(Matrix4::from_nonuniform_scale(x, y, z))
+    }
+
+    #[inline]
+    fn then(self, mat: Matrix4<S>) -> TransformBuilder<S> {
+        TransformBuilder { mat: mat.mul_affine(&self.mat) }
+    }
+
+    /// The composed transformation matrix.
+    #[inline]
+    pub fn build(&self) -> Matrix4<S> {
+        self.mat
+    }
+
+    /// The composed transformation, as an `AffineMatrix3`.
+    #[inline]
+    pub fn build_affine(&self) -> AffineMatrix3<S> {
+        AffineMatrix3 { mat: self.mat }
+    }
+}
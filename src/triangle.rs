@@ -0,0 +1,198 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::ops::{Add, Mul};
+
+use rust_num::One;
+
+use num::{BaseFloat, PartialOrd};
+use point::{Point, Point3};
+use vector::{Vector, EuclideanVector, Vector3};
+
+/// The [barycentric coordinates]
+/// (https://en.wikipedia.org/wiki/Barycentric_coordinate_system) of `p` with
+/// respect to the triangle `abc`, such that `p = u * a + v * b + w * c`.
+///
+/// Works for both `Point2` and `Point3`. Points off the plane of a 3D
+/// triangle are first projected onto it. Returns `None` if the triangle is
+/// degenerate, or so close to degenerate that its area is lost in rounding
+/// error.
+pub fn barycentric<P>(p: P, a: P, b: P, c: P) -> Option<Vector3<P::Scalar>> where
+    P: Point,
+    P::Scalar: BaseFloat,
+    P::Vector: EuclideanVector,
+{
+    let v0 = b - a;
+    let v1 = c - a;
+    let v2 = p - a;
+    let d00 = v0.dot(v0);
+    let d01 = v0.dot(v1);
+    let d11 = v1.dot(v1);
+    let d20 = v2.dot(v0);
+    let d21 = v2.dot(v1);
+    let v3 = c - b;
+    // `denom` is the squared length of the cross product of `v0` and `v1`
+    let denom = d00 * d11 - d01 * d01;
+    if is_degenerate(denom, d00.partial_max(d11).partial_max(v3.dot(v3))) {
+        return None;
+    }
+    let v = (d11 * d20 - d01 * d21) / denom;
+    let w = (d00 * d21 - d01 * d20) / denom;
+    Some(Vector3::new(P::Scalar::one() - v - w, v, w))
+}
+
+/// Whether a triangle is degenerate, given the squared length of the cross
+/// product of two of its edges and the squared length of its longest edge.
+///
+/// The rounding error in the cross product grows with the fourth power of the
+/// longest edge, so this only rejects triangles whose area is within a few
+/// ulps of zero, regardless of which vertex the edges share.
+pub(crate) fn is_degenerate<S: BaseFloat>(cross_length2: S, longest_edge_length2: S) -> bool {
+    let four = S::two() * S::two();
+    !(cross_length2 > four * S::epsilon() * longest_edge_length2 * longest_edge_length2)
+}
+
+/// Interpolate per-vertex attributes `a`, `b`, and `c` with the barycentric
+/// coordinates `bary`, as returned by `barycentric`.
+///
+/// The attributes can be scalars, or vectors such as normals, colours, and
+/// texture coordinates.
+#[inline]
+pub fn barycentric_interpolate<S, T>(bary: Vector3<S>, a: T, b: T, c: T) -> T where
+    S: BaseFloat,
+    T: Add<T, Output = T> + Mul<S, Output = T>,
+{
+    a * bary.x + b * bary.y + c * bary.z
+}
+
+/// A triangle in three dimensions, with vertices `a`, `b`, and `c`.
+#[derive(Copy, Clone, PartialEq, RustcEncodable, RustcDecodable)]
+pub struct Triangle3<S> {
+    pub a: Point3<S>,
+    pub b: Point3<S>,
+    pub c: Point3<S>,
+}
+
+impl<S: BaseFloat> Triangle3<S> {
+    /// Create a new triangle.
+    #[inline]
+    pub fn new(a: Point3<S>, b: Point3<S>, c: Point3<S>) -> Triangle3<S> {
+        Triangle3 { a: a, b: b, c: c }
+    }
+
+    /// The unit normal of the triangle. The vertices are wound
+    /// counter-clockwise when viewed from the side the normal points to.
+    ///
+    /// The result is not finite for degenerate triangles.
+    #[inline]
+    pub fn normal(&self) -> Vector3<S> {
+        (self.b - self.a).cross(self.c - self.a).normalize()
+    }
+
+    /// The area of the triangle.
+    #[inline]
+    pub fn area(&self) -> S {
+        let half = S::half();
+        (self.b - self.a).cross(self.c - self.a).length() * half
+    }
+
+    /// The centroid of the triangle, the average of its vertices.
+    #[inline]
+    pub fn centroid(&self) -> Point3<S> {
+        let third = (S::one() + S::one() + S::one()).recip();
+        self.a + ((self.b - self.a) + (self.c - self.a)) * third
+    }
+
+    /// The barycentric coordinates of `point` with respect to the triangle.
+    /// See `barycentric`.
+    #[inline]
+    pub fn barycentric(&self, point: Point3<S>) -> Option<Vector3<S>> {
+        barycentric(point, self.a, self.b, self.c)
+    }
+
+    /// Whether the point lies approximately in the plane of the triangle,
+    /// and inside it or on its edges. The distance from the plane is allowed
+    /// to be the approximate epsilon times the length of the longest edge.
+    /// Always `false` for degenerate triangles.
+    pub fn contains(&self, point: Point3<S>) -> bool {
+        let bary = match self.barycentric(point) {
+            Some(bary) => bary,
+            None => return false,
+        };
+        let epsilon = S::approx_epsilon();
+        let longest = (self.b - self.a).length2()
+                          .partial_max((self.c - self.a).length2())
+                          .partial_max((self.c - self.b).length2());
+        bary.x >= -epsilon && bary.y >= -epsilon && bary.z >= -epsilon &&
+            (point - self.a).dot(self.normal()).abs() <= epsilon * longest.sqrt()
+    }
+
+    /// The point on the triangle closest to `point`.
+    ///
+    /// This is the method from Christer Ericson's Real-Time Collision
+    /// Detection, which finds the Voronoi region of the triangle containing
+    /// the point.
+    pub fn closest_point(&self, point: Point3<S>) -> Point3<S> {
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+
+        let ap = point - self.a;
+        let d1 = ab.dot(ap);
+        let d2 = ac.dot(ap);
+        if d1 <= S::zero() && d2 <= S::zero() {
+            return self.a;
+        }
+
+        let bp = point - self.b;
+        let d3 = ab.dot(bp);
+        let d4 = ac.dot(bp);
+        if d3 >= S::zero() && d4 <= d3 {
+            return self.b;
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= S::zero() && d1 >= S::zero() && d3 <= S::zero() {
+            return self.a + ab * (d1 / (d1 - d3));
+        }
+
+        let cp = point - self.c;
+        let d5 = ab.dot(cp);
+        let d6 = ac.dot(cp);
+        if d6 >= S::zero() && d5 <= d6 {
+            return self.c;
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= S::zero() && d2 >= S::zero() && d6 <= S::zero() {
+            return self.a + ac * (d2 / (d2 - d6));
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= S::zero() && d4 - d3 >= S::zero() && d5 - d6 >= S::zero() {
+            return self.b + (self.c - self.b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+
+        // inside the face
+        let denom = (va + vb + vc).recip();
+        self.a + ab * (vb * denom) + ac * (vc * denom)
+    }
+}
+
+impl<S: BaseFloat> fmt::Debug for Triangle3<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Triangle3({:?}, {:?}, {:?})", self.a, self.b, self.c)
+    }
+}
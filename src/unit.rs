@@ -0,0 +1,266 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Wrappers for values that are known to have unit length.
+
+use std::fmt;
+use std::ops::*;
+
+use rust_num::traits::cast;
+use rustc_serialize::{Decodable, Decoder};
+
+use angle::{Angle, Rad};
+use approx::ApproxEq;
+use num::BaseFloat;
+use quaternion::Quaternion;
+use rotation::Rotation3;
+use vector::{Vector, EuclideanVector};
+use vector::{Vector2, Vector3, Vector4};
+
+/// A value that has been normalized to unit length.
+///
+/// Operations that require unit inputs can then skip renormalizing them.
+/// The wrapped value can be read through `Deref`, but can only be changed by
+/// constructing a new `Unit`, so the invariant can't be broken by accident.
+#[derive(Copy, Clone, PartialEq, RustcEncodable)]
+pub struct Unit<T> {
+    value: T,
+}
+
+pub type UnitVector2<S> = Unit<Vector2<S>>;
+pub type UnitVector3<S> = Unit<Vector3<S>>;
+pub type UnitVector4<S> = Unit<Vector4<S>>;
+pub type UnitQuaternion<S> = Unit<Quaternion<S>>;
+
+impl<T> Unit<T> {
+    /// Wrap a value without checking its length. The caller is responsible
+    /// for ensuring that it is normalized.
+    #[inline]
+    pub fn new_unchecked(value: T) -> Unit<T> {
+        Unit { value: value }
+    }
+
+    /// Unwrap the normalized value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for Unit<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> AsRef<T> for Unit<T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for Unit<T> {
+    type Epsilon = T::Epsilon;
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Unit<T>, epsilon: &T::Epsilon) -> bool {
+        self.value.approx_eq_eps(&other.value, epsilon)
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Unit<T>, epsilon: &T::Epsilon, max_relative: &T::Epsilon) -> bool {
+        self.value.relative_eq(&other.value, epsilon, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Unit<T>, epsilon: &T::Epsilon, max_ulps: u32) -> bool {
+        self.value.ulps_eq(&other.value, epsilon, max_ulps)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Unit<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Unit({:?})", self.value)
+    }
+}
+
+/// Decoding goes through `try_new`, so values that are not approximately of
+/// unit length are rejected instead of breaking the invariant.
+macro_rules! impl_decodable_unit {
+    ($T:ident) => {
+        impl<S: BaseFloat + Decodable> Decodable for Unit<$T<S>> {
+            fn decode<D: Decoder>(d: &mut D) -> Result<Unit<$T<S>>, D::Error> {
+                let value = d.read_struct("Unit", 1, |d| d.read_struct_field("value", 0, Decodable::decode))?;
+                Unit::<$T<S>>::try_new(value).ok_or_else(|| d.error("value is not of unit length"))
+            }
+        }
+    }
+}
+
+impl_decodable_unit!(Vector2);
+impl_decodable_unit!(Vector3);
+impl_decodable_unit!(Vector4);
+impl_decodable_unit!(Quaternion);
+
+/// The threshold above which the dot product of two unit values is close
+/// enough to one to interpolate them linearly.
+fn slerp_threshold<S: BaseFloat>() -> S {
+    cast(0.9995f64).unwrap()
+}
+
+macro_rules! impl_unit_vector {
+    ($VectorN:ident) => {
+        impl<S: BaseFloat> Unit<$VectorN<S>> {
+            /// Normalize a vector and wrap it.
+            #[inline]
+            pub fn new_normalize(v: $VectorN<S>) -> Unit<$VectorN<S>> {
+                Unit::new_unchecked(v.normalize())
+            }
+
+            /// Wrap a vector, returning `None` if it is not approximately of
+            /// unit length.
+            #[inline]
+            pub fn try_new(v: $VectorN<S>) -> Option<Unit<$VectorN<S>>> {
+                if v.length2().approx_eq(&S::one()) { Some(Unit::new_unchecked(v)) } else { None }
+            }
+
+            /// The angle between two unit vectors.
+            #[inline]
+            pub fn angle(self, other: Unit<$VectorN<S>>) -> Rad<S> {
+                Rad::acos(self.value.dot(other.value).max(-S::one()).min(S::one()))
+            }
+
+            /// Reflect `v` in the plane with this normal.
+            #[inline]
+            pub fn reflect(self, v: $VectorN<S>) -> $VectorN<S> {
+                v - self.value * (S::two() * v.dot(self.value))
+            }
+
+            /// Spherical linear interpolation between two unit vectors.
+            ///
+            /// The result is undefined if the vectors point in opposite
+            /// directions.
+            pub fn slerp(self, other: Unit<$VectorN<S>>, amount: S) -> Unit<$VectorN<S>> {
+                let dot = self.value.dot(other.value);
+                if dot > slerp_threshold() {
+                    Unit::<$VectorN<S>>::new_normalize(self.value.lerp(other.value, amount))
+                } else {
+                    let theta = Rad::acos(dot.max(-S::one()));
+                    let scale1 = Rad::sin(theta * (S::one() - amount));
+                    let scale2 = Rad::sin(theta * amount);
+                    Unit::new_unchecked((self.value * scale1 + other.value * scale2) / Rad::sin(theta))
+                }
+            }
+        }
+
+        impl<S: BaseFloat> Neg for Unit<$VectorN<S>> {
+            type Output = Unit<$VectorN<S>>;
+
+            #[inline]
+            fn neg(self) -> Unit<$VectorN<S>> {
+                Unit::new_unchecked(-self.value)
+            }
+        }
+    }
+}
+
+impl_unit_vector!(Vector2);
+impl_unit_vector!(Vector3);
+impl_unit_vector!(Vector4);
+
+impl<S: BaseFloat> Unit<Quaternion<S>> {
+    /// Normalize a quaternion and wrap it.
+    #[inline]
+    pub fn new_normalize(q: Quaternion<S>) -> Unit<Quaternion<S>> {
+        Unit::new_unchecked(q.normalize())
+    }
+
+    /// Wrap a quaternion, returning `None` if it is not approximately of
+    /// unit length.
+    #[inline]
+    pub fn try_new(q: Quaternion<S>) -> Option<Unit<Quaternion<S>>> {
+        if q.magnitude2().approx_eq(&S::one()) { Some(Unit::new_unchecked(q)) } else { None }
+    }
+
+    /// The identity rotation.
+    #[inline]
+    pub fn identity() -> Unit<Quaternion<S>> {
+        Unit::new_unchecked(Quaternion::one())
+    }
+
+    /// Create a rotation of `angle` around a unit `axis`.
+    #[inline]
+    pub fn from_axis_angle(axis: Unit<Vector3<S>>, angle: Rad<S>) -> Unit<Quaternion<S>> {
+        Unit::new_unchecked(Quaternion::from_axis_angle(axis.value, angle))
+    }
+
+    /// The axis and angle of the rotation. The angle is in the range
+    /// `[0, π]`, and the axis is `x` for the identity rotation.
+    pub fn to_axis_angle(self) -> (Unit<Vector3<S>>, Rad<S>) {
+        let q = if self.value.s < S::zero() { -self.value } else { self.value };
+        let sin = q.v.length();
+        let angle = Rad::atan2(sin, q.s) * S::two();
+        if sin == S::zero() {
+            (Unit::new_unchecked(Vector3::unit_x()), angle)
+        } else {
+            (Unit::new_unchecked(q.v / sin), angle)
+        }
+    }
+
+    /// The inverse rotation. For a unit quaternion this is the conjugate.
+    #[inline]
+    pub fn invert(self) -> Unit<Quaternion<S>> {
+        Unit::new_unchecked(self.value.conjugate())
+    }
+
+    /// Rotate a vector.
+    #[inline]
+    pub fn rotate_vector(self, v: Vector3<S>) -> Vector3<S> {
+        self.value * v
+    }
+
+    /// Spherical linear interpolation between two rotations.
+    #[inline]
+    pub fn slerp(self, other: Unit<Quaternion<S>>, amount: S) -> Unit<Quaternion<S>> {
+        Unit::new_unchecked(self.value.slerp(other.value, amount))
+    }
+
+    /// Normalize the quaternion again, to remove the error accumulated over a
+    /// long chain of multiplications.
+    #[inline]
+    pub fn renormalize(self) -> Unit<Quaternion<S>> {
+        Unit::<Quaternion<S>>::new_normalize(self.value)
+    }
+}
+
+impl<S: BaseFloat> Mul for Unit<Quaternion<S>> {
+    type Output = Unit<Quaternion<S>>;
+
+    #[inline]
+    fn mul(self, other: Unit<Quaternion<S>>) -> Unit<Quaternion<S>> {
+        Unit::new_unchecked(self.value * other.value)
+    }
+}
+
+impl<S: BaseFloat> From<Unit<Quaternion<S>>> for Quaternion<S> {
+    #[inline]
+    fn from(q: Unit<Quaternion<S>>) -> Quaternion<S> {
+        q.value
+    }
+}
@@ -87,6 +87,7 @@
 //! features of the vectors, while [`EuclideanVector`]
 //! (../array/trait.EuclideanVector.html) is more specific to Euclidean space.
 
+use std::cmp::{self, Ordering};
 use std::fmt;
 use std::mem;
 use std::ops::*;
@@ -98,7 +99,8 @@ use rust_num::{NumCast, Zero, One};
 use angle::{Angle, Rad};
 use approx::ApproxEq;
 use array::Array;
-use num::{BaseNum, BaseFloat, PartialOrd};
+use matrix::{Matrix2, Matrix3, Matrix4};
+use num::{BaseNum, BaseFloat, PartialOrd, TotalOrd};
 
 /// A trait that specifies a range of numeric operations for vectors. Not all
 /// of these make sense from a linear algebra point of view, but are included
@@ -139,15 +141,31 @@ pub trait Vector: Copy + Clone where
 // Utility macro for generating associated functions for the vectors
 macro_rules! impl_vector {
     ($VectorN:ident <$S:ident> { $($field:ident),+ }, $n:expr, $constructor:ident) => {
-        #[derive(PartialEq, Eq, Copy, Clone, Hash, RustcEncodable, RustcDecodable)]
+        #[derive(PartialEq, Copy, Clone, RustcEncodable, RustcDecodable)]
         pub struct $VectorN<S> { $(pub $field: S),+ }
 
+        impl_hash!($VectorN<$S> { $($field),+ });
+
         impl<$S> $VectorN<$S> {
             /// Construct a new vector, using the provided values.
             #[inline]
             pub fn new($($field: $S),+) -> $VectorN<$S> {
                 $VectorN { $($field: $field),+ }
             }
+
+            /// Apply a function to each component of the vector, returning
+            /// a vector of the results.
+            #[inline]
+            pub fn map<U, F: FnMut($S) -> U>(self, mut f: F) -> $VectorN<U> {
+                $VectorN { $($field: f(self.$field)),+ }
+            }
+
+            /// Apply a function to each pair of corresponding components of
+            /// this vector and `other`, returning a vector of the results.
+            #[inline]
+            pub fn zip<T, U, F: FnMut($S, T) -> U>(self, other: $VectorN<T>, mut f: F) -> $VectorN<U> {
+                $VectorN { $($field: f(self.$field, other.$field)),+ }
+            }
         }
 
         impl<$S: Copy + Neg<Output = $S>> $VectorN<$S> {
@@ -164,6 +182,62 @@ macro_rules! impl_vector {
             $VectorN::new($($field),+)
         }
 
+        impl<$S: BaseNum> $VectorN<$S> {
+            /// The component-wise minimum of the vector and `other`.
+            #[inline]
+            pub fn min_v(self, other: $VectorN<$S>) -> $VectorN<$S> {
+                $VectorN { $($field: self.$field.partial_min(other.$field)),+ }
+            }
+
+            /// The component-wise maximum of the vector and `other`.
+            #[inline]
+            pub fn max_v(self, other: $VectorN<$S>) -> $VectorN<$S> {
+                $VectorN { $($field: self.$field.partial_max(other.$field)),+ }
+            }
+
+            /// Restrict each component of the vector to the range given by the
+            /// corresponding components of `lo` and `hi`.
+            #[inline]
+            pub fn clamp(self, lo: $VectorN<$S>, hi: $VectorN<$S>) -> $VectorN<$S> {
+                self.max_v(lo).min_v(hi)
+            }
+
+            /// Restrict each component of the vector to the range `[0, 1]`.
+            #[inline]
+            pub fn saturate(self) -> $VectorN<$S> {
+                self.clamp($VectorN::from_value($S::zero()), $VectorN::from_value($S::one()))
+            }
+
+            /// Returns `true` if every component is exactly zero.
+            #[inline]
+            pub fn is_zero(&self) -> bool {
+                $(self.$field == $S::zero())&&+
+            }
+
+            /// The index of the smallest component of the vector. Ties go to
+            /// the lowest index.
+            #[inline]
+            pub fn min_index(self) -> usize {
+                let mut index = 0;
+                for i in 1..$n {
+                    if self[i] < self[index] { index = i; }
+                }
+                index
+            }
+
+            /// The index of the largest component of the vector, for example
+            /// to find the dominant axis of a direction. Ties go to the lowest
+            /// index.
+            #[inline]
+            pub fn max_index(self) -> usize {
+                let mut index = 0;
+                for i in 1..$n {
+                    if self[i] > self[index] { index = i; }
+                }
+                index
+            }
+        }
+
         impl<$S: NumCast + Copy> $VectorN<$S> {
             /// Component-wise casting to another type
             #[inline]
@@ -172,6 +246,41 @@ macro_rules! impl_vector {
             }
         }
 
+        impl<$S: cmp::PartialOrd> $VectorN<$S> {
+            /// Compare this vector with `other` lexicographically, component by
+            /// component. Returns `None` if the first pair of components that
+            /// differ is unordered, for example if one of them is `NaN`.
+            #[inline]
+            pub fn lex_partial_cmp(&self, other: &$VectorN<$S>) -> Option<Ordering> {
+                let (a, b): (&[$S; $n], &[$S; $n]) = (self.as_ref(), other.as_ref());
+                a.partial_cmp(b)
+            }
+        }
+
+        impl<$S: cmp::Ord> $VectorN<$S> {
+            /// Compare this vector with `other` lexicographically, component by
+            /// component.
+            #[inline]
+            pub fn lex_cmp(&self, other: &$VectorN<$S>) -> Ordering {
+                let (a, b): (&[$S; $n], &[$S; $n]) = (self.as_ref(), other.as_ref());
+                a.cmp(b)
+            }
+        }
+
+        impl<$S: TotalOrd> $VectorN<$S> {
+            /// Compare this vector with `other` lexicographically, using the
+            /// total ordering of the components. This never fails, so it can be
+            /// passed directly to `sort_by` for vectors of floats.
+            #[inline]
+            pub fn total_cmp(&self, other: &$VectorN<$S>) -> Ordering {
+                $(match self.$field.total_cmp(&other.$field) {
+                    Ordering::Equal => {},
+                    ordering => return ordering,
+                })+
+                Ordering::Equal
+            }
+        }
+
         impl<S: Copy> Array for $VectorN<S> {
             type Element = S;
 
@@ -203,6 +312,41 @@ macro_rules! impl_vector {
             fn approx_eq_eps(&self, other: &$VectorN<S>, epsilon: &S) -> bool {
                 $(self.$field.approx_eq_eps(&other.$field, epsilon))&&+
             }
+
+            #[inline]
+            fn relative_eq(&self, other: &$VectorN<S>, epsilon: &S, max_relative: &S) -> bool {
+                $(self.$field.relative_eq(&other.$field, epsilon, max_relative))&&+
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &$VectorN<S>, epsilon: &S, max_ulps: u32) -> bool {
+                $(self.$field.ulps_eq(&other.$field, epsilon, max_ulps))&&+
+            }
+        }
+
+        impl<S: BaseFloat> $VectorN<S> {
+            /// Returns `true` if none of the components are infinite or NaN.
+            #[inline]
+            pub fn is_finite(&self) -> bool {
+                $(self.$field.is_finite())&&+
+            }
+        }
+
+        impl<S: Zero> $VectorN<S> {
+            /// The vector with every component zero. This is the same as
+            /// `Vector::zero`, but only needs the scalar to have a zero.
+            #[inline]
+            pub fn zero() -> $VectorN<S> {
+                $VectorN { $($field: S::zero()),+ }
+            }
+        }
+
+        impl<S: One> $VectorN<S> {
+            /// The vector with every component one.
+            #[inline]
+            pub fn one() -> $VectorN<S> {
+                $VectorN { $($field: S::one()),+ }
+            }
         }
 
         impl<S: BaseFloat + Rand> Rand for $VectorN<S> {
@@ -293,12 +437,16 @@ impl_fixed_array_conversions!(Vector2<S> { x: 0, y: 1 }, 2);
 impl_fixed_array_conversions!(Vector3<S> { x: 0, y: 1, z: 2 }, 3);
 impl_fixed_array_conversions!(Vector4<S> { x: 0, y: 1, z: 2, w: 3 }, 4);
 
+impl_display_parse!(Vector2<S> { x, y }, 2);
+impl_display_parse!(Vector3<S> { x, y, z }, 3);
+impl_display_parse!(Vector4<S> { x, y, z, w }, 4);
+
 impl_tuple_conversions!(Vector2<S> { x, y }, (S, S));
 impl_tuple_conversions!(Vector3<S> { x, y, z }, (S, S, S));
 impl_tuple_conversions!(Vector4<S> { x, y, z, w }, (S, S, S, S));
 
-/// Operations specific to numeric two-dimensional vectors.
-impl<S: BaseNum> Vector2<S> {
+/// Unit vectors, which only need a zero and a one.
+impl<S: Zero + One> Vector2<S> {
     /// A unit vector in the `x` direction.
     #[inline]
     pub fn unit_x() -> Vector2<S> {
@@ -310,7 +458,10 @@ impl<S: BaseNum> Vector2<S> {
     pub fn unit_y() -> Vector2<S> {
         Vector2::new(S::zero(), S::one())
     }
+}
 
+/// Operations specific to numeric two-dimensional vectors.
+impl<S: BaseNum> Vector2<S> {
     /// The perpendicular dot product of the vector and `other`.
     #[inline]
     pub fn perp_dot(self, other: Vector2<S>) -> S {
@@ -325,8 +476,43 @@ impl<S: BaseNum> Vector2<S> {
     }
 }
 
-/// Operations specific to numeric three-dimensional vectors.
-impl<S: BaseNum> Vector3<S> {
+/// Operations specific to signed two-dimensional vectors.
+impl<S: BaseNum + Neg<Output = S>> Vector2<S> {
+    /// Returns the vector rotated by 90 degrees counter-clockwise. Its dot
+    /// product with `other` is the same as `self.perp_dot(other)`.
+    #[inline]
+    pub fn perp(self) -> Vector2<S> {
+        Vector2::new(-self.y, self.x)
+    }
+}
+
+/// Operations specific to floating point two-dimensional vectors.
+impl<S: BaseFloat> Vector2<S> {
+    /// Create a vector from polar coordinates, with `angle` measured
+    /// counter-clockwise from the `x` axis.
+    #[inline]
+    pub fn from_polar(radius: S, angle: Rad<S>) -> Vector2<S> {
+        let (sin, cos) = Rad::sin_cos(angle);
+        Vector2::new(radius * cos, radius * sin)
+    }
+
+    /// Convert the vector to polar coordinates, `(radius, angle)`. The angle
+    /// is in the range `[-π, π]`. This is the inverse of `from_polar`.
+    #[inline]
+    pub fn to_polar(self) -> (S, Rad<S>) {
+        (self.length(), Rad::atan2(self.y, self.x))
+    }
+
+    /// Returns the outer product of the vector and `other`, the matrix
+    /// `self * other^T`.
+    #[inline]
+    pub fn outer(self, other: Vector2<S>) -> Matrix2<S> {
+        Matrix2::from_cols(self * other.x, self * other.y)
+    }
+}
+
+/// Unit vectors, which only need a zero and a one.
+impl<S: Zero + One> Vector3<S> {
     /// A unit vector in the `x` direction.
     #[inline]
     pub fn unit_x() -> Vector3<S> {
@@ -344,7 +530,10 @@ impl<S: BaseNum> Vector3<S> {
     pub fn unit_z() -> Vector3<S> {
         Vector3::new(S::zero(), S::zero(), S::one())
     }
+}
 
+/// Operations specific to numeric three-dimensional vectors.
+impl<S: BaseNum> Vector3<S> {
     /// Returns the cross product of the vector and `other`.
     #[inline]
     #[must_use]
@@ -368,8 +557,79 @@ impl<S: BaseNum> Vector3<S> {
     }
 }
 
-/// Operations specific to numeric four-dimensional vectors.
-impl<S: BaseNum> Vector4<S> {
+/// Operations specific to floating point three-dimensional vectors.
+impl<S: BaseFloat> Vector3<S> {
+    /// Create a vector from spherical coordinates, where `theta` is the
+    /// polar angle measured from the `z` axis and `phi` is the azimuthal angle
+    /// measured counter-clockwise from the `x` axis in the `xy` plane.
+    #[inline]
+    pub fn from_spherical(radius: S, theta: Rad<S>, phi: Rad<S>) -> Vector3<S> {
+        let (sin_theta, cos_theta) = Rad::sin_cos(theta);
+        let (sin_phi, cos_phi) = Rad::sin_cos(phi);
+        Vector3::new(radius * sin_theta * cos_phi,
+                     radius * sin_theta * sin_phi,
+                     radius * cos_theta)
+    }
+
+    /// Convert the vector to spherical coordinates, `(radius, theta, phi)`,
+    /// with `theta` in the range `[0, π]` and `phi` in the range `[-π, π]`.
+    /// This is the inverse of `from_spherical`.
+    #[inline]
+    pub fn to_spherical(self) -> (S, Rad<S>, Rad<S>) {
+        let rho = self.x.hypot(self.y);
+        (self.length(), Rad::atan2(rho, self.z), Rad::atan2(self.y, self.x))
+    }
+
+    /// Create a vector from cylindrical coordinates, where `angle` is
+    /// measured counter-clockwise from the `x` axis and `height` is along the
+    /// `z` axis.
+    #[inline]
+    pub fn from_cylindrical(radius: S, angle: Rad<S>, height: S) -> Vector3<S> {
+        Vector2::from_polar(radius, angle).extend(height)
+    }
+
+    /// Convert the vector to cylindrical coordinates, `(radius, angle,
+    /// height)`. This is the inverse of `from_cylindrical`.
+    #[inline]
+    pub fn to_cylindrical(self) -> (S, Rad<S>, S) {
+        let (radius, angle) = self.truncate().to_polar();
+        (radius, angle, self.z)
+    }
+
+    /// Returns two unit vectors that, together with this one, form a
+    /// right-handed orthonormal basis. The vector must be normalized.
+    ///
+    /// This uses the branchless construction from [Building an Orthonormal
+    /// Basis, Revisited](http://jcgt.org/published/0006/01/01/) by Duff et
+    /// al., which is continuous everywhere except across the `z = 0` plane.
+    #[inline]
+    pub fn any_orthonormal_pair(self) -> (Vector3<S>, Vector3<S>) {
+        let sign = self.z.signum();
+        let a = -(sign + self.z).recip();
+        let b = self.x * self.y * a;
+        (Vector3::new(S::one() + sign * self.x * self.x * a, sign * b, -sign * self.x),
+         Vector3::new(b, sign + self.y * self.y * a, -self.y))
+    }
+
+    /// Returns the outer product of the vector and `other`, the matrix
+    /// `self * other^T`.
+    #[inline]
+    pub fn outer(self, other: Vector3<S>) -> Matrix3<S> {
+        Matrix3::from_cols(self * other.x, self * other.y, self * other.z)
+    }
+
+    /// Returns the skew-symmetric cross product matrix of the vector, for
+    /// which `v.cross_matrix() * w == v.cross(w)`.
+    #[inline]
+    pub fn cross_matrix(self) -> Matrix3<S> {
+        Matrix3::new(S::zero(), self.z, -self.y,
+                     -self.z, S::zero(), self.x,
+                     self.y, -self.x, S::zero())
+    }
+}
+
+/// Unit vectors, which only need a zero and a one.
+impl<S: Zero + One> Vector4<S> {
     /// A unit vector in the `x` direction.
     #[inline]
     pub fn unit_x() -> Vector4<S> {
@@ -393,7 +653,10 @@ impl<S: BaseNum> Vector4<S> {
     pub fn unit_w() -> Vector4<S> {
         Vector4::new(S::zero(), S::zero(), S::zero(), S::one())
     }
+}
 
+/// Operations specific to numeric four-dimensional vectors.
+impl<S: BaseNum> Vector4<S> {
     /// Create a `Vector3`, dropping the `w` value.
     #[inline]
     pub fn truncate(self)-> Vector3<S> {
@@ -413,6 +676,16 @@ impl<S: BaseNum> Vector4<S> {
     }
 }
 
+/// Operations specific to floating point four-dimensional vectors.
+impl<S: BaseFloat> Vector4<S> {
+    /// Returns the outer product of the vector and `other`, the matrix
+    /// `self * other^T`.
+    #[inline]
+    pub fn outer(self, other: Vector4<S>) -> Matrix4<S> {
+        Matrix4::from_cols(self * other.x, self * other.y, self * other.z, self * other.w)
+    }
+}
+
 /// Specifies geometric operations for vectors. This is only implemented for
 /// 2-dimensional and 3-dimensional vectors.
 pub trait EuclideanVector: Vector + Sized where
@@ -426,6 +699,12 @@ pub trait EuclideanVector: Vector + Sized where
         self.dot(other).approx_eq(&Self::Scalar::zero())
     }
 
+    /// Returns `true` if the vector has approximately unit length.
+    #[inline]
+    fn is_normalized(self) -> bool {
+        self.length2().approx_eq(&Self::Scalar::one())
+    }
+
     /// Returns the squared length of the vector. This does not perform an
     /// expensive square root operation like in the `length` method and can
     /// therefore be more efficient for comparing the lengths of two vectors.
@@ -441,6 +720,34 @@ pub trait EuclideanVector: Vector + Sized where
         <<Self as Vector>::Scalar as ::rust_num::Float>::sqrt(self.dot(self))
     }
 
+    /// Returns the squared distance between the vector and `other`. Like
+    /// `length2`, this avoids a square root, so it is cheaper for comparing
+    /// distances.
+    #[inline]
+    fn distance2(self, other: Self) -> Self::Scalar {
+        (other - self).length2()
+    }
+
+    /// The distance between the vector and `other`.
+    #[inline]
+    fn distance(self, other: Self) -> Self::Scalar {
+        (other - self).length()
+    }
+
+    /// Move towards `target` by at most `max_distance`, without
+    /// overshooting. Returns `target` once it is within reach.
+    #[inline]
+    #[must_use]
+    fn move_towards(self, target: Self, max_distance: Self::Scalar) -> Self {
+        let delta = target - self;
+        let distance = delta.length();
+        if distance <= max_distance {
+            target
+        } else {
+            self + delta * (max_distance / distance)
+        }
+    }
+
     /// The angle between the vector and `other`, in radians.
     fn angle(self, other: Self) -> Rad<Self::Scalar>;
 
@@ -0,0 +1,47 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_transform() {
+    let aabb = Aabb3::new(Point3::new(1.0f64, -2.0, 0.0), Point3::new(3.0, 2.0, 1.0));
+
+    let translate = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0));
+    let moved = aabb.transform(&translate);
+    assert!(moved.min.approx_eq(&Point3::new(2.0, 0.0, 3.0)));
+    assert!(moved.max.approx_eq(&Point3::new(4.0, 4.0, 4.0)));
+
+    // the result is the bounds of the transformed corners
+    let mat = translate * Matrix4::from(Matrix3::from_axis_angle(Vector3::new(0.0, 0.6, 0.8), rad(0.7)))
+                        * Matrix4::from_nonuniform_scale(2.0, 1.0, -0.5);
+    let mut expected: Option<Aabb3<f64>> = None;
+    for i in 0..8 {
+        let corner = Point3::new(if i & 1 == 0 { aabb.min.x } else { aabb.max.x },
+                                 if i & 2 == 0 { aabb.min.y } else { aabb.max.y },
+                                 if i & 4 == 0 { aabb.min.z } else { aabb.max.z });
+        let p = Point3::from_homogeneous(mat * corner.to_homogeneous());
+        expected = Some(match expected {
+            None => Aabb3::new(p, p),
+            Some(bounds) => bounds.grow(p),
+        });
+    }
+    let expected = expected.unwrap();
+    let transformed = aabb.transform(&mat);
+    assert!(transformed.min.approx_eq(&expected.min));
+    assert!(transformed.max.approx_eq(&expected.max));
+}
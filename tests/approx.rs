@@ -13,7 +13,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-#[macro_use]
 extern crate cgmath;
 
 use cgmath::*;
@@ -39,3 +38,54 @@ fn macro_assert_approx_eq() {
 fn macro_assert_approx_eq_fail() {
     assert_approx_eq!(1.0f64 / 3.0, 0.333);
 }
+
+#[test]
+fn test_relative_eq() {
+    // A fixed tolerance is too tight for large values...
+    assert!(!1.0e7f32.approx_eq(&(1.0e7 + 1.0)));
+    assert!(1.0e7f32.relative_eq(&(1.0e7 + 1.0), &1e-5, &1e-5));
+    // ...and too loose for small ones
+    assert!(1.0e-7f64.approx_eq(&2.0e-7));
+    assert!(!1.0e-7f64.relative_eq(&2.0e-7, &1e-12, &1e-5));
+    assert!(0.0f64.relative_eq(&1e-13, &1e-12, &1e-5));
+
+    let a = Matrix2::new(1.0e6f64, 0.0, 2.0, 3.0e-3);
+    let b = Matrix2::new(1.000001e6f64, 1e-13, 2.0, 3.0e-3);
+    assert!(a.relative_eq(&b, &1e-12, &1e-5));
+    assert!(!a.relative_eq(&(b * 2.0), &1e-12, &1e-5));
+    assert!(Vector3::new(1.0e9f64, 1.0, 0.0).relative_eq(&Vector3::new(1.0e9 + 1.0, 1.0, 0.0),
+                                                          &1e-12, &1e-8));
+}
+
+#[test]
+fn test_ulps_eq() {
+    let x = 1.0f32;
+    let next = f32::from_bits(x.to_bits() + 2);
+    assert!(x.ulps_eq(&next, &0.0, 2));
+    assert!(!x.ulps_eq(&next, &0.0, 1));
+    assert!(!x.ulps_eq(&-x, &0.0, 4));
+    assert!(0.0f32.ulps_eq(&-0.0, &0.0, 0));
+    assert!(!std::f64::NAN.ulps_eq(&std::f64::NAN, &0.0, 4));
+
+    let q = Quaternion::new(1.0f64, 0.5, 0.25, 0.0);
+    assert!(q.ulps_eq(&(q * (1.0 + std::f64::EPSILON)), &0.0, 4));
+}
+
+/// A type that only implements the required method of `ApproxEq`.
+#[derive(Debug)]
+struct Length(f64);
+
+impl ApproxEq for Length {
+    type Epsilon = f64;
+
+    fn approx_eq_eps(&self, other: &Length, epsilon: &f64) -> bool {
+        self.0.approx_eq_eps(&other.0, epsilon)
+    }
+}
+
+#[test]
+fn test_default_methods() {
+    assert!(Length(1.0).relative_eq(&Length(1.001), &0.01, &0.0));
+    assert!(!Length(1.0e9).relative_eq(&Length(1.0e9 + 1.0), &0.01, &1.0));
+    assert!(Length(1.0).ulps_eq(&Length(1.001), &0.01, 0));
+}
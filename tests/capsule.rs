@@ -0,0 +1,52 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::*;
+
+fn capsule() -> Capsule3<f64> {
+    Capsule3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 2.0, 0.0), 0.5)
+}
+
+#[test]
+fn test_contains() {
+    let c = capsule();
+    assert!(c.contains(Point3::new(0.4, 1.0, 0.0)));
+    assert!(c.contains(Point3::new(0.0, 2.4, 0.0)));
+    assert!(!c.contains(Point3::new(0.6, 1.0, 0.0)));
+    assert!(!c.contains(Point3::new(0.4, 2.4, 0.0)));
+}
+
+#[test]
+fn test_intersects() {
+    let c = capsule();
+    assert!(c.intersects_sphere(&Sphere::new(Point3::new(1.0, 1.0, 0.0), 0.6)));
+    assert!(!c.intersects_sphere(&Sphere::new(Point3::new(1.0, 3.0, 0.0), 0.6)));
+
+    let crossing = Capsule3::new(Point3::new(-2.0, 1.0, 0.9), Point3::new(2.0, 1.0, 0.9), 0.5);
+    assert!(c.intersects_capsule(&crossing));
+    let above = Capsule3::new(Point3::new(-2.0, 1.0, 1.1), Point3::new(2.0, 1.0, 1.1), 0.5);
+    assert!(!c.intersects_capsule(&above));
+
+    let aabb = Aabb3::new(Point3::new(0.4, 2.4, -1.0), Point3::new(1.0, 3.0, 1.0));
+    // the corner of the box is just outside the rounded end
+    assert!(!c.intersects_aabb(&aabb));
+    assert!(c.intersects_aabb(&Aabb3::new(Point3::new(0.3, 1.0, -1.0), Point3::new(1.0, 3.0, 1.0))));
+
+    let cylinder = Cylinder3::new(Point3::new(0.0, 2.45, -1.0), Point3::new(0.0, 2.45, 1.0), 0.1);
+    assert!(c.intersects_cylinder(&cylinder));
+    assert!(cylinder.intersects_capsule(&c));
+}
@@ -0,0 +1,79 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_arithmetic() {
+    let a = Rgb::new(0.5f32, 0.25, 1.0);
+    let b = Rgb::new(0.5f32, 0.5, 0.25);
+    assert_eq!(a + b, Rgb::new(1.0, 0.75, 1.25));
+    assert_eq!(a - b, Rgb::new(0.0, -0.25, 0.75));
+    assert_eq!(a * b, Rgb::new(0.25, 0.125, 0.25));
+    assert_eq!(a * 2.0, Rgb::new(1.0, 0.5, 2.0));
+    assert_eq!(Rgba::new(1.0f32, 0.5, 0.0, 1.0) / 2.0, Rgba::new(0.5, 0.25, 0.0, 0.5));
+}
+
+#[test]
+fn test_hsv() {
+    assert_approx_eq!(Rgb::from_hsv(deg(0.0f64), 1.0, 1.0), Rgb::new(1.0, 0.0, 0.0));
+    assert_approx_eq!(Rgb::from_hsv(deg(120.0f64), 1.0, 1.0), Rgb::new(0.0, 1.0, 0.0));
+    assert_approx_eq!(Rgb::from_hsv(deg(-120.0f64), 1.0, 0.5), Rgb::new(0.0, 0.0, 0.5));
+    assert_approx_eq!(Rgb::from_hsv(deg(30.0f64), 0.5, 1.0), Rgb::new(1.0, 0.75, 0.5));
+
+    let (h, s, v) = Rgb::new(1.0f64, 0.75, 0.5).to_hsv();
+    assert_approx_eq!(h, deg(30.0));
+    assert_approx_eq!(s, 0.5);
+    assert_approx_eq!(v, 1.0);
+
+    // grays have no hue or saturation
+    let (h, s, v) = Rgb::new(0.25f64, 0.25, 0.25).to_hsv();
+    assert_eq!((h, s, v), (deg(0.0), 0.0, 0.25));
+
+    for &c in [Rgb::new(0.2f64, 0.4, 0.9), Rgb::new(0.9, 0.1, 0.3), Rgb::new(0.3, 0.8, 0.1)].iter() {
+        let (h, s, v) = c.to_hsv();
+        assert_approx_eq!(Rgb::from_hsv(h, s, v), c);
+    }
+}
+
+#[test]
+fn test_srgb() {
+    assert_eq!(srgb_to_linear(0.0f32), 0.0);
+    assert_approx_eq!(srgb_to_linear(1.0f32), 1.0);
+    assert_approx_eq!(srgb_to_linear(0.5f64), 0.21404114048223255);
+    assert_approx_eq!(linear_to_srgb(0.21404114048223255f64), 0.5);
+
+    // alpha is not converted
+    let c = Rgba::new(0.5f64, 0.02, 1.0, 0.5);
+    assert_eq!(c.to_linear().a, 0.5);
+    assert_approx_eq!(c.to_linear().to_srgb(), c);
+}
+
+#[test]
+fn test_pack() {
+    assert_eq!(Rgba::new(1.0f32, 0.0, 0.5, 0.2).pack_rgba8(), 0x33_80_00_ff);
+    assert_eq!(Rgb::new(0.0f32, 1.0, 0.0).pack_rgba8(), 0xff_00_ff_00);
+    assert_approx_eq_eps!(Rgba::unpack_rgba8(0x33_80_00_ff), Rgba::new(1.0, 0.0, 0.5, 0.2), 1.0 / 255.0);
+}
+
+#[test]
+fn test_vector_conversions() {
+    let c = Rgba::new(0.1f32, 0.2, 0.3, 0.4);
+    assert_eq!(Vector4::from(c), Vector4::new(0.1, 0.2, 0.3, 0.4));
+    assert_eq!(Rgba::from(Vector4::from(c)), c);
+    assert_eq!(Rgb::from(Vector3::new(0.1f32, 0.2, 0.3)), c.rgb());
+}
@@ -0,0 +1,61 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::*;
+
+fn cylinder() -> Cylinder3<f64> {
+    Cylinder3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 0.0, 2.0), 1.0)
+}
+
+#[test]
+fn test_contains() {
+    let c = cylinder();
+    assert!(c.height().approx_eq(&2.0));
+    assert!(c.contains(Point3::new(0.7, 0.7, 0.0)));
+    assert!(c.contains(Point3::new(0.0, -1.0, 2.0)));
+    assert!(!c.contains(Point3::new(0.8, 0.8, 1.0)));
+    assert!(!c.contains(Point3::new(0.0, 0.0, 2.1)));
+}
+
+#[test]
+fn test_intersects() {
+    let c = cylinder();
+    // unlike a capsule the cylinder has sharp edges
+    assert!(!c.intersects_sphere(&Sphere::new(Point3::new(1.2, 0.0, 2.2), 0.25)));
+    assert!(c.intersects_sphere(&Sphere::new(Point3::new(1.2, 0.0, 1.8), 0.25)));
+    assert!(c.intersects_sphere(&Sphere::new(Point3::new(0.0, 0.0, 2.2), 0.25)));
+
+    assert!(c.intersects_aabb(&Aabb3::new(Point3::new(0.6, 0.6, 1.0), Point3::new(2.0, 2.0, 3.0))));
+    assert!(!c.intersects_aabb(&Aabb3::new(Point3::new(0.8, 0.8, 1.0), Point3::new(2.0, 2.0, 3.0))));
+
+    let lying = Cylinder3::new(Point3::new(-3.0, 0.0, 2.4), Point3::new(3.0, 0.0, 2.4), 0.5);
+    assert!(c.intersects_cylinder(&lying));
+    let higher = Cylinder3::new(Point3::new(-3.0, 0.0, 2.6), Point3::new(3.0, 0.0, 2.6), 0.5);
+    assert!(!c.intersects_cylinder(&higher));
+
+    // flat caps facing each other, where the support points are along the axes
+    for i in 0..10 {
+        let t = i as f64 * 0.6;
+        let (x, y) = (t.cos() * 0.5, t.sin() * 0.5);
+        let above = Cylinder3::new(Point3::new(x, y, 2.001), Point3::new(x, y, 4.0), 0.7);
+        assert!(!c.intersects_cylinder(&above));
+        let above = Cylinder3::new(Point3::new(x, y, 1.999), Point3::new(x, y, 4.0), 0.7);
+        assert!(c.intersects_cylinder(&above));
+        let capsule = Capsule3::new(Point3::new(x, y, 2.5 + 1e-9), Point3::new(x, y, 4.0), 0.5);
+        assert!(!c.intersects_capsule(&capsule));
+    }
+}
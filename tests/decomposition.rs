@@ -0,0 +1,265 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::{Matrix, Matrix2, Matrix3, Matrix4, SquareMatrix};
+use cgmath::{Vector3, Vector4, Quaternion, Rotation3, ApproxEq, EuclideanVector};
+
+#[test]
+fn test_lu() {
+    let a = Matrix3::new(0.0f64, 2.0, 1.0,
+                         1.0, 1.0, -1.0,
+                         3.0, 0.5, 2.0);
+    let lu = a.lu().unwrap();
+    // rebuild P * A from the permutation
+    let pa = Matrix3::from_cols(a.row(lu.perm[0]), a.row(lu.perm[1]), a.row(lu.perm[2])).transpose();
+    assert!((lu.l * lu.u).approx_eq(&pa));
+    assert_eq!(lu.l[0][0], 1.0);
+    assert_eq!(lu.u[0][1], 0.0);
+    assert!(lu.determinant().approx_eq(&a.determinant()));
+}
+
+#[test]
+fn test_lu_solve() {
+    let a = Matrix4::new(2.0f64, 1.0, 0.0, 3.0,
+                         -1.0, 4.0, 2.0, 0.0,
+                         0.5, 0.0, 1.0, -2.0,
+                         1.0, 1.0, 1.0, 1.0);
+    let b = Vector4::new(1.0, -2.0, 0.5, 3.0);
+    let x = a.lu().unwrap().solve(b);
+    assert!((a * x).approx_eq(&b));
+    assert!(x.approx_eq(&(a.invert().unwrap() * b)));
+    assert!(a.lu().unwrap().determinant().approx_eq(&a.determinant()));
+
+    let x = Matrix3::from_angle_z(::cgmath::rad(0.4f64)).lu().unwrap().solve(Vector3::unit_x());
+    assert!(x.approx_eq(&(Matrix3::from_angle_z(::cgmath::rad(-0.4f64)) * Vector3::unit_x())));
+}
+
+#[test]
+fn test_solve() {
+    let a = Matrix4::new(2.0f64, 1.0, 0.0, 3.0,
+                         -1.0, 4.0, 2.0, 0.0,
+                         0.5, 0.0, 1.0, -2.0,
+                         1.0, 1.0, 1.0, 1.0);
+    let b = Vector4::new(1.0, -2.0, 0.5, 3.0);
+    assert!((a * a.solve(b).unwrap()).approx_eq(&b));
+
+    let a = Matrix3::new(0.0f64, 2.0, 1.0,
+                         1.0, 1.0, -1.0,
+                         3.0, 0.5, 2.0);
+    let x = a.solve(Vector3::new(1.0, 2.0, 3.0)).unwrap();
+    assert!((a * x).approx_eq(&Vector3::new(1.0, 2.0, 3.0)));
+
+    assert_eq!(Matrix3::new(1.0f64, 2.0, 3.0,
+                            2.0, 4.0, 6.0,
+                            0.0, 1.0, 0.0).solve(Vector3::unit_x()), None);
+}
+
+#[test]
+fn test_lu_singular() {
+    assert!(Matrix2::new(1.0f64, 2.0, 2.0, 4.0).lu().is_none());
+    assert!(Matrix3::<f64>::zero().lu().is_none());
+
+    // rounding leaves a tiny nonzero pivot in a singular matrix
+    let nearly = Matrix3::new(0.1f64, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9);
+    assert!(nearly.lu().is_none());
+    assert_eq!(nearly.lu().is_some(), nearly.is_invertible());
+    // the test doesn't depend on the scale of the matrix
+    let small = Matrix3::from_value(1e-4f64);
+    assert!(small.lu().is_some());
+    assert!((small * 1e-6).lu().is_some());
+}
+
+#[test]
+fn test_cholesky() {
+    let a = Matrix3::new(4.0f64, 12.0, -16.0,
+                         12.0, 37.0, -43.0,
+                         -16.0, -43.0, 98.0);
+    let chol = a.cholesky().unwrap();
+    assert!(chol.l.approx_eq(&Matrix3::new(2.0, 6.0, -8.0,
+                                           0.0, 1.0, 5.0,
+                                           0.0, 0.0, 3.0)));
+    assert!((chol.l * chol.l.transpose()).approx_eq(&a));
+    assert!(chol.determinant().approx_eq(&a.determinant()));
+    let b = Vector3::new(1.0, -2.0, 0.5);
+    assert!((a * chol.solve(b)).approx_eq(&b));
+
+    let a = Matrix4::new(5.0f64, 1.0, 0.0, 0.5,
+                         1.0, 4.0, 1.0, 0.0,
+                         0.0, 1.0, 3.0, 1.0,
+                         0.5, 0.0, 1.0, 2.0);
+    let b = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    assert!(a.cholesky().unwrap().solve(b).approx_eq(&a.solve(b).unwrap()));
+}
+
+#[test]
+fn test_cholesky_not_positive_definite() {
+    assert!(Matrix2::new(1.0f64, 2.0, 2.0, 1.0).cholesky().is_none());
+    assert!(Matrix2::new(1.0f64, 1.0, 1.0, 1.0).cholesky().is_none());
+    assert!(Matrix3::<f64>::from_value(-1.0).cholesky().is_none());
+}
+
+#[test]
+fn test_qr() {
+    let a = Matrix3::new(2.0f64, 1.0, 0.0,
+                         -1.0, 3.0, 1.0,
+                         0.5, 0.0, 4.0);
+    let (q, r) = a.qr().unwrap();
+    assert!((q * r).approx_eq(&a));
+    assert!((q.transpose() * q).is_identity());
+    assert!(r[0][1] == 0.0 && r[0][2] == 0.0 && r[1][2] == 0.0);
+    assert!(r[0][0] > 0.0 && r[1][1] > 0.0 && r[2][2] > 0.0);
+
+    assert!(Matrix2::new(1.0f64, 2.0, 2.0, 4.0).qr().is_none());
+}
+
+#[test]
+fn test_orthonormalize() {
+    let rot = Matrix3::from_axis_angle(Vector3::new(1.0f64, 2.0, 3.0).normalize(), ::cgmath::rad(0.9));
+    let drifted = rot + Matrix3::new(1e-3, -2e-3, 0.0,
+                                     0.0, 1e-3, 3e-3,
+                                     -1e-3, 0.0, 2e-3);
+    assert!(!(drifted.transpose() * drifted).is_identity());
+    let fixed = drifted.orthonormalize();
+    assert!((fixed.transpose() * fixed).is_identity());
+    assert!(fixed.approx_eq_eps(&rot, &1e-2));
+
+    let m = Matrix4::from_nonuniform_scale(2.0f64, 3.0, 4.0).orthonormalize();
+    assert!(m.is_identity());
+}
+
+#[test]
+fn test_eigen_symmetric() {
+    let a = Matrix3::new(4.0f64, 1.0, -2.0,
+                         1.0, 2.0, 0.5,
+                         -2.0, 0.5, 3.0);
+    let (values, vectors) = a.eigen_symmetric();
+    assert!(values.x >= values.y && values.y >= values.z);
+    for i in 0..3 {
+        assert!((a * vectors[i]).approx_eq(&(vectors[i] * values[i])));
+    }
+    assert!((vectors.transpose() * vectors).is_identity());
+    assert!(vectors.determinant().approx_eq(&1.0));
+    assert!((values.x + values.y + values.z).approx_eq(&a.trace()));
+
+    // A diagonal matrix is already decomposed
+    let (values, _) = Matrix3::from_diagonal(Vector3::new(1.0f64, 5.0, 3.0)).eigen_symmetric();
+    assert_eq!(values, Vector3::new(5.0, 3.0, 1.0));
+
+    let (values, vectors) = Matrix2::new(2.0f64, 1.0, 1.0, 2.0).eigen_symmetric();
+    assert!(values.approx_eq(&::cgmath::Vector2::new(3.0, 1.0)));
+    assert!((vectors[0].x.abs()).approx_eq(&0.5f64.sqrt()));
+}
+
+#[test]
+fn test_polar_decompose() {
+    let rot = Matrix3::from_axis_angle(Vector3::new(1.0f64, -1.0, 2.0).normalize(), ::cgmath::rad(1.1));
+    let stretch = Matrix3::new(2.0f64, 0.3, 0.0,
+                               0.3, 1.0, -0.2,
+                               0.0, -0.2, 0.5);
+    let (r, s) = (rot * stretch).polar_decompose().unwrap();
+    assert!(r.approx_eq(&rot));
+    assert!(s.approx_eq(&stretch));
+    assert!(s.is_symmetric());
+
+    // A reflection is kept in the orthogonal factor
+    let (r, s) = Matrix3::from_diagonal(Vector3::new(-2.0f64, 3.0, 1.0)).polar_decompose().unwrap();
+    assert!(r.approx_eq(&Matrix3::from_diagonal(Vector3::new(-1.0, 1.0, 1.0))));
+    assert!(s.approx_eq(&Matrix3::from_diagonal(Vector3::new(2.0, 3.0, 1.0))));
+
+    assert!(Matrix3::new(1.0f64, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 0.0, 1.0).polar_decompose().is_none());
+}
+
+fn check_svd(a: Matrix3<f64>) {
+    let (u, sigma, v) = a.svd();
+    assert!((u * Matrix3::from_diagonal(sigma) * v.transpose()).approx_eq(&a));
+    assert!((u.transpose() * u).is_identity());
+    assert!((v.transpose() * v).is_identity());
+    assert!(sigma.x >= sigma.y && sigma.y >= sigma.z && sigma.z >= 0.0);
+}
+
+#[test]
+fn test_svd() {
+    check_svd(Matrix3::new(4.0, 1.0, -2.0,
+                           0.0, 2.0, 0.5,
+                           -2.0, 3.5, 3.0));
+    // rank deficient
+    check_svd(Matrix3::new(1.0, 2.0, 3.0,
+                           2.0, 4.0, 6.0,
+                           -1.0, 0.0, 1.0));
+    check_svd(Matrix3::new(1.0, 2.0, 3.0,
+                           2.0, 4.0, 6.0,
+                           -1.0, -2.0, -3.0));
+    check_svd(Matrix3::zero());
+
+    let rot = Matrix3::from_angle_y(::cgmath::rad(0.7f64));
+    let (_, sigma, _) = (rot * Matrix3::from_diagonal(Vector3::new(1.0, 3.0, 2.0))).svd();
+    assert!(sigma.approx_eq(&Vector3::new(3.0, 2.0, 1.0)));
+
+    let a = Matrix2::new(3.0f64, 0.0, 4.0, 5.0);
+    let (u, sigma, v) = a.svd();
+    assert!((u * Matrix2::from_diagonal(sigma) * v.transpose()).approx_eq(&a));
+    assert!(sigma.approx_eq(&::cgmath::Vector2::new(45.0f64.sqrt(), 5.0f64.sqrt())));
+}
+
+#[test]
+fn test_decompose_affine() {
+    let rotation = Quaternion::from_axis_angle(Vector3::new(1.0, -2.0, 2.0).normalize(), ::cgmath::deg(40.0f64).into());
+    let scale = Matrix4::from_nonuniform_scale(2.0, 3.0, 0.5);
+    let m = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0)) * Matrix4::from(Matrix3::from(rotation)) * scale;
+    let d = m.decompose().unwrap();
+    assert!(d.rotation.rotation_eq(&rotation));
+    assert!(d.scale.approx_eq(&Vector3::new(2.0, 3.0, 0.5)));
+    assert!(d.is_shear_free());
+    assert_eq!(d.translation, Vector3::new(1.0, 2.0, 3.0));
+    assert!(Matrix4::from(d).approx_eq(&m));
+}
+
+#[test]
+fn test_decompose_shear() {
+    // shear x by y, and y by z
+    let shear = Matrix4::new(1.0f64, 0.0, 0.0, 0.0,
+                             0.5, 1.0, 0.0, 0.0,
+                             0.0, -0.25, 1.0, 0.0,
+                             0.0, 0.0, 0.0, 1.0);
+    let m = Matrix4::from(Matrix3::from_angle_z(::cgmath::rad(0.3f64))) * shear * Matrix4::from_scale(2.0);
+    let d = m.decompose().unwrap();
+    assert!(!d.is_shear_free());
+    assert!(d.shear.approx_eq(&Vector3::new(0.5, 0.0, -0.25)));
+    assert!(d.scale.approx_eq(&Vector3::new(2.0, 2.0, 2.0)));
+    assert!(Matrix4::from(d).approx_eq(&m));
+}
+
+#[test]
+fn test_decompose_reflection_and_failure() {
+    let m = Matrix4::from_nonuniform_scale(1.0f64, -2.0, 3.0);
+    let d = m.decompose().unwrap();
+    assert!(d.scale.approx_eq(&Vector3::new(-1.0, -2.0, -3.0)));
+    assert!(Matrix4::from(d).approx_eq(&m));
+
+    assert!(Matrix4::from_nonuniform_scale(1.0f64, 0.0, 1.0).decompose().is_none());
+    // singularity is judged relative to the size of the matrix
+    let d = Matrix4::from_scale(1e-6f64).decompose().unwrap();
+    assert!((d.scale * 1e6).approx_eq(&Vector3::new(1.0, 1.0, 1.0)));
+    let nearly_singular = Matrix4::new(1e4f64, 0.0, 0.0, 0.0,
+                                       1e4, 1e-3, 0.0, 0.0,
+                                       0.0, 0.0, 1e4, 0.0,
+                                       0.0, 0.0, 0.0, 1.0);
+    assert!(nearly_singular.decompose().is_none());
+    let mut projective = Matrix4::<f64>::identity();
+    projective.z.w = -1.0;
+    assert!(projective.decompose().is_none());
+}
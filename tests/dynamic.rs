@@ -0,0 +1,124 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+extern crate rustc_serialize;
+
+use cgmath::*;
+use rustc_serialize::json;
+
+fn matrix5() -> MatN<f64> {
+    MatN::from_fn(5, 5, |r, c| if r == c { 4.0 } else { (r as f64 - c as f64) * 0.5 + 0.25 })
+}
+
+#[test]
+fn test_vector() {
+    let a = VecN::new(vec![1.0f64, 2.0, 3.0, 4.0, 5.0]);
+    let b = VecN::from_value(5, 2.0);
+    assert_eq!(a.len(), 5);
+    assert_eq!(&a + &b, VecN::new(vec![3.0, 4.0, 5.0, 6.0, 7.0]));
+    assert_eq!(a.clone() - b.clone(), VecN::new(vec![-1.0, 0.0, 1.0, 2.0, 3.0]));
+    assert_eq!(&a * 2.0, VecN::new(vec![2.0, 4.0, 6.0, 8.0, 10.0]));
+    assert_eq!(a.dot(&b), 30.0);
+    assert_eq!(VecN::new(vec![3.0f64, 0.0, 0.0, 4.0, 0.0]).length(), 5.0);
+    assert_approx_eq!(a.normalize().length(), 1.0);
+}
+
+#[test]
+#[should_panic]
+fn test_vector_dimension_mismatch() {
+    let _ = VecN::new(vec![1.0f64, 2.0]) + VecN::new(vec![1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn test_matrix_layout() {
+    let m = MatN::from_column_major(2, 3, vec![1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+    assert_eq!((m.rows(), m.cols()), (2, 3));
+    assert_eq!(m[1], [3.0, 4.0]);
+    assert_eq!(m[2][1], 6.0);
+    assert_eq!(m.row(0), VecN::new(vec![1.0, 3.0, 5.0]));
+    assert_eq!(m.transpose(), MatN::from_fn(3, 2, |r, c| m[r][c]));
+    assert_eq!(MatN::from_cols(&[m.col(0), m.col(1), m.col(2)]), m);
+    assert!(MatN::from_column_major(2, 2, vec![1.0f64]).is_none());
+}
+
+#[test]
+fn test_matrix_product() {
+    // a 2x3 matrix times a 3x2 matrix
+    let a = MatN::from_column_major(2, 3, vec![1.0f64, 4.0, 2.0, 5.0, 3.0, 6.0]).unwrap();
+    let b = MatN::from_column_major(3, 2, vec![7.0f64, 9.0, 11.0, 8.0, 10.0, 12.0]).unwrap();
+    assert_eq!(&a * &b, MatN::from_column_major(2, 2, vec![58.0, 139.0, 64.0, 154.0]).unwrap());
+    assert_eq!(&a * VecN::new(vec![1.0, 0.0, -1.0]), VecN::new(vec![-2.0, -2.0]));
+    assert_eq!(&a * MatN::identity(3), a);
+
+    // agrees with the fixed size matrices
+    let m = Matrix3::new(1.0f64, 2.0, 3.0, 0.0, 1.0, 4.0, 5.0, 6.0, 0.0);
+    let dynamic = MatN::from_column_major(3, 3, m.as_slice().to_vec()).unwrap();
+    assert_eq!((&dynamic * &dynamic).as_slice(), (m * m).as_slice());
+    assert_approx_eq!(dynamic.determinant(), m.determinant());
+    assert_approx_eq!(MatN::from_column_major(3, 3, m.invert().unwrap().as_slice().to_vec()).unwrap(),
+                      dynamic.invert().unwrap());
+}
+
+#[test]
+fn test_matrix_invert_and_solve() {
+    let m = matrix5();
+    let inverse = m.invert().unwrap();
+    assert_approx_eq!(&m * &inverse, MatN::identity(5));
+    assert!(m.is_invertible());
+
+    let b = VecN::new(vec![1.0, -2.0, 0.5, 3.0, 0.0]);
+    let x = m.solve(&b).unwrap();
+    assert_approx_eq!(&m * &x, b);
+
+    let mut singular = matrix5();
+    for r in 0..5 {
+        singular[4][r] = singular[0][r] * 2.0;
+    }
+    assert_eq!(singular.invert(), None);
+    assert_eq!(singular.determinant(), 0.0);
+    assert!(!singular.is_invertible());
+
+    // nearly singular relative to its scale, even though the pivots aren't zero
+    singular[4][0] = singular[4][0] + 1e-13;
+    assert!(singular.determinant() != 0.0);
+    assert_eq!(singular.invert(), None);
+    assert_eq!(singular.solve(&b), None);
+    assert!(!singular.is_invertible());
+
+    // small but well-conditioned
+    let small = matrix5() * 1e-6;
+    assert!(small.determinant().abs() < 1e-20);
+    assert!(small.is_invertible());
+    assert_approx_eq!(&small * &small.invert().unwrap(), MatN::identity(5));
+}
+
+#[test]
+fn test_matrix_decode() {
+    let m: MatN<f64> = json::decode(r#"{"rows":2,"cols":1,"elements":[1.0,2.0]}"#).unwrap();
+    assert_eq!(m, MatN::from_column_major(2, 1, vec![1.0, 2.0]).unwrap());
+    assert!(json::decode::<MatN<f64>>(r#"{"rows":2,"cols":2,"elements":[1.0,2.0]}"#).is_err());
+}
+
+#[test]
+fn test_matrix_determinant() {
+    assert_eq!(MatN::<f64>::identity(6).determinant(), 1.0);
+    assert_eq!(MatN::from_diagonal(&VecN::new(vec![1.0f64, 2.0, 3.0, 4.0, 5.0])).determinant(), 120.0);
+    // swapping two rows flips the sign
+    let mut m = matrix5();
+    let det = m.determinant();
+    m.swap_rows(0, 3);
+    assert_approx_eq!(m.determinant(), -det);
+}
@@ -0,0 +1,77 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::{Euler, EulerOrder};
+use cgmath::{Matrix3, Quaternion};
+use cgmath::{rad, ApproxEq};
+
+static ORDERS: [EulerOrder; 6] = [
+    EulerOrder::XYZ, EulerOrder::XZY, EulerOrder::YXZ,
+    EulerOrder::YZX, EulerOrder::ZXY, EulerOrder::ZYX,
+];
+
+#[test]
+fn test_xyz_matches_from_euler() {
+    let euler = Euler::new(rad(0.3f64), rad(-0.7), rad(1.1), EulerOrder::XYZ);
+    let mat: Matrix3<_> = euler.into();
+    assert!(mat.approx_eq(&Matrix3::from_euler(rad(0.3), rad(-0.7), rad(1.1))));
+}
+
+#[test]
+fn test_matrix_round_trip() {
+    for &order in ORDERS.iter() {
+        let euler = Euler::new(rad(0.3f64), rad(-0.7), rad(1.1), order);
+        let mat: Matrix3<_> = euler.into();
+        assert!(Euler::from_matrix(mat, order).approx_eq(&euler));
+    }
+}
+
+#[test]
+fn test_quaternion_round_trip() {
+    for &order in ORDERS.iter() {
+        let euler = Euler::new(rad(-1.2f64), rad(0.4), rad(1.3), order);
+        let quat: Quaternion<_> = euler.into();
+        let mat: Matrix3<_> = euler.into();
+        assert!(Matrix3::from(quat).approx_eq(&mat));
+        assert!(Euler::from_quaternion(quat, order).approx_eq(&euler));
+    }
+}
+
+#[test]
+fn test_reorder() {
+    let euler = Euler::new(rad(0.5f64), rad(0.2), rad(-0.9), EulerOrder::ZXY);
+    let expected: Matrix3<_> = euler.into();
+    for &order in ORDERS.iter() {
+        let mat: Matrix3<_> = euler.reorder(order).into();
+        assert!(mat.approx_eq(&expected));
+    }
+}
+
+#[test]
+fn test_gimbal_lock() {
+    for &order in ORDERS.iter() {
+        let mut euler = Euler::new(rad(0.3f64), rad(0.3), rad(0.3), order);
+        match order.axes().1 {
+            0 => euler.pitch = rad(1.5707963267948966),
+            1 => euler.yaw = rad(1.5707963267948966),
+            _ => euler.roll = rad(1.5707963267948966),
+        }
+        let expected: Matrix3<_> = euler.into();
+        let mat: Matrix3<_> = Euler::from_matrix(expected, order).into();
+        assert!(mat.approx_eq(&expected));
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::*;
+
+fn frustum(depth: DepthRange) -> Frustum<f64> {
+    // looking down -z with a 90 degree field of view
+    let proj = PerspectiveFov { fovy: deg(90.0f64).into(), aspect: 1.0, near: 1.0, far: 10.0 };
+    Frustum::from_matrix4(proj.to_matrix(depth), depth)
+}
+
+#[test]
+fn test_from_matrix4() {
+    for &depth in &[DepthRange::NegativeOneToOne, DepthRange::ZeroToOne, DepthRange::ReversedZeroToOne] {
+        let f = frustum(depth);
+        assert!(f.near.approx_eq(&Plane::new(Vector3::new(0.0, 0.0, -1.0), 1.0)));
+        assert!(f.far.approx_eq(&Plane::new(Vector3::new(0.0, 0.0, 1.0), -10.0)));
+        let s = 0.5f64.sqrt();
+        assert!(f.left.approx_eq(&Plane::new(Vector3::new(s, 0.0, -s), 0.0)));
+        assert!(f.top.approx_eq(&Plane::new(Vector3::new(0.0, -s, -s), 0.0)));
+    }
+
+    // the far plane of an infinite projection contains everything
+    let f = Frustum::from_matrix4(perspective_infinite(deg(90.0f64), 1.0, 1.0), DepthRange::NegativeOneToOne);
+    assert!(f.contains(Point3::new(0.0, 0.0, -1.0e9)));
+}
+
+#[test]
+fn test_contains() {
+    let f = frustum(DepthRange::NegativeOneToOne);
+    assert!(f.contains(Point3::new(0.0, 0.0, -5.0)));
+    assert!(f.contains(Point3::new(4.0, -4.0, -5.0)));
+    assert!(!f.contains(Point3::new(6.0, 0.0, -5.0)));
+    assert!(!f.contains(Point3::new(0.0, 0.0, -0.5)));
+    assert!(!f.contains(Point3::new(0.0, 0.0, -11.0)));
+}
+
+#[test]
+fn test_relate_sphere() {
+    let f = frustum(DepthRange::ZeroToOne);
+    assert_eq!(f.relate_sphere(&Sphere::new(Point3::new(0.0, 0.0, -5.0), 1.0)), Relation::Inside);
+    assert_eq!(f.relate_sphere(&Sphere::new(Point3::new(0.0, 0.0, -10.0), 1.0)), Relation::Intersecting);
+    assert_eq!(f.relate_sphere(&Sphere::new(Point3::new(5.0, 0.0, -5.0), 1.0)), Relation::Intersecting);
+    assert_eq!(f.relate_sphere(&Sphere::new(Point3::new(0.0, 0.0, 2.0), 1.0)), Relation::Outside);
+    assert_eq!(f.relate_sphere(&Sphere::new(Point3::new(8.0, 0.0, -5.0), 1.0)), Relation::Outside);
+}
+
+#[test]
+fn test_relate_aabb() {
+    let f = frustum(DepthRange::NegativeOneToOne);
+    let aabb = |min: (f64, f64, f64), max: (f64, f64, f64)| Aabb3::new(Point3::from(min), Point3::from(max));
+    assert_eq!(f.relate_aabb(&aabb((-1.0, -1.0, -6.0), (1.0, 1.0, -4.0))), Relation::Inside);
+    assert_eq!(f.relate_aabb(&aabb((-1.0, -1.0, -12.0), (1.0, 1.0, -8.0))), Relation::Intersecting);
+    assert_eq!(f.relate_aabb(&aabb((-20.0, -20.0, -20.0), (20.0, 20.0, 20.0))), Relation::Intersecting);
+    assert_eq!(f.relate_aabb(&aabb((-1.0, -1.0, 1.0), (1.0, 1.0, 3.0))), Relation::Outside);
+    assert_eq!(f.relate_aabb(&aabb((7.0, -1.0, -6.0), (9.0, 1.0, -4.0))), Relation::Outside);
+}
@@ -0,0 +1,64 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::*;
+use std::f64;
+
+fn ray_towards(target: Point3<f64>) -> Ray3<f64> {
+    let eye = Point3::new(0.0, 0.0, 10.0);
+    Ray::new(eye, target - eye)
+}
+
+#[test]
+fn test_axis_translation() {
+    let origin = Point3::new(1.0f64, 0.0, 0.0);
+    let from = ray_towards(Point3::new(1.0, 0.0, 0.0));
+    let to = ray_towards(Point3::new(3.0, 0.0, 0.0));
+    let delta = axis_translation(from, to, origin, Vector3::unit_x()).unwrap();
+    assert!(delta.approx_eq(&Vector3::new(2.0, 0.0, 0.0)));
+
+    // Looking straight down the axis gives no useful constraint
+    let ray = Ray::new(Point3::new(1.0, 0.0, 10.0), -Vector3::unit_z());
+    assert!(project_ray_onto_axis(ray, origin, Vector3::unit_z()).is_none());
+}
+
+#[test]
+fn test_plane_translation() {
+    let origin = Point3::new(0.0f64, 0.0, 0.0);
+    let from = ray_towards(Point3::new(0.0, 0.0, 0.0));
+    let to = ray_towards(Point3::new(1.0, -2.0, 0.0));
+    let delta = plane_translation(from, to, origin, Vector3::unit_z()).unwrap();
+    assert!(delta.approx_eq(&Vector3::new(1.0, -2.0, 0.0)));
+
+    // The plane is behind the ray
+    let ray = Ray::new(Point3::new(0.0, 0.0, 10.0), Vector3::unit_z());
+    assert!(project_ray_onto_plane(ray, origin, Vector3::unit_z()).is_none());
+}
+
+#[test]
+fn test_axis_rotation() {
+    let origin = Point3::new(0.0f64, 0.0, 0.0);
+    let from = ray_towards(Point3::new(1.0, 0.0, 0.0));
+    let to = ray_towards(Point3::new(0.0, 2.0, 0.0));
+    let angle = axis_rotation(from, to, origin, Vector3::unit_z()).unwrap();
+    assert!(angle.approx_eq(&rad(f64::consts::FRAC_PI_2)));
+
+    let angle = axis_rotation(to, from, origin, Vector3::unit_z()).unwrap();
+    assert!(angle.approx_eq(&-rad(f64::consts::FRAC_PI_2)));
+
+    assert!(axis_rotation(ray_towards(origin), to, origin, Vector3::unit_z()).is_none());
+}
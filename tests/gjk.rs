@@ -0,0 +1,107 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::{Aabb3, Capsule3, Cylinder3, Obb3, Sphere};
+use cgmath::{Matrix3, Point3, Vector3};
+use cgmath::{SupportMap, gjk_intersects, rad};
+
+#[test]
+fn test_support() {
+    let sphere = Sphere::new(Point3::new(1.0f64, 0.0, 0.0), 2.0);
+    assert_eq!(sphere.support(&Vector3::new(0.0, 5.0, 0.0)), Point3::new(1.0, 2.0, 0.0));
+
+    let aabb = Aabb3::new(Point3::new(1.0f64, 2.0, 3.0), Point3::new(-1.0, -2.0, -3.0));
+    assert_eq!(aabb.support(&Vector3::new(1.0, -1.0, 1.0)), Point3::new(1.0, -2.0, 3.0));
+
+    let capsule = Capsule3::new(Point3::new(0.0f64, 0.0, 0.0), Point3::new(0.0, 4.0, 0.0), 1.0);
+    assert_eq!(capsule.support(&Vector3::new(0.0, 1.0, 0.0)), Point3::new(0.0, 5.0, 0.0));
+    assert_eq!(capsule.support(&Vector3::new(-3.0, 0.0, 0.0)), Point3::new(-1.0, 0.0, 0.0));
+
+    let points = [Point3::new(0.0f64, 0.0, 0.0), Point3::new(1.0, 1.0, 0.0), Point3::new(2.0, -1.0, 0.0)];
+    assert_eq!(points[..].support(&Vector3::new(0.0, 1.0, 0.0)), points[1]);
+}
+
+#[test]
+fn test_spheres() {
+    let a = Sphere::new(Point3::new(0.0f64, 0.0, 0.0), 1.0);
+    assert!(gjk_intersects(&a, &Sphere::new(Point3::new(1.5, 0.5, 0.0), 1.0)));
+    assert!(!gjk_intersects(&a, &Sphere::new(Point3::new(1.5, 1.5, 0.5), 1.0)));
+    assert!(gjk_intersects(&a, &a));
+}
+
+#[test]
+fn test_boxes() {
+    let aabb = Aabb3::new(Point3::new(-1.0f64, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0));
+    // A box rotated by 45 degrees, whose corner reaches further than its faces
+    let axes = Matrix3::from_angle_z(rad(::std::f64::consts::FRAC_PI_4));
+    let obb = Obb3::new(Point3::new(2.3, 0.0, 0.0), axes, Vector3::new(1.0, 1.0, 1.0));
+    assert!(gjk_intersects(&aabb, &obb));
+    let obb = Obb3::new(Point3::new(2.5, 0.0, 0.0), axes, Vector3::new(1.0, 1.0, 1.0));
+    assert!(!gjk_intersects(&aabb, &obb));
+}
+
+#[test]
+fn test_mixed() {
+    let capsule = Capsule3::new(Point3::new(0.0f64, -2.0, 0.0), Point3::new(0.0, 2.0, 0.0), 0.5);
+    let aabb = Aabb3::new(Point3::new(0.4f64, 1.0, -1.0), Point3::new(2.0, 3.0, 1.0));
+    assert!(gjk_intersects(&capsule, &aabb));
+    let aabb = Aabb3::new(Point3::new(0.6f64, 1.0, -1.0), Point3::new(2.0, 3.0, 1.0));
+    assert!(!gjk_intersects(&capsule, &aabb));
+
+    let tetrahedron = [Point3::new(0.0f64, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0),
+                       Point3::new(0.0, 1.0, 0.0), Point3::new(0.0, 0.0, 1.0)];
+    let sphere = Sphere::new(Point3::new(1.0, 1.0, 1.0), 1.2);
+    assert!(gjk_intersects(&tetrahedron[..], &sphere));
+    let sphere = Sphere::new(Point3::new(1.0, 1.0, 1.0), 0.5);
+    assert!(!gjk_intersects(&tetrahedron[..], &sphere));
+    assert!(gjk_intersects(&tetrahedron[..], &Point3::new(0.2, 0.2, 0.2)));
+}
+
+#[test]
+fn test_close() {
+    // separated by far more than rounding errors, but too little for the
+    // search to converge
+    let a = Sphere::new(Point3::new(0.0f64, 0.0, 0.0), 1.0);
+    for i in 0..20 {
+        let (t, u) = (i as f64 * 0.1, i as f64 * 0.07);
+        let center = Point3::new(2.0 * t.cos() * u.sin(), 2.0 * t.sin() * u.sin(), 2.0 * u.cos());
+        assert!(!gjk_intersects(&a, &Sphere::new(center, 1.0 - 1e-9)));
+    }
+    let aabb = Aabb3::new(Point3::new(1.000001f64, -1.0, -1.0), Point3::new(2.0, 1.0, 1.0));
+    assert!(!gjk_intersects(&a, &aabb));
+}
+
+#[test]
+fn test_degenerate() {
+    // flat and collinear shapes, whose support points repeat
+    let square = [Point3::new(-1.0f64, -1.0, 0.0), Point3::new(1.0, -1.0, 0.0),
+                  Point3::new(1.0, 1.0, 0.0), Point3::new(-1.0, 1.0, 0.0)];
+    assert!(gjk_intersects(&square[..], &Point3::new(0.5, 0.25, 0.0)));
+    assert!(!gjk_intersects(&square[..], &Point3::new(0.5, 0.25, 1e-6)));
+    assert!(!gjk_intersects(&square[..], &Point3::new(1.5, 0.25, 0.0)));
+
+    let segment = [Point3::new(-1.0f64, 2.0, 0.0), Point3::new(1.0, 2.0, 0.0)];
+    assert!(gjk_intersects(&segment[..], &Point3::new(0.5, 2.0, 0.0)));
+    assert!(!gjk_intersects(&segment[..], &Point3::new(0.5, 2.0, 1e-6)));
+    assert!(!gjk_intersects(&segment[..], &square[..]));
+
+    let needle = Cylinder3::new(Point3::new(0.5f64, 0.5, 1e-6), Point3::new(0.5, 0.5, 1.0), 0.0);
+    assert!(!gjk_intersects(&needle, &square[..]));
+    assert!(!gjk_intersects(&Sphere::new(Point3::new(0.5, 0.5, 1e-6), 0.0), &square[..]));
+    let needle = Cylinder3::new(Point3::new(0.5f64, 0.5, -1.0), Point3::new(0.5, 0.5, 1.0), 0.0);
+    assert!(gjk_intersects(&needle, &square[..]));
+}
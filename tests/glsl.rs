@@ -0,0 +1,46 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::*;
+use cgmath::glsl::{dot, cross, length, distance, normalize, mix, transpose, determinant, inverse};
+
+#[test]
+fn test_vector_functions() {
+    let a = Vector3::new(1.0f64, 2.0, 2.0);
+    let b = Vector3::new(0.0f64, 1.0, 0.0);
+    assert_eq!(dot(a, b), 2.0);
+    assert_eq!(cross(a, b), a.cross(b));
+    assert_eq!(length(a), 3.0);
+    assert_eq!(distance(a, b), a.distance(b));
+    assert!(normalize(a).approx_eq(&(a / 3.0)));
+    assert_eq!(mix(a, b, 0.5), Vector3::new(0.5, 1.5, 1.0));
+    assert_eq!(mix(1.0f32, 3.0, 0.25), 1.5);
+}
+
+#[test]
+fn test_matrix_functions() {
+    let m = Matrix2::new(2.0f64, 0.0, 1.0, 4.0);
+    assert_eq!(transpose(m), m.transpose());
+    assert_eq!(determinant(m), 8.0);
+    assert!((inverse(m) * m).approx_eq(&Matrix2::identity()));
+}
+
+#[test]
+#[should_panic]
+fn test_inverse_singular() {
+    inverse(Matrix2::new(1.0f64, 2.0, 2.0, 4.0));
+}
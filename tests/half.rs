@@ -0,0 +1,73 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::*;
+
+use std::f32;
+
+#[test]
+fn test_f32_to_f16() {
+    assert_eq!(f32_to_f16(0.0), 0x0000);
+    assert_eq!(f32_to_f16(-0.0), 0x8000);
+    assert_eq!(f32_to_f16(1.0), 0x3c00);
+    assert_eq!(f32_to_f16(-2.0), 0xc000);
+    assert_eq!(f32_to_f16(65504.0), 0x7bff);
+    assert_eq!(f32_to_f16(1e6), 0x7c00);
+    assert_eq!(f32_to_f16(f32::NEG_INFINITY), 0xfc00);
+    assert!(f32_to_f16(f32::NAN) & 0x7fff > 0x7c00);
+
+    // the smallest normal and subnormal
+    assert_eq!(f32_to_f16(6.103515625e-5), 0x0400);
+    assert_eq!(f32_to_f16(5.960464477539063e-8), 0x0001);
+    assert_eq!(f32_to_f16(2.0e-8), 0x0000);
+
+    // ties round to even
+    assert_eq!(f32_to_f16(1.0 + 1.0 / 2048.0), 0x3c00);
+    assert_eq!(f32_to_f16(1.0 + 3.0 / 2048.0), 0x3c02);
+    assert_eq!(f32_to_f16(1.0 + 1.0 / 2048.0 + 1.0 / 65536.0), 0x3c01);
+    // rounding up can carry into the exponent
+    assert_eq!(f32_to_f16(65519.0), 0x7bff);
+    assert_eq!(f32_to_f16(65520.0), 0x7c00);
+}
+
+#[test]
+fn test_f16_to_f32() {
+    assert_eq!(f16_to_f32(0x3c00), 1.0);
+    assert_eq!(f16_to_f32(0xc000), -2.0);
+    assert_eq!(f16_to_f32(0x0001), 5.960464477539063e-8);
+    assert_eq!(f16_to_f32(0x03ff), 6.097555160522461e-5);
+    assert_eq!(f16_to_f32(0x7c00), f32::INFINITY);
+    assert!(f16_to_f32(0x7e00).is_nan());
+    assert!(f16_to_f32(0x8000).is_sign_negative());
+
+    // every finite half survives a round trip
+    for h in (0..0x7c00).chain(0x8000..0xfc00) {
+        assert_eq!(f32_to_f16(f16_to_f32(h)), h);
+    }
+}
+
+#[test]
+fn test_f16_arrays() {
+    let v = Vector3::new(1.0f32, -0.5, 1.0 / 3.0);
+    assert_eq!(v.to_f16_array(), [0x3c00, 0xb800, 0x3555]);
+    assert!(Vector3::from_f16_array(v.to_f16_array()).approx_eq_eps(&v, &1e-3));
+
+    let m = Matrix2::new(1.0f32, 2.0, 3.0, 4.0);
+    assert_eq!(m.to_f16_array(), [0x3c00, 0x4000, 0x4200, 0x4400]);
+    assert_eq!(Matrix2::from_f16_array(m.to_f16_array()), m);
+    assert_eq!(Matrix4::from_f16_array(Matrix4::<f32>::identity().to_f16_array()), Matrix4::identity());
+}
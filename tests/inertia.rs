@@ -0,0 +1,83 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::*;
+use std::f64;
+
+#[test]
+fn test_sphere_box() {
+    let sphere = Sphere::new(Point3::new(1.0f64, 2.0, 3.0), 2.0);
+    assert_approx_eq!(sphere.inertia(5.0), Matrix3::from_value(8.0));
+
+    let cube = Aabb3::new(Point3::new(0.0f64, 0.0, 0.0), Point3::new(2.0, 2.0, 2.0));
+    assert_approx_eq!(cube.inertia(3.0), Matrix3::from_value(2.0));
+    let brick = Aabb3::new(Point3::new(0.0f64, 0.0, 0.0), Point3::new(2.0, 4.0, 6.0));
+    assert_approx_eq!(brick.inertia(12.0), Matrix3::from_diagonal(Vector3::new(52.0, 40.0, 20.0)));
+
+    let axes = Matrix3::from_axis_angle(Vector3::new(1.0f64, 2.0, 3.0).normalize(), rad(0.8));
+    let obb = Obb3::new(brick.center(), axes, Vector3::new(1.0, 2.0, 3.0));
+    assert_approx_eq!(obb.inertia(12.0), axes * brick.inertia(12.0) * axes.transpose());
+}
+
+#[test]
+fn test_cylinder_capsule() {
+    let cylinder = Cylinder3::new(Point3::new(0.0f64, 0.0, -1.0), Point3::new(0.0, 0.0, 1.0), 1.0);
+    assert_approx_eq!(cylinder.inertia(12.0), Matrix3::from_diagonal(Vector3::new(7.0, 7.0, 6.0)));
+
+    // the same cylinder along another axis
+    let axis = Vector3::new(1.0f64, -1.0, 0.5).normalize();
+    let rotation = Matrix3::from(Quaternion::between_vectors(Vector3::unit_z(), axis));
+    let tilted = Cylinder3::new(Point3::from_vec(-axis), Point3::from_vec(axis), 1.0);
+    assert_approx_eq!(tilted.inertia(12.0), rotate_inertia(cylinder.inertia(12.0), &rotation));
+
+    // a capsule without a cylinder is a sphere
+    let capsule = Capsule3::new(Point3::new(1.0f64, 1.0, 1.0), Point3::new(1.0, 1.0, 1.0), 2.0);
+    assert_approx_eq!(capsule.inertia(5.0), Sphere::new(capsule.start, 2.0).inertia(5.0));
+
+    // and a thin capsule is a rod
+    let rod = Capsule3::new(Point3::new(0.0f64, -3.0, 0.0), Point3::new(0.0, 3.0, 0.0), 1e-6);
+    assert_approx_eq!(rod.inertia(2.0), Matrix3::from_diagonal(Vector3::new(6.0, 0.0, 6.0)));
+
+    // the capsule is bigger than its cylinder, and smaller than its bounding
+    // cylinder, with the same density
+    let capsule = Capsule3::new(Point3::new(0.0f64, 0.0, -1.0), Point3::new(0.0, 0.0, 1.0), 1.0);
+    let density = 12.0 / (f64::consts::PI * 2.0);
+    let capsule_mass = density * f64::consts::PI * (2.0 + 4.0 / 3.0);
+    let outer = Cylinder3::new(Point3::new(0.0f64, 0.0, -2.0), Point3::new(0.0, 0.0, 2.0), 1.0);
+    let inertia = capsule.inertia(capsule_mass);
+    assert!(inertia.x.x > cylinder.inertia(12.0).x.x && inertia.x.x < outer.inertia(24.0).x.x);
+    assert!(inertia.z.z > cylinder.inertia(12.0).z.z && inertia.z.z < outer.inertia(24.0).z.z);
+}
+
+#[test]
+fn test_parallel_axis() {
+    let offset = Vector3::new(1.0f64, 2.0, 3.0);
+    let point = parallel_axis(Matrix3::zero(), 2.0, offset);
+    assert_approx_eq!(point, Matrix3::new(26.0, -4.0, -6.0,
+                                          -4.0, 20.0, -12.0,
+                                          -6.0, -12.0, 10.0));
+    // a point mass at the offset resists rotation about the offset axis not at all
+    assert_approx_eq!(point * offset, Vector3::zero());
+
+    let sphere = Sphere::new(Point3::new(0.0f64, 0.0, 0.0), 1.0).inertia(2.0);
+    assert_approx_eq!(parallel_axis(sphere, 2.0, offset), sphere + point);
+
+    let rotation = Matrix3::from_angle_x(rad(0.3f64));
+    let rotated = rotate_inertia(point, &rotation);
+    assert!(rotated.trace().approx_eq(&point.trace()));
+    assert_approx_eq!(rotated, parallel_axis(Matrix3::zero(), 2.0, rotation * offset));
+}
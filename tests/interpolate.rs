@@ -0,0 +1,123 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::*;
+
+fn midpoint<T: Interpolate<f64>>(a: T, b: T) -> T {
+    a.lerp(&b, 0.5)
+}
+
+#[test]
+fn test_linear() {
+    assert_eq!(midpoint(1.0, 3.0), 2.0);
+    assert_eq!(midpoint(Vector2::new(0.0, 2.0), Vector2::new(2.0, 4.0)), Vector2::new(1.0, 3.0));
+    assert_eq!(midpoint(Point3::new(0.0, 2.0, 4.0), Point3::new(2.0, 4.0, 8.0)), Point3::new(1.0, 3.0, 6.0));
+    assert_eq!(midpoint(Matrix2::new(1.0, 2.0, 3.0, 4.0), Matrix2::new(3.0, 4.0, 5.0, 6.0)),
+               Matrix2::new(2.0, 3.0, 4.0, 5.0));
+    assert_eq!(midpoint(Rgba::new(0.0, 0.0, 1.0, 1.0), Rgba::new(1.0, 0.0, 0.0, 1.0)),
+               Rgba::new(0.5, 0.0, 0.5, 1.0));
+    // slerp falls back to lerp
+    assert_eq!(Vector3::new(0.0, 0.0, 0.0).slerp(&Vector3::new(4.0, 2.0, 0.0), 0.25f64),
+               Vector3::new(1.0, 0.5, 0.0));
+}
+
+#[test]
+fn test_by_value_methods() {
+    // the by-value methods are still called with `Interpolate` in scope
+    let a = Vector3::new(0.0f64, 0.0, 0.0);
+    let b = Vector3::new(4.0, 2.0, 0.0);
+    assert_eq!(a.lerp(b, 0.5), Vector3::new(2.0, 1.0, 0.0));
+    let q = Quaternion::<f64>::one();
+    let r = Quaternion::from_axis_angle(Vector3::unit_z(), deg(90.0).into());
+    assert_eq!(q.slerp(r, 0.5), Interpolate::slerp(&q, &r, 0.5));
+}
+
+#[test]
+fn test_quaternion() {
+    let a = Quaternion::<f64>::one();
+    let b = Quaternion::from_axis_angle(Vector3::unit_z(), deg(90.0).into());
+    let expected = Quaternion::from_axis_angle(Vector3::unit_z(), deg(45.0).into());
+    assert_approx_eq!(Interpolate::slerp(&a, &b, 0.5), expected);
+    assert_approx_eq!(midpoint(a, b), expected);
+    // nlerp doesn't keep a constant angular velocity
+    let quarter = Quaternion::from_axis_angle(Vector3::unit_z(), deg(22.5).into());
+    assert_approx_eq!(Interpolate::slerp(&a, &b, 0.25), quarter);
+    assert!(!a.lerp(&b, 0.25).approx_eq(&quarter));
+}
+
+#[test]
+fn test_transforms() {
+    let a = Decomposed {
+        scale: 1.0,
+        rot: Quaternion::<f64>::one(),
+        disp: Vector3::new(0.0, 0.0, 0.0),
+    };
+    let b = Decomposed {
+        scale: 3.0,
+        rot: -Quaternion::from_axis_angle(Vector3::unit_x(), deg(90.0).into()),
+        disp: Vector3::new(2.0, 4.0, 6.0),
+    };
+    let mid = a.slerp(&b, 0.5);
+    assert_eq!(mid.scale, 2.0);
+    assert_eq!(mid.disp, Vector3::new(1.0, 2.0, 3.0));
+    // the rotation takes the shortest path
+    assert!(mid.rot.rotation_eq(&Quaternion::from_axis_angle(Vector3::unit_x(), deg(45.0).into())));
+    assert!(a.lerp(&b, 0.5).rot.rotation_eq(&mid.rot));
+
+    let p = Isometry3::new(Quaternion::one(), Vector3::new(0.0, 0.0, 0.0));
+    let q = Isometry3::new(Quaternion::from_axis_angle(Vector3::unit_y(), deg(60.0).into()),
+                           Vector3::new(0.0, 0.0, 2.0f64));
+    let mid = p.slerp(&q, 0.5);
+    assert_approx_eq!(mid.rot, Quaternion::from_axis_angle(Vector3::unit_y(), deg(30.0).into()));
+    assert_eq!(mid.disp, Vector3::new(0.0, 0.0, 1.0));
+}
+
+#[test]
+fn test_hermite() {
+    let (p0, m0) = (Vector2::new(0.0f64, 0.0), Vector2::new(1.0, 0.0));
+    let (p1, m1) = (Vector2::new(1.0, 1.0), Vector2::new(0.0, 1.0));
+    assert_eq!(hermite(p0, m0, p1, m1, 0.0), p0);
+    assert_eq!(hermite(p0, m0, p1, m1, 1.0), p1);
+    assert_approx_eq!(hermite(p0, m0, p1, m1, 0.5), Vector2::new(0.625, 0.375));
+    // with matching tangents, a straight line
+    let m = Vector3::new(2.0f64, 4.0, 6.0);
+    for i in 0..5 {
+        let t = i as f64 / 4.0;
+        assert_approx_eq!(hermite(Vector3::new(0.0, 0.0, 0.0), m, m, m, t), m * t);
+    }
+    assert_eq!(hermite(1.0, 0.0, 3.0, 0.0, 0.5), 2.0);
+}
+
+#[test]
+fn test_easing() {
+    for &f in [ease_in_cubic::<f64>, ease_out_cubic, ease_in_out_cubic].iter() {
+        assert_eq!(f(0.0), 0.0);
+        assert_eq!(f(1.0), 1.0);
+    }
+    assert_eq!(ease_in_cubic(0.5f64), 0.125);
+    assert_eq!(ease_out_cubic(0.5f64), 0.875);
+    assert_eq!(ease_in_out_cubic(0.25f64), 0.0625);
+    assert_eq!(ease_in_out_cubic(0.5f64), 0.5);
+    assert_eq!(ease_in_out_cubic(0.75f64), 0.9375);
+
+    assert_eq!(smoothstep(1.0f64, 3.0, 0.0), 0.0);
+    assert_eq!(smoothstep(1.0f64, 3.0, 2.0), 0.5);
+    assert_eq!(smoothstep(1.0f64, 3.0, 4.0), 1.0);
+    assert_eq!(smootherstep(1.0f64, 3.0, 2.0), 0.5);
+    assert_eq!(smootherstep(0.0f64, 1.0, 0.25), 0.103515625);
+    assert_eq!(smootherstep(0.0f64, 1.0, -1.0), 0.0);
+}
@@ -0,0 +1,80 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use std::f64;
+
+use cgmath::{Isometry3, Matrix4, Point3, Quaternion, Similarity3, Vector3};
+use cgmath::{interpolate_pose, rad, ApproxEq, Rotation3, Transform};
+
+#[test]
+fn test_interpolate_pose() {
+    let prev = Isometry3::new(Quaternion::from_angle_z(rad(0.2f64)), Vector3::new(0.0, 1.0, 0.0));
+    let curr = Isometry3::new(Quaternion::from_angle_z(rad(0.6f64)), Vector3::new(2.0, 1.0, -4.0));
+
+    let pose = interpolate_pose(&prev, &curr, 0.25);
+    assert!(pose.rot.approx_eq(&Quaternion::from_angle_z(rad(0.3))));
+    assert!(pose.disp.approx_eq(&Vector3::new(0.5, 1.0, -1.0)));
+
+    assert!(interpolate_pose(&prev, &curr, 0.0).approx_eq(&prev));
+    assert!(interpolate_pose(&prev, &curr, 1.0).approx_eq(&curr));
+}
+
+#[test]
+fn test_interpolate_pose_shortest_path() {
+    // The same rotation as `curr`, but in the opposite hemisphere
+    let prev = Isometry3::new(Quaternion::from_angle_x(rad(0.0f64)), Vector3::new(0.0, 0.0, 0.0));
+    let curr = Isometry3::new(-Quaternion::from_angle_x(rad(1.0f64)), Vector3::new(0.0, 0.0, 0.0));
+    let pose = interpolate_pose(&prev, &curr, 0.5);
+    assert!(pose.rot.approx_eq(&Quaternion::from_angle_x(rad(0.5))));
+}
+
+#[test]
+fn test_isometry_transform() {
+    let a = Isometry3::new(Quaternion::from_angle_z(rad(f64::consts::FRAC_PI_2)), Vector3::new(1.0, 2.0, 3.0));
+    let b = Isometry3::new(Quaternion::from_angle_x(rad(0.4f64)), Vector3::new(-2.0, 0.5, 1.0));
+    let p = Point3::new(1.0f64, 0.0, 0.0);
+
+    assert!(a.transform_point(p).approx_eq(&Point3::new(1.0, 3.0, 3.0)));
+    assert!(a.transform_vector(Vector3::new(1.0, 0.0, 0.0)).approx_eq(&Vector3::new(0.0, 1.0, 0.0)));
+    assert!(a.concat(&b).transform_point(p).approx_eq(&a.transform_point(b.transform_point(p))));
+
+    let ai = a.invert().unwrap();
+    assert!(ai.transform_point(a.transform_point(p)).approx_eq(&p));
+    assert!(a.concat(&ai).approx_eq(&Isometry3::one()));
+
+    let m = Matrix4::from(a);
+    assert!(Point3::from_homogeneous(m * p.to_homogeneous()).approx_eq(&a.transform_point(p)));
+}
+
+#[test]
+fn test_similarity_transform() {
+    let a = Similarity3::from_parts(2.0f64, Quaternion::from_angle_z(rad(f64::consts::FRAC_PI_2)), Vector3::new(1.0, 2.0, 3.0));
+    let b = Similarity3::from_parts(0.5f64, Quaternion::from_angle_x(rad(0.4f64)), Vector3::new(-2.0, 0.5, 1.0));
+    let p = Point3::new(1.0f64, 0.0, 0.0);
+
+    // scaled to (2, 0, 0), rotated to (0, 2, 0), then translated
+    assert!(a.transform_point(p).approx_eq(&Point3::new(1.0, 4.0, 3.0)));
+    assert!(a.concat(&b).transform_point(p).approx_eq(&a.transform_point(b.transform_point(p))));
+
+    let ai = a.invert().unwrap();
+    assert!(ai.transform_point(a.transform_point(p)).approx_eq(&p));
+    assert!(a.concat(&ai).approx_eq(&Similarity3::one()));
+    assert!(Similarity3::from_parts(0.0, Quaternion::one(), Vector3::zero()).invert().is_none());
+
+    let m = Matrix4::from(a);
+    assert!(Point3::from_homogeneous(m * p.to_homogeneous()).approx_eq(&a.transform_point(p)));
+}
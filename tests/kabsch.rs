@@ -0,0 +1,64 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::*;
+
+fn points() -> Vec<Point3<f64>> {
+    vec![Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 2.0, 0.0),
+         Point3::new(0.0, 0.0, 3.0), Point3::new(1.0, 1.0, -1.0),
+         Point3::new(-2.0, 0.5, 0.25)]
+}
+
+#[test]
+fn test_best_fit_rotation() {
+    let rot = Matrix3::from_axis_angle(Vector3::new(1.0, 2.0, -1.0).normalize(), deg(130.0f64).into());
+    let from: Vec<Vector3<f64>> = points().iter().map(|p| p.to_vec()).collect();
+    let to: Vec<Vector3<f64>> = from.iter().map(|&v| rot * v).collect();
+    assert_approx_eq!(best_fit_rotation(&from, &to).unwrap(), rot);
+
+    assert_eq!(best_fit_rotation(&from, &to[1..]), None);
+    assert_eq!(best_fit_rotation::<f64>(&[], &[]), None);
+}
+
+#[test]
+fn test_no_reflection() {
+    // the best fit for a mirror image is still a rotation
+    let from: Vec<Vector3<f64>> = points().iter().map(|p| p.to_vec()).collect();
+    let to: Vec<Vector3<f64>> = from.iter().map(|v| Vector3::new(v.x, v.y, -v.z)).collect();
+    let rot = best_fit_rotation(&from, &to).unwrap();
+    assert_approx_eq!(rot.determinant(), 1.0);
+    assert_approx_eq!(rot * rot.transpose(), Matrix3::identity());
+}
+
+#[test]
+fn test_best_fit_isometry() {
+    let iso = Isometry3::new(Quaternion::from_axis_angle(Vector3::new(0.0, 0.6, 0.8), deg(-75.0f64).into()),
+                             Vector3::new(3.0, -1.0, 2.0));
+    let from = points();
+    let to: Vec<Point3<f64>> = from.iter().map(|p| iso.transform_point(*p)).collect();
+    let fit = best_fit_isometry(&from, &to).unwrap();
+    assert!(fit.rot.rotation_eq(&iso.rot));
+    assert_approx_eq!(fit.disp, iso.disp);
+
+    // noise is averaged out
+    let noisy: Vec<Point3<f64>> = to.iter().enumerate()
+        .map(|(i, &p)| p + Vector3::new(0.01, -0.01, 0.01) * if i % 2 == 0 { 1.0 } else { -1.0 })
+        .collect();
+    let fit = best_fit_isometry(&from, &noisy).unwrap();
+    assert!(fit.rot.rotation_eq_eps(&iso.rot, &0.01));
+    assert!(fit.disp.approx_eq_eps(&iso.disp, &0.01));
+}
@@ -0,0 +1,71 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_line_closest_point() {
+    let line = Line3::from_points(Point3::new(0.0f64, 0.0, 1.0), Point3::new(2.0, 0.0, 1.0));
+    assert!(line.closest_point(Point3::new(-3.0, 4.0, 1.0)).approx_eq(&Point3::new(-3.0, 0.0, 1.0)));
+    assert!(line.distance(Point3::new(-3.0, 4.0, 1.0)).approx_eq(&4.0));
+    assert!(line.distance2(Point3::new(5.0, 1.0, 2.0)).approx_eq(&2.0));
+
+    let line = Line2::new(Point2::new(0.0f64, 0.0), Vector2::new(1.0, 1.0));
+    assert!(line.closest_point(Point2::new(2.0, 0.0)).approx_eq(&Point2::new(1.0, 1.0)));
+}
+
+#[test]
+fn test_segment_closest_point() {
+    let segment = Segment3::new(Point3::new(0.0f64, 0.0, 0.0), Point3::new(2.0, 0.0, 0.0));
+    assert!(segment.length().approx_eq(&2.0));
+    assert!(segment.closest_point(Point3::new(1.0, 3.0, 0.0)).approx_eq(&Point3::new(1.0, 0.0, 0.0)));
+    assert!(segment.closest_point(Point3::new(-1.0, 1.0, 0.0)).approx_eq(&segment.start));
+    assert!(segment.closest_point(Point3::new(5.0, 1.0, 0.0)).approx_eq(&segment.end));
+    assert!(segment.distance(Point3::new(5.0, 4.0, 0.0)).approx_eq(&5.0));
+
+    let point = Segment2::new(Point2::new(1.0f64, 1.0), Point2::new(1.0, 1.0));
+    assert!(point.distance(Point2::new(4.0, 5.0)).approx_eq(&5.0));
+}
+
+#[test]
+fn test_segment_closest_points() {
+    // skew segments crossing above one another
+    let a = Segment3::new(Point3::new(-1.0f64, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0));
+    let b = Segment3::new(Point3::new(0.5f64, -1.0, 2.0), Point3::new(0.5, 1.0, 2.0));
+    let (p, q) = a.closest_points(&b);
+    assert!(p.approx_eq(&Point3::new(0.5, 0.0, 0.0)));
+    assert!(q.approx_eq(&Point3::new(0.5, 0.0, 2.0)));
+    assert!(a.segment_distance(&b).approx_eq(&2.0));
+
+    // the closest points of the lines lie beyond the end of the segments
+    let c = Segment3::new(Point3::new(3.0f64, 1.0, 0.0), Point3::new(3.0, 2.0, 0.0));
+    let (p, q) = a.closest_points(&c);
+    assert!(p.approx_eq(&a.end));
+    assert!(q.approx_eq(&c.start));
+    assert!(a.segment_distance2(&c).approx_eq(&5.0));
+
+    // parallel segments
+    let d = Segment3::new(Point3::new(0.0f64, 1.0, 0.0), Point3::new(4.0, 1.0, 0.0));
+    assert!(a.segment_distance(&d).approx_eq(&1.0));
+
+    // intersecting 2D segments
+    let e = Segment2::new(Point2::new(0.0f64, 0.0), Point2::new(2.0, 2.0));
+    let f = Segment2::new(Point2::new(0.0f64, 2.0), Point2::new(2.0, 0.0));
+    let (p, q) = e.closest_points(&f);
+    assert!(p.approx_eq(&Point2::new(1.0, 1.0)));
+    assert!(q.approx_eq(&Point2::new(1.0, 1.0)));
+}
@@ -247,40 +247,79 @@ fn test_invert() {
     assert_eq!(mut_c, matrix3::C.invert().unwrap());
 
     // Matrix4
-    assert!(Matrix4::<f64>::identity().invert().unwrap().is_identity());
+    assert!(SquareMatrix::invert(&Matrix4::<f64>::identity()).unwrap().is_identity());
 
-    assert!(matrix4::C.invert().unwrap().approx_eq(&(
+    assert!(SquareMatrix::invert(&matrix4::C).unwrap().approx_eq(&(
             Matrix4::new( 5.0f64, -4.0f64,  1.0f64,  0.0f64,
                          -4.0f64,  8.0f64, -4.0f64,  0.0f64,
                           4.0f64, -8.0f64,  4.0f64,  8.0f64,
                          -3.0f64,  4.0f64,  1.0f64, -8.0f64) * 0.125f64)));
     let mut mut_c = matrix4::C;
-    mut_c.invert_self();
-    assert_eq!(mut_c, matrix4::C.invert().unwrap());
+    SquareMatrix::invert_self(&mut mut_c);
+    assert_eq!(mut_c, SquareMatrix::invert(&matrix4::C).unwrap());
 
     let mat_c = Matrix4::new(-0.131917f64, -0.76871f64,   0.625846f64, 0.0f64,
                              -0.,        0.631364f64,  0.775487f64, 0.0f64,
                              -0.991261f64,  0.1023f64,   -0.083287f64, 0.0f64,
                               0.,       -1.262728f64, -1.550973f64, 1.0f64);
-    assert!((mat_c.invert().unwrap() * mat_c).is_identity());
+    assert!((SquareMatrix::invert(&mat_c).unwrap() * mat_c).is_identity());
 
     let mat_d = Matrix4::new( 0.065455f64, -0.720002f64,  0.690879f64, 0.0f64,
                              -0.,        0.692364f64,  0.721549f64, 0.0f64,
                              -0.997856f64, -0.047229f64,  0.045318f64, 0.0f64,
                               0.,       -1.384727f64, -1.443098f64, 1.0f64);
-    assert!((mat_d.invert().unwrap() * mat_d).is_identity());
+    assert!((SquareMatrix::invert(&mat_d).unwrap() * mat_d).is_identity());
 
     let mat_e = Matrix4::new( 0.409936f64,  0.683812f64, -0.603617f64, 0.0f64,
                               0.,        0.661778f64,  0.7497f64,   0.0f64,
                               0.912114f64, -0.307329f64,  0.271286f64, 0.0f64,
                              -0.,       -1.323555f64, -1.499401f64, 1.0f64);
-    assert!((mat_e.invert().unwrap() * mat_e).is_identity());
+    assert!((SquareMatrix::invert(&mat_e).unwrap() * mat_e).is_identity());
 
     let mat_f = Matrix4::new(-0.160691f64, -0.772608f64,  0.614211f64, 0.0f64,
                              -0.,        0.622298f64,  0.78278f64,  0.0f64,
                              -0.987005f64,  0.125786f64, -0.099998f64, 0.0f64,
                               0.,       -1.244597f64, -1.565561f64, 1.0f64);
-    assert!((mat_f.invert().unwrap() * mat_f).is_identity());
+    assert!((SquareMatrix::invert(&mat_f).unwrap() * mat_f).is_identity());
+}
+
+#[test]
+fn test_invert_scale_invariant() {
+    // well conditioned, but with a tiny determinant
+    let small = Matrix4::from_scale(1e-3f64) * Matrix4::from_angle_y(rad(0.5));
+    assert!(small.is_invertible());
+    assert_approx_eq!(SquareMatrix::invert(&small).unwrap() * small, Matrix4::identity());
+
+    // nearly singular, but with a large determinant
+    let large = Matrix3::from_cols(Vector3::new(1e4f64, 0.0, 0.0),
+                                   Vector3::new(1e4, 1e-3, 0.0),
+                                   Vector3::new(0.0, 0.0, 1e4));
+    assert!(large.determinant() > 1.0);
+    assert!(!large.is_invertible());
+    assert!(large.invert().is_none());
+
+    assert!(Matrix2::new(1e-4f64, 0.0, 0.0, 1e-4).is_invertible());
+    assert!(!Matrix2::<f64>::from_value(0.0).is_invertible());
+    assert!(!Matrix2::new(f64::NAN, 0.0, 0.0, 1.0).is_invertible());
+
+    let m = Matrix3::new(2.0f64, 0.0, 1.0, 0.0, 3.0, 0.0, 1.0, 0.0, 1.0);
+    assert_eq!(m.invert_unchecked(), m.invert().unwrap());
+    assert!(!Matrix2::<f64>::from_value(0.0).invert_unchecked().is_finite());
+}
+
+#[test]
+fn test_transpose_mul() {
+    assert_eq!(matrix2::A.transpose_mul(&matrix2::B), matrix2::A.transpose() * matrix2::B);
+    assert_eq!(matrix3::A.transpose_mul(&matrix3::B), matrix3::A.transpose() * matrix3::B);
+    assert_eq!(matrix4::A.transpose_mul(&matrix4::B), matrix4::A.transpose() * matrix4::B);
+
+    assert_eq!(matrix2::A.mul_transpose(&matrix2::B), matrix2::A * matrix2::B.transpose());
+    assert_eq!(matrix3::A.mul_transpose(&matrix3::B), matrix3::A * matrix3::B.transpose());
+    assert_eq!(matrix4::A.mul_transpose(&matrix4::B), matrix4::A * matrix4::B.transpose());
+
+    let mut m = matrix3::A;
+    m.transpose_self();
+    assert_eq!(m, matrix3::A.transpose());
 }
 
 #[test]
@@ -291,6 +330,28 @@ fn test_from_translation() {
     assert_eq!(res, Vector4::new(1., 2., 3., 1.));
 }
 
+#[test]
+fn test_scale_translation_shear() {
+    let v2 = Vector2::new(3.0f64, 4.0);
+    assert_eq!(Matrix2::from_scale(2.0) * v2, Vector2::new(6.0, 8.0));
+    assert_eq!(Matrix2::from_nonuniform_scale(2.0, -1.0) * v2, Vector2::new(6.0, -4.0));
+    assert_eq!(Matrix2::from_shear(0.5) * v2, Vector2::new(5.0, 4.0));
+
+    let v3 = Vector3::new(3.0f64, 4.0, 5.0);
+    assert_eq!(Matrix3::from_scale(2.0) * v3, Vector3::new(6.0, 8.0, 10.0));
+    assert_eq!(Matrix3::from_nonuniform_scale(2.0, -1.0, 1.0) * v2.extend(1.0), Vector3::new(6.0, -4.0, 1.0));
+    assert_eq!(Matrix3::from_translation(Vector2::new(1.0, 2.0)) * v2.extend(1.0), Vector3::new(4.0, 6.0, 1.0));
+    assert_eq!(Matrix3::from_translation(Vector2::new(1.0, 2.0)) * v2.extend(0.0), v2.extend(0.0));
+    assert_eq!(Matrix3::from_shear(1.0, 2.0, 3.0) * v3, Vector3::new(17.0, 19.0, 5.0));
+    assert_eq!(Matrix4::from_shear(1.0, 2.0, 3.0) * v3.extend(1.0), Vector4::new(17.0, 19.0, 5.0, 1.0));
+
+    let m = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0)) * Matrix4::from_shear(0.5, -1.0, 2.0) *
+            Matrix4::from_nonuniform_scale(2.0, 3.0, 4.0);
+    let d = m.decompose().unwrap();
+    assert_approx_eq!(d.shear, Vector3::new(0.5, -1.0, 2.0));
+    assert_approx_eq!(d.scale, Vector3::new(2.0, 3.0, 4.0));
+}
+
 #[test]
 fn test_predicates() {
     // Matrix2
@@ -351,6 +412,112 @@ fn test_predicates() {
     assert!(Matrix4::from_value(6.0f64).is_diagonal());
 }
 
+#[test]
+fn test_rotation_predicates() {
+    let rot = Matrix3::from_axis_angle(Vector3::new(0.0f64, 0.6, 0.8), deg(50.0).into());
+    assert!(rot.is_rotation());
+    assert!(!rot.is_reflection());
+    assert!(Matrix2::from_angle(rad(1.0f64)).is_rotation());
+    assert!(Matrix4::<f64>::identity().is_rotation());
+
+    // neither scales nor translations are rotations
+    assert!(!Matrix3::from_value(2.0f64).is_rotation());
+    assert!(!Matrix4::from_translation(Vector3::new(1.0f64, 0.0, 0.0)).is_rotation());
+    assert!(!(rot * Matrix3::from_diagonal(Vector3::new(1.0, 2.0, 1.0))).is_rotation());
+
+    let mirror = Matrix3::from_diagonal(Vector3::new(1.0f64, -1.0, 1.0));
+    assert!(!mirror.is_rotation());
+    assert!(mirror.is_reflection());
+    assert!((rot * mirror).is_reflection());
+    assert!(!Matrix3::<f64>::zero().is_reflection());
+}
+
+#[test]
+fn test_orthogonality() {
+    let rot = Matrix3::from_axis_angle(Vector3::new(0.0f64, 0.6, 0.8), deg(50.0).into());
+    let scaled = rot * Matrix3::from_diagonal(Vector3::new(1.0, 20.0, 0.5));
+    assert!(rot.is_orthonormal(1e-12));
+    assert!(scaled.is_orthogonal(1e-12));
+    assert!(!scaled.is_orthonormal(1e-6));
+
+    let sheared = Matrix2::new(1.0f64, 0.0, 0.01, 1.0);
+    assert!(!sheared.is_orthogonal(1e-3));
+    assert!(sheared.is_orthogonal(0.1));
+    assert!(!Matrix4::<f64>::zero().is_orthonormal(0.1));
+    assert!(Matrix4::from_nonuniform_scale(1.0f64, -2.0, 3.0).is_orthogonal(0.0));
+    assert!(!Matrix3::new(f64::NAN, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0).is_orthonormal(0.1));
+}
+
+#[test]
+fn test_is_affine() {
+    assert!(Matrix4::from_translation(Vector3::new(1.0f64, 2.0, 3.0)).is_affine());
+    assert!(Matrix4::from(Matrix3::from_value(2.0f64)).is_affine());
+    assert!(!perspective(deg(60.0f64), 1.0, 0.1, 100.0).is_affine());
+}
+
+#[test]
+fn test_adjugate() {
+    let m = Matrix3::new(1.0f64, 2.0, 3.0, 0.0, 1.0, 4.0, 5.0, 6.0, 0.0);
+    assert_eq!(m.adjugate(), Matrix3::new(-24.0, 18.0, 5.0, 20.0, -15.0, -4.0, -5.0, 4.0, 1.0));
+    assert_eq!(m.cofactor(0, 0), -24.0);
+    assert_eq!(m.cofactor(1, 0), 18.0);
+    assert_eq!(m.cofactor(0, 1), 20.0);
+
+    // the adjugate is the inverse scaled by the determinant
+    for &m in &[matrix4::C, matrix4::D] {
+        assert!(m.adjugate().approx_eq(&(SquareMatrix::invert(&m).unwrap() * m.determinant())));
+        assert!((m * m.adjugate()).approx_eq(&Matrix4::from_value(m.determinant())));
+    }
+    let m = Matrix2::new(1.0f64, 2.0, 3.0, 4.0);
+    assert_eq!(m.adjugate() * m, Matrix2::from_value(m.determinant()));
+    assert_eq!(m.cofactor(1, 0), -2.0);
+
+    // and exists even when the inverse doesn't
+    let singular = Matrix3::new(1.0f64, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 1.0, 0.0);
+    assert!((singular * singular.adjugate()).is_zero());
+}
+
+#[test]
+fn test_diagonal() {
+    let m = Matrix3::from_diagonal(Vector3::new(1.0f64, 2.0, 3.0));
+    assert!(m.is_diagonal());
+    assert_eq!(m * Vector3::new(1.0, 1.0, 1.0), Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(m.diagonal(), Vector3::new(1.0, 2.0, 3.0));
+
+    assert_eq!(Matrix2::from_diagonal(Vector2::new(4.0f64, 5.0)), Matrix2::new(4.0, 0.0, 0.0, 5.0));
+    assert_eq!(Matrix4::from_diagonal(matrix4::V).diagonal(), matrix4::V);
+    assert_eq!(matrix3::A.diagonal(), Vector3::new(1.0, 5.0, 9.0));
+}
+
+#[test]
+fn test_truncate() {
+    assert_eq!(matrix4::A.truncate(), Matrix3::new(1.0, 5.0, 9.0,
+                                                   2.0, 6.0, 10.0,
+                                                   3.0, 7.0, 11.0));
+    assert_eq!(matrix3::A.truncate(), Matrix2::new(1.0, 4.0, 2.0, 5.0));
+    assert_eq!(matrix4::A.truncate().truncate(), Matrix2::new(1.0, 5.0, 2.0, 6.0));
+
+    // truncating undoes expanding
+    assert_eq!(Matrix4::from(matrix3::A).truncate(), matrix3::A);
+    assert_eq!(Matrix3::from(matrix2::A).truncate(), matrix2::A);
+
+    let rot = Matrix3::from_angle_z(rad(0.5f64));
+    let transform = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0)) * Matrix4::from(rot);
+    assert_eq!(transform.truncate(), rot);
+}
+
+#[test]
+fn test_is_zero_is_finite() {
+    assert!(Matrix3::<f64>::zero().is_zero());
+    assert!(!matrix2::A.is_zero());
+    assert!(matrix4::A.is_finite());
+
+    let mut m = matrix3::A;
+    m[2][1] = f64::INFINITY;
+    assert!(!m.is_finite());
+    assert!(!(Matrix2::<f64>::identity() * f64::NAN).is_finite());
+}
+
 #[test]
 fn test_from_angle() {
     // Rotate the vector (1, 0) by π/2 radians to the vector (0, 1)
@@ -365,3 +532,301 @@ fn test_from_angle() {
     let rot3: Matrix2<f64> = Matrix2::from_angle(rad(f64::consts::PI));
     assert!((rot3 * Vector2::new(1.0, 1.0)).approx_eq(&Vector2::new(-1.0, -1.0)));
 }
+
+#[test]
+fn test_from_angle_xyz() {
+    let theta = rad(0.5f64 * f64::consts::PI);
+    assert_approx_eq!(Matrix3::from_angle_x(theta) * Vector3::unit_y(), Vector3::unit_z());
+    assert_approx_eq!(Matrix3::from_angle_y(theta) * Vector3::unit_z(), Vector3::unit_x());
+    assert_approx_eq!(Matrix3::from_angle_z(theta) * Vector3::unit_x(), Vector3::unit_y());
+
+    let p = Point3::new(1.0f64, 2.0, 3.0).to_homogeneous();
+    let theta = rad(0.7f64);
+    assert_approx_eq!(Matrix4::from_angle_x(theta) * p, (Matrix3::from_angle_x(theta) * Vector3::new(1.0, 2.0, 3.0)).extend(1.0));
+    assert_approx_eq!(Matrix4::from_angle_y(theta) * p, (Matrix3::from_angle_y(theta) * Vector3::new(1.0, 2.0, 3.0)).extend(1.0));
+    assert_approx_eq!(Matrix4::from_angle_z(theta) * p, (Matrix3::from_angle_z(theta) * Vector3::new(1.0, 2.0, 3.0)).extend(1.0));
+    assert_approx_eq!(Matrix4::from_angle_z(theta), Matrix4::from(Quaternion::from_angle_z(theta)));
+}
+
+#[test]
+fn test_precise() {
+    // The products in the determinant are exact in f64, but are rounded to a
+    // multiple of 8 in f32 before they cancel. The determinant is 9216.125.
+    let m = Matrix2::new(8193.0f32, 8192.0, 8192.0, 8192.125);
+    let adjugate = Matrix2::new(8192.125f32, -8192.0, -8192.0, 8193.0);
+    assert_eq!(m.precise_determinant(), 9216.125);
+    assert_eq!(m.determinant(), 9216.0);
+
+    assert_eq!(m.precise_mul(&adjugate), Matrix2::from_value(9216.125));
+    assert!(m * adjugate != Matrix2::from_value(9216.125));
+
+    let inverse = Matrix2::new(8192.125 / 9216.125, -8192.0 / 9216.125,
+                               -8192.0 / 9216.125, 8193.0 / 9216.125);
+    assert_eq!(m.precise_invert(), Some(inverse));
+    assert!(m.invert().unwrap() != inverse);
+
+    assert!(Matrix2::new(1.0f32, 2.0, 2.0, 4.0).precise_invert().is_none());
+}
+
+#[test]
+fn test_to_angle() {
+    for &theta in [0.0f64, 1e-7, 0.3, -2.0, 3.1415926].iter() {
+        assert!(Matrix2::from_angle(rad(theta)).to_angle().approx_eq(&rad(theta)));
+    }
+}
+
+#[test]
+fn test_to_axis_angle() {
+    let axis = Vector3::new(1.0f64, -2.0, 0.5).normalize();
+    for &theta in [1e-7f64, 0.3, 2.0, 3.14159, f64::consts::PI].iter() {
+        let mat = Matrix3::from_axis_angle(axis, rad(theta));
+        let (a, angle) = mat.to_axis_angle();
+        assert!(angle.approx_eq(&rad(theta)));
+        assert!(Matrix3::from_axis_angle(a, angle).approx_eq(&mat));
+        if theta > 1e-3 && theta < 3.0 {
+            assert!(a.approx_eq(&axis));
+        }
+    }
+    let (a, angle) = Matrix3::<f64>::identity().to_axis_angle();
+    assert_eq!((a, angle), (Vector3::unit_x(), rad(0.0)));
+}
+
+#[test]
+fn test_to_euler() {
+    let mat = Matrix3::from_euler(rad(0.2f64), rad(-0.4), rad(1.0));
+    let euler = mat.to_euler(EulerOrder::XYZ);
+    assert!(euler.approx_eq(&Euler::new(rad(0.2), rad(-0.4), rad(1.0), EulerOrder::XYZ)));
+}
+
+#[test]
+fn test_mul_affine() {
+    let a = Matrix4::from_translation(Vector3::new(1.0f64, -2.0, 3.0)) *
+            Matrix4::from(Matrix3::from_axis_angle(Vector3::new(1.0, 1.0, 0.0).normalize(), rad(0.8)));
+    let b = Matrix4::from_nonuniform_scale(2.0f64, 0.5, -1.0) *
+            Matrix4::from_translation(Vector3::new(0.25, 4.0, -1.5));
+    assert!(a.mul_affine(&b).approx_eq(&(a * b)));
+    assert!(b.mul_affine(&a).approx_eq(&(b * a)));
+    assert_eq!(a.mul_affine(&Matrix4::identity()), a);
+}
+
+#[test]
+fn test_exact_eq() {
+    let a = Matrix2::new(1.0f64, 2.0, 3.0, 4.0);
+    let b = Matrix2::new(1.0f64, 2.0, 3.0, 4.0 + 1e-9);
+    assert!(a == a);
+    assert!(a != b);
+    assert!(a.approx_eq(&b));
+}
+
+#[test]
+fn test_norms() {
+    let m = Matrix2::new(1.0f64, -3.0,
+                         2.0, 4.0);
+    assert!(m.norm_frobenius().approx_eq(&30.0f64.sqrt()));
+    // rows are [1, 2] and [-3, 4]
+    assert!(m.norm_inf().approx_eq(&7.0));
+    assert!(m.norm_one().approx_eq(&6.0));
+
+    assert!(Matrix4::<f64>::identity().norm_frobenius().approx_eq(&2.0));
+    assert!(Matrix3::<f64>::identity().condition_number().approx_eq(&1.0));
+    assert!(Matrix3::from_angle_x(rad(0.3f64)).norm_frobenius().approx_eq(&3.0f64.sqrt()));
+}
+
+#[test]
+fn test_condition_number() {
+    // inverse is [[4, -2], [3, 1]] / 10
+    let m = Matrix2::new(1.0f64, -3.0,
+                         2.0, 4.0);
+    assert!(m.condition_number().approx_eq(&(7.0 * 0.6)));
+
+    let nearly_singular = Matrix2::new(1.0f64, 1.0,
+                                       1.0, 1.0 + 1e-10);
+    assert!(nearly_singular.condition_number() > 1e9);
+    assert_eq!(Matrix2::new(1.0f64, 2.0, 2.0, 4.0).condition_number(), f64::INFINITY);
+}
+
+#[test]
+fn test_iterators() {
+    let cols: Vec<_> = matrix3::A.columns().cloned().collect();
+    assert_eq!(cols, vec![matrix3::A.x, matrix3::A.y, matrix3::A.z]);
+
+    let rows: Vec<_> = matrix2::A.rows().collect();
+    assert_eq!(rows, vec![Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0)]);
+
+    let elements: Vec<f64> = matrix4::A.elements().cloned().collect();
+    assert_eq!(elements, vec![1.0, 5.0, 9.0, 13.0,
+                              2.0, 6.0, 10.0, 14.0,
+                              3.0, 7.0, 11.0, 15.0,
+                              4.0, 8.0, 12.0, 16.0]);
+    assert_eq!(matrix4::A.elements().len(), 16);
+    assert_eq!(matrix4::A.rows().skip(1).len(), 3);
+}
+
+#[test]
+fn test_map_zip() {
+    assert_eq!(matrix2::A.map(|x| x * 2.0), matrix2::A * 2.0);
+    assert_eq!(matrix3::A.map(|x| x as i32)[2][1], 6);
+    assert_eq!(matrix4::A.zip(matrix4::B, |a, b| b - a), matrix4::B - matrix4::A);
+    assert_eq!(matrix3::A.zip(matrix3::B, f64::max), matrix3::B);
+}
+
+#[test]
+fn test_from_slice() {
+    let elements = [1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0,
+                    10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0];
+    assert_eq!(Matrix2::from_slice(&elements[..4]), Some(Matrix2::new(1.0, 2.0, 3.0, 4.0)));
+    assert_eq!(Matrix3::from_slice(&elements[..9]),
+               Some(Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0)));
+    let expected = Matrix4::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0,
+                                9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+    assert_eq!(Matrix4::from_slice(&elements), Some(expected));
+    assert_eq!(Matrix4::from_slice(&elements[..15]), None);
+    assert_eq!(Matrix3::from_slice(&elements[..10]), None);
+
+    let m = unsafe { Matrix4::from_ptr(elements.as_ptr()) };
+    assert_eq!(m, expected);
+    assert_eq!(Matrix4::from_slice(AsRef::<[f64; 16]>::as_ref(&m)), Some(m));
+}
+
+#[test]
+fn test_display_parse() {
+    let m = Matrix3::new(1.0f64, 4.0, 7.0,
+                         -2.5, 5.0, 8.0,
+                         3.0, 60.0, -9.0);
+    assert_eq!(m.to_string(), "[1, -2.5,  3]\n[4,    5, 60]\n[7,    8, -9]");
+    assert_eq!(format!("{:.1}", Matrix2::new(1.0f64, 0.0, -0.5, 2.0)), "[1.0, -0.5]\n[0.0,  2.0]");
+    assert_eq!(m.to_string().parse::<Matrix3<f64>>(), Ok(m));
+    assert_eq!(matrix4::A.to_string().parse::<Matrix4<f64>>(), Ok(matrix4::A));
+    assert_eq!("[1, 2] [3, 4]".parse::<Matrix2<f64>>(), Ok(Matrix2::new(1.0, 3.0, 2.0, 4.0)));
+
+    assert_eq!("[1, 2] [3, 4".parse::<Matrix2<f64>>(), Err(ParseError::Syntax));
+    assert_eq!("[1, 2]".parse::<Matrix2<f64>>(), Err(ParseError::Length));
+    assert_eq!("[1, 2] [3, 4, 5]".parse::<Matrix2<f64>>(), Err(ParseError::Length));
+}
+
+#[test]
+fn test_hash() {
+    use std::collections::HashSet;
+
+    let mut set = HashSet::new();
+    set.insert(matrix4::A);
+    set.insert(matrix4::B);
+    set.insert(matrix4::A);
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&matrix4::B));
+
+    let mut rotations = HashSet::new();
+    rotations.insert(Quaternion::new(1.0f32, 0.0, 0.0, 0.0));
+    assert!(rotations.contains(&Quaternion::new(1.0, -0.0, 0.0, 0.0)));
+
+    // integer matrices hash exactly too
+    let mut permutations = HashSet::new();
+    permutations.insert(Matrix3::from([[1, 0, 0], [0, 1, 0], [0, 0, 1]]));
+    permutations.insert(Matrix3::from([[1, 0, 0], [0, 1, 0], [0, 0, 1]]));
+    permutations.insert(Matrix3::from([[0, 1, 0], [1, 0, 0], [0, 0, 1]]));
+    assert_eq!(permutations.len(), 2);
+}
+
+#[test]
+fn test_as_slice() {
+    let mut m = Matrix2::new(1.0f64, 2.0, 3.0, 4.0);
+    assert_eq!(m.as_slice(), &[1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(Matrix4::from_slice(matrix4::A.as_slice()), Some(matrix4::A));
+
+    m.as_mut_slice()[2] = 5.0;
+    assert_eq!(m, Matrix2::new(1.0, 2.0, 5.0, 4.0));
+
+    let buffer = Matrix3::<f64>::identity().as_slice().to_vec();
+    assert_eq!(buffer, vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]);
+}
+
+#[test]
+fn test_row_major() {
+    let rows = [[1.0f64, 2.0, 3.0, 4.0],
+                [5.0, 6.0, 7.0, 8.0],
+                [9.0, 10.0, 11.0, 12.0],
+                [13.0, 14.0, 15.0, 16.0]];
+    // `matrix4::A` is written out row by row
+    assert_eq!(Matrix4::from_row_major(rows), matrix4::A);
+    assert_eq!(matrix4::A.to_row_major_array(), rows);
+    assert_eq!(matrix4::A.row(1), Vector4::new(5.0, 6.0, 7.0, 8.0));
+
+    let m = Matrix2::from_row_major([[1.0f64, 2.0], [3.0, 4.0]]);
+    assert_eq!(m, Matrix2::new(1.0, 3.0, 2.0, 4.0));
+    assert_eq!(Matrix3::from_row_major(matrix3::A.to_row_major_array()), matrix3::A);
+}
+
+#[test]
+fn test_planar_projection() {
+    let plane = Plane::from_point_normal(Point3::new(0.0f64, 1.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    let project = |m: Matrix4<f64>, p: Point3<f64>| Point3::from_homogeneous(m * p.to_homogeneous());
+
+    let m = Matrix4::from_planar_projection(&plane, Point3::new(0.0, 5.0, 0.0));
+    assert_approx_eq!(project(m, Point3::new(1.0, 3.0, 2.0)), Point3::new(2.0, 1.0, 4.0));
+    assert_approx_eq!(project(m, Point3::new(-3.0, 1.0, 7.0)), Point3::new(-3.0, 1.0, 7.0));
+
+    let m = Matrix4::from_planar_projection_directional(&plane, Vector3::new(1.0, -1.0, 0.0));
+    assert_approx_eq!(project(m, Point3::new(1.0, 3.0, 2.0)), Point3::new(3.0, 1.0, 2.0));
+    assert_approx_eq!(project(m, Point3::new(-3.0, 1.0, 7.0)), Point3::new(-3.0, 1.0, 7.0));
+}
+
+#[test]
+fn test_permutation() {
+    let p = Matrix3::from_permutation([2, 0, 1]).unwrap();
+    assert_eq!(p * Vector3::new(1.0f64, 2.0, 3.0), Vector3::new(3.0, 1.0, 2.0));
+    assert!(p.is_rotation());
+    assert_eq!(p.transpose() * p, Matrix3::identity());
+
+    // a single swap permutes rows from the left and columns from the right
+    let swap = Matrix4::from_permutation([0, 3, 2, 1]).unwrap();
+    let mut rows = matrix4::A;
+    rows.swap_rows(1, 3);
+    assert_eq!(swap * matrix4::A, rows);
+    let mut cols = matrix4::A;
+    cols.swap_columns(1, 3);
+    assert_eq!(matrix4::A * swap, cols);
+    assert!(swap.is_reflection());
+
+    let a = Matrix3::new(1.0f64, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 10.0);
+    let lu = a.lu().unwrap();
+    assert_approx_eq!(Matrix3::from_permutation(lu.perm).unwrap() * a, lu.l * lu.u);
+
+    assert!(Matrix2::<f64>::from_permutation([1, 1]).is_none());
+    assert!(Matrix2::<f64>::from_permutation([0, 2]).is_none());
+}
+
+#[test]
+fn test_index() {
+    let mut m = matrix3::A;
+    assert_eq!(m[1], m.y);
+    assert_eq!(m[2][0], 3.0);
+    m[0][2] = -1.0;
+    m[1] = Vector3::new(0.0, 1.0, 2.0);
+    assert_eq!(m, Matrix3::new(1.0, 4.0, -1.0, 0.0, 1.0, 2.0, 3.0, 6.0, 9.0));
+    assert_eq!(matrix4::A * matrix4::V, Vector4::new(matrix4::A.row(0).dot(matrix4::V),
+                                                     matrix4::A.row(1).dot(matrix4::V),
+                                                     matrix4::A.row(2).dot(matrix4::V),
+                                                     matrix4::A.row(3).dot(matrix4::V)));
+}
+
+#[test]
+#[should_panic]
+fn test_index_out_of_bounds() {
+    let _ = matrix2::A[2];
+}
+
+#[test]
+fn test_look_at_pose() {
+    let (eye, center, up) = (Point3::new(1.0f64, 2.0, 3.0), Point3::new(-2.0, 0.5, 1.0), Vector3::unit_y());
+    let (position, orientation) = Matrix4::look_at_pose(eye, center, up);
+    assert_eq!(position, eye);
+    assert_approx_eq!(orientation.rotate_vector(-Vector3::unit_z()), (center - eye).normalize());
+    assert!(orientation.rotate_vector(Vector3::unit_y()).dot(up) > 0.0);
+    assert_approx_eq!(Matrix4::from_camera_pose(position, orientation), Matrix4::look_at(eye, center, up));
+
+    // moving the pose moves the view
+    let (position, orientation) = (position + Vector3::unit_x(), orientation * Quaternion::from_angle_y(rad(0.5)));
+    let view = Matrix4::from_camera_pose(position, orientation);
+    assert_approx_eq!(view * position.to_homogeneous(), Vector4::unit_w());
+    assert_approx_eq!(view * orientation.rotate_vector(Vector3::unit_x()).extend(0.0), Vector4::unit_x());
+}
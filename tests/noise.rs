@@ -0,0 +1,71 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::*;
+use std::{f32, f64};
+
+#[test]
+fn test_reference_value() {
+    // from Ken Perlin's reference implementation
+    assert_approx_eq!(perlin3(Vector3::new(3.14f64, 42.0, 7.0)), 0.13691995878400012);
+}
+
+#[test]
+fn test_lattice_points() {
+    for &(x, y, z) in [(0.0f64, 0.0, 0.0), (1.0, -3.0, 7.0), (-12.0, 255.0, 256.0)].iter() {
+        assert_eq!(perlin3(Vector3::new(x, y, z)), 0.0);
+        assert_eq!(perlin2(Vector2::new(x, y)), 0.0);
+    }
+}
+
+#[test]
+fn test_non_finite() {
+    assert!(perlin3(Vector3::new(f64::NAN, 0.5, 0.5)).is_nan());
+    assert!(perlin3(Vector3::new(0.5, f64::INFINITY, 0.5)).is_nan());
+    assert!(perlin2(Vector2::new(0.5f32, f32::NEG_INFINITY)).is_nan());
+    // too large for an integer, so on a lattice point
+    assert_eq!(perlin3(Vector3::new(1e30f64, -1e20, 0.5)), perlin3(Vector3::new(0.0, 0.0, 0.5)));
+}
+
+#[test]
+fn test_plane() {
+    for i in 0..50 {
+        let p = Vector2::new(i as f64 * 0.37 - 9.0, i as f64 * 0.71 + 2.0);
+        assert_approx_eq!(perlin2(p), perlin3(p.extend(0.0)));
+    }
+}
+
+#[test]
+fn test_range_and_period() {
+    for i in 0..1000 {
+        let p = Vector3::new(i as f64 * 0.137, i as f64 * 0.0731 - 20.0, i as f64 * 0.291);
+        let n = perlin3(p);
+        assert!(n >= -1.0 && n <= 1.0, "{} out of range", n);
+        assert_approx_eq!(n, perlin3(p + Vector3::new(256.0, -256.0, 512.0)));
+    }
+}
+
+#[test]
+fn test_fbm() {
+    let p = Vector2::new(0.3f64, 1.7);
+    assert_eq!(fbm(perlin2, p, 1, 2.0, 0.5), perlin2(p));
+    assert_approx_eq!(fbm(perlin2, p, 3, 2.0, 0.5),
+                      perlin2(p) + perlin2(p * 2.0) * 0.5 + perlin2(p * 4.0) * 0.25);
+    assert_approx_eq!(turbulence(perlin2, p, 2, 3.0, 0.25),
+                      perlin2(p).abs() + perlin2(p * 3.0).abs() * 0.25);
+    assert_eq!(fbm(perlin2, p, 0, 2.0, 0.5), 0.0);
+}
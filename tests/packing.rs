@@ -0,0 +1,57 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_unorm4x8() {
+    let color = Vector4::new(1.0f32, 0.0, 0.5, 0.2);
+    assert_eq!(color.pack_unorm4x8(), 0x33_80_00_ff);
+    assert!(Vector4::unpack_unorm4x8(0x33_80_00_ff).approx_eq_eps(&color, &(1.0 / 255.0)));
+    // out of range components are clamped
+    assert_eq!(Vector4::new(2.0f32, -1.0, 0.0, 1.0).pack_unorm4x8(), 0xff_00_00_ff);
+}
+
+#[test]
+fn test_snorm4x8() {
+    let v = Vector4::new(1.0f32, -1.0, 0.0, -0.5);
+    assert_eq!(v.pack_snorm4x8(), 0xc0_00_81_7f);
+    assert!(Vector4::unpack_snorm4x8(v.pack_snorm4x8()).approx_eq_eps(&v, &(0.5 / 127.0)));
+    // -128 also unpacks to -1
+    assert_eq!(Vector4::unpack_snorm4x8(0x80), Vector4::new(-1.0, 0.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_16_bit() {
+    let v = Vector2::new(0.25f32, 1.0);
+    assert_eq!(v.pack_unorm2x16(), 0xffff_4000);
+    assert!(Vector2::unpack_unorm2x16(v.pack_unorm2x16()).approx_eq_eps(&v, &(0.5 / 65535.0)));
+
+    let v = Vector2::new(-1.0f32, 0.5);
+    assert_eq!(v.pack_snorm2x16(), 0x4000_8001);
+    assert!(Vector2::unpack_snorm2x16(v.pack_snorm2x16()).approx_eq_eps(&v, &(0.5 / 32767.0)));
+}
+
+#[test]
+fn test_snorm_10_10_10_2() {
+    let normal = Vector3::new(1.0f32, -2.0, 2.0).normalize().extend(-1.0);
+    let packed = normal.pack_snorm_10_10_10_2();
+    assert_eq!(packed >> 30, 0b11);
+    assert!(Vector4::unpack_snorm_10_10_10_2(packed).approx_eq_eps(&normal, &(0.5 / 511.0)));
+    assert_eq!(Vector4::new(1.0f32, -1.0, 0.0, 1.0).pack_snorm_10_10_10_2(),
+               0x1ff | 0x201 << 10 | 0x1 << 30);
+}
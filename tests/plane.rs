@@ -0,0 +1,75 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_from_points() {
+    let plane = Plane::from_points(Point3::new(1.0f64, 0.0, 3.0),
+                                   Point3::new(0.0, 1.0, 3.0),
+                                   Point3::new(0.0, 0.0, 3.0)).unwrap();
+    assert!(plane.approx_eq(&Plane::new(Vector3::new(0.0, 0.0, 1.0), 3.0)));
+    assert!(plane.approx_eq(&Plane::from_abcd(0.0, 0.0, 1.0, 3.0)));
+
+    assert!(Plane::from_points(Point3::new(1.0f64, 1.0, 1.0),
+                               Point3::new(2.0, 2.0, 2.0),
+                               Point3::new(3.0, 3.0, 3.0)).is_none());
+
+    // the collinearity test doesn't depend on the distances between the points
+    let small = Plane::from_points(Point3::new(0.001f64, 0.0, 3.0),
+                                   Point3::new(0.0, 0.001, 3.0),
+                                   Point3::new(0.0, 0.0, 3.0)).unwrap();
+    assert!(small.approx_eq(&plane));
+    assert!(Plane::from_points(Point3::new(0.0f64, 0.0, 0.0),
+                               Point3::new(1000.0, 0.0, 0.0),
+                               Point3::new(2000.0, 1e-9, 0.0)).is_none());
+
+    // thin triangles are fine, however their vertices are labelled
+    let (a, b, c) = (Point3::new(0.0f64, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0), Point3::new(1000.0, 1.0, 0.0));
+    for &(a, b, c) in &[(a, b, c), (b, c, a), (c, a, b)] {
+        let plane = Plane::from_points(a, b, c).unwrap();
+        assert!(plane.n.approx_eq(&Vector3::unit_z()));
+        assert!(plane.d.approx_eq(&0.0));
+    }
+}
+
+#[test]
+fn test_distance() {
+    let plane = Plane::from_point_normal(Point3::new(1.0f64, 1.0, 1.0), Vector3::new(0.0, 1.0, 0.0));
+    assert!(plane.distance(Point3::new(5.0, 4.0, -2.0)).approx_eq(&3.0));
+    assert!(plane.distance(Point3::new(5.0, -1.0, -2.0)).approx_eq(&-2.0));
+}
+
+#[test]
+fn test_transform() {
+    let plane = Plane::from_point_normal(Point3::new(1.0f64, 2.0, 3.0), Vector3::new(1.0, 1.0, 0.0).normalize());
+    let m = Matrix4::from_translation(Vector3::new(4.0, -5.0, 6.0)) *
+            Matrix4::from(Quaternion::from_axis_angle(Vector3::unit_z(), rad(1.0))) *
+            Matrix4::from_nonuniform_scale(2.0, 0.5, 3.0);
+    let transformed = plane.transform(&m).unwrap().normalize().unwrap();
+
+    for p in &[Point3::new(1.0, 2.0, 3.0), Point3::new(2.0, 1.0, -7.0), Point3::new(0.0, 3.0, 5.0)] {
+        assert!(plane.distance(*p).approx_eq(&0.0));
+        let q = Point3::from_homogeneous(m * p.to_homogeneous());
+        assert!(transformed.distance(q).approx_eq(&0.0));
+    }
+    // points on the positive side stay on it
+    let q = Point3::from_homogeneous(m * Point3::new(2.0, 3.0, 0.0).to_homogeneous());
+    assert!(transformed.distance(q) > 0.0);
+
+    assert!(plane.transform(&Matrix4::from_value(0.0)).is_none());
+}
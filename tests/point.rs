@@ -16,7 +16,7 @@
 
 extern crate cgmath;
 
-use cgmath::Point3;
+use cgmath::{Point2, Point3};
 use cgmath::ApproxEq;
 
 #[test]
@@ -24,3 +24,60 @@ fn test_homogeneous() {
 	let p = Point3::new(1.0f64, 2.0f64, 3.0f64);
     assert!(p.approx_eq(&Point3::from_homogeneous(p.to_homogeneous())));
 }
+
+#[test]
+fn test_weld() {
+	use std::f64;
+
+	let points = [Point3::new(0.0f64, 0.0, 0.0),
+	              Point3::new(1.0f64, 0.0, 0.0),
+	              Point3::new(0.0005f64, -0.0005, 0.0),
+	              Point3::new(1.0f64, 0.0009, 0.0),
+	              Point3::new(0.0f64, 0.0, 2.0)];
+	let (unique, remap) = Point3::weld(&points, 0.001);
+	assert_eq!(unique, vec![points[0], points[1], points[4]]);
+	assert_eq!(remap, vec![0, 1, 0, 1, 2]);
+
+	let points = [Point2::new(-0.0001f32, 0.0), Point2::new(0.0001f32, 0.0), Point2::new(0.5f32, 0.5)];
+	let (unique, remap) = Point2::weld(&points, 0.001);
+	assert_eq!(unique, vec![points[0], points[2]]);
+	assert_eq!(remap, vec![0, 0, 1]);
+
+	// points that can't be bucketed are kept as they are
+	let points = [Point2::new(f64::NAN, 0.0), Point2::new(0.0, 0.0), Point2::new(f64::INFINITY, 0.0),
+	              Point2::new(0.0, 1e30), Point2::new(0.0, -1e30), Point2::new(0.0, 0.0001)];
+	let (unique, remap) = Point2::weld(&points, 0.001);
+	assert_eq!(unique.len(), 5);
+	assert!(unique[0].x.is_nan());
+	assert_eq!(&unique[1..], &points[1..5]);
+	assert_eq!(remap, vec![0, 1, 2, 3, 4, 1]);
+}
+
+#[test]
+fn test_distance() {
+    let a = Point3::new(1.0f64, 2.0, 3.0);
+    let b = Point3::new(3.0f64, -1.0, 9.0);
+    assert_eq!(a.distance2(b), 49.0);
+    assert_eq!(a.distance(b), 7.0);
+    assert_eq!(b.distance(a), 7.0);
+    assert_eq!(Point2::new(0.0f64, 0.0).distance(Point2::new(3.0, 4.0)), 5.0);
+}
+
+#[test]
+fn test_lex_cmp() {
+    use std::cmp::Ordering;
+    use std::f64;
+
+    assert_eq!(Point2::new(1i32, 2).lex_cmp(&Point2::new(1, 3)), Ordering::Less);
+    assert_eq!(Point3::new(2i32, 0, 0).lex_cmp(&Point3::new(1, 9, 9)), Ordering::Greater);
+    assert_eq!(Point2::new(1.0f64, 2.0).lex_partial_cmp(&Point2::new(1.0, 2.0)), Some(Ordering::Equal));
+    assert_eq!(Point2::new(f64::NAN, 2.0).lex_partial_cmp(&Point2::new(1.0, 2.0)), None);
+    assert_eq!(Point2::new(-0.0f64, 5.0).total_cmp(&Point2::new(0.0, 1.0)), Ordering::Less);
+
+    // events for a sweep along the x axis, breaking ties on y
+    let mut events = vec![Point2::new(2.0f64, 1.0), Point2::new(0.5, 3.0),
+                          Point2::new(2.0, -1.0), Point2::new(0.5, 0.0)];
+    events.sort_by(|a, b| a.total_cmp(b));
+    assert_eq!(events, vec![Point2::new(0.5, 0.0), Point2::new(0.5, 3.0),
+                            Point2::new(2.0, -1.0), Point2::new(2.0, 1.0)]);
+}
@@ -16,6 +16,9 @@
 extern crate cgmath;
 
 use cgmath::{Vector4, ortho, Matrix4};
+use cgmath::{Point3, Vector3, ApproxEq, Matrix, deg, perspective, project, unproject};
+use cgmath::{DepthRange, Ortho, Perspective, PerspectiveFov, perspective_infinite, perspective_infinite_reversed};
+use cgmath::{Point2, SquareMatrix, Vector2, pick_matrix, viewport, viewport_inverse};
 
 #[test]
 fn test_ortho_scale() {
@@ -67,3 +70,135 @@ fn test_ortho_translate() {
     let orig = o * vec_orig;
     assert_eq!(orig, Vector4::new(1., 1., 1., 1.));
 }
+
+#[test]
+fn test_project_unproject() {
+    let view = Matrix4::look_at(Point3::new(1.0f64, 2.0, 5.0), Point3::new(0.0, 0.0, 0.0),
+                                Vector3::unit_y());
+    let proj = perspective(deg(60.0f64), 4.0 / 3.0, 0.1, 100.0);
+    let viewport = [10.0, 20.0, 800.0, 600.0];
+
+    // The point being looked at lands in the centre of the viewport
+    let centre = project(Point3::new(0.0, 0.0, 0.0), &view, &proj, viewport);
+    assert!(centre.x.approx_eq(&410.0));
+    assert!(centre.y.approx_eq(&320.0));
+    assert!(centre.z > 0.0 && centre.z < 1.0);
+
+    let world = Point3::new(0.3, -0.7, 1.2);
+    let window = project(world, &view, &proj, viewport);
+    assert!(unproject(window, &view, &proj, viewport).unwrap().approx_eq(&world));
+
+    assert!(unproject(window, &view, &Matrix4::zero(), viewport).is_none());
+}
+
+fn depth_of(m: &Matrix4<f64>, z: f64) -> f64 {
+    let clip = *m * Vector4::new(0.0, 0.0, z, 1.0);
+    clip.z / clip.w
+}
+
+#[test]
+fn test_depth_range() {
+    let fov = PerspectiveFov { fovy: deg(60.0f64).into(), aspect: 1.5, near: 0.5, far: 50.0 };
+    let frustum = fov.to_perspective();
+    let ortho = Ortho { left: -1.0f64, right: 1.0, bottom: -1.0, top: 1.0, near: 0.5, far: 50.0 };
+
+    let gl = fov.to_matrix(DepthRange::NegativeOneToOne);
+    assert_eq!(gl, Matrix4::from(fov));
+    assert!(depth_of(&gl, -0.5).approx_eq(&-1.0));
+    assert!(depth_of(&gl, -50.0).approx_eq(&1.0));
+
+    for m in &[fov.to_matrix(DepthRange::ZeroToOne),
+               frustum.to_matrix(DepthRange::ZeroToOne),
+               ortho.to_matrix(DepthRange::ZeroToOne)] {
+        assert!(depth_of(m, -0.5).approx_eq(&0.0));
+        assert!(depth_of(m, -50.0).approx_eq(&1.0));
+    }
+    for m in &[fov.to_matrix(DepthRange::ReversedZeroToOne),
+               frustum.to_matrix(DepthRange::ReversedZeroToOne),
+               ortho.to_matrix(DepthRange::ReversedZeroToOne)] {
+        assert!(depth_of(m, -0.5).approx_eq(&1.0));
+        assert!(depth_of(m, -50.0).approx_eq(&0.0));
+    }
+
+    // the other rows are unaffected
+    let zo = fov.to_matrix(DepthRange::ZeroToOne);
+    assert_eq!(zo.x, gl.x);
+    assert_eq!(zo.row(3), gl.row(3));
+}
+
+#[test]
+fn test_perspective_infinite() {
+    let finite = perspective(deg(60.0f64), 1.5, 0.5, 1.0e12);
+    let infinite = perspective_infinite(deg(60.0f64), 1.5, 0.5);
+    assert!(infinite.approx_eq(&finite));
+    assert!(depth_of(&infinite, -0.5).approx_eq(&-1.0));
+    assert!(depth_of(&infinite, -1.0e6) < 1.0);
+
+    let reversed = perspective_infinite_reversed(deg(60.0f64), 1.5, 0.5);
+    assert!(depth_of(&reversed, -0.5).approx_eq(&1.0));
+    assert!(depth_of(&reversed, -5.0).approx_eq(&0.1));
+    assert!(depth_of(&reversed, -1.0e6) > 0.0);
+    assert_eq!(reversed.x, infinite.x);
+    assert_eq!(reversed.y, infinite.y);
+}
+
+#[test]
+fn test_off_axis() {
+    // a symmetric frustum matches the field of view form
+    let symmetric = Perspective::from_angles(deg(-30.0f64), deg(30.0), deg(-30.0), deg(30.0), 0.1, 100.0);
+    assert!(Matrix4::from(symmetric).approx_eq(&perspective(deg(60.0f64), 1.0, 0.1, 100.0)));
+
+    // the right eye of a headset sees further to the right than the left
+    let eye = Perspective::from_tangents(-1.0f64, 1.5, -1.2, 1.0, 0.1, 100.0);
+    let m = eye.to_matrix(DepthRange::ZeroToOne);
+    let edge = |x: f64, y: f64| {
+        let clip = m * Vector4::new(x, y, -1.0, 1.0);
+        (clip.x / clip.w, clip.y / clip.w)
+    };
+    let (x, _) = edge(1.5, 0.0);
+    assert!(x.approx_eq(&1.0));
+    let (x, y) = edge(-1.0, -1.2);
+    assert!(x.approx_eq(&-1.0));
+    assert!(y.approx_eq(&-1.0));
+    let (_, y) = edge(0.0, 1.0);
+    assert!(y.approx_eq(&1.0));
+}
+
+#[test]
+fn test_viewport() {
+    let view = Matrix4::look_at(Point3::new(1.0f64, 2.0, 5.0), Point3::new(0.0, 0.0, 0.0),
+                                Vector3::unit_y());
+    let proj = perspective(deg(60.0f64), 4.0 / 3.0, 0.1, 100.0);
+    let world = Point3::new(0.3, -0.7, 1.2);
+
+    // the composed matrices agree with `project`
+    let screen = viewport(10.0, 20.0, 800.0, 600.0, DepthRange::NegativeOneToOne) * proj * view;
+    let window = project(world, &view, &proj, [10.0, 20.0, 800.0, 600.0]);
+    assert!(Point3::from_homogeneous(screen * world.to_homogeneous()).approx_eq(&window));
+
+    for &depth in &[DepthRange::NegativeOneToOne, DepthRange::ZeroToOne, DepthRange::ReversedZeroToOne] {
+        let m = viewport(10.0f64, 20.0, 800.0, 600.0, depth);
+        assert!((viewport_inverse(10.0, 20.0, 800.0, 600.0, depth) * m).is_identity());
+        assert!((m * Vector4::new(-1.0, 1.0, 0.0, 1.0)).approx_eq(&Vector4::new(10.0, 620.0, m.w.z, 1.0)));
+    }
+}
+
+#[test]
+fn test_pick_matrix() {
+    let view = Matrix4::look_at(Point3::new(1.0f64, 2.0, 5.0), Point3::new(0.0, 0.0, 0.0),
+                                Vector3::unit_y());
+    let proj = perspective(deg(60.0f64), 4.0 / 3.0, 0.1, 100.0);
+    let vp = [10.0, 20.0, 800.0, 600.0];
+    let world = Point3::new(0.3, -0.7, 1.2);
+    let window = project(world, &view, &proj, vp);
+
+    // the picked point lands at the centre of the picked region, scaled up
+    let pick = pick_matrix(Point2::new(window.x - 2.0, window.y + 1.0), Vector2::new(10.0, 8.0), vp);
+    let picked = project(world, &view, &(pick * proj), vp);
+    assert!(picked.x.approx_eq(&(410.0 + 2.0 * 80.0)));
+    assert!(picked.y.approx_eq(&(320.0 - 1.0 * 75.0)));
+    assert!(picked.z.approx_eq(&window.z));
+
+    let everything = pick_matrix(Point2::new(410.0, 320.0), Vector2::new(800.0, 600.0), vp);
+    assert!(everything.is_identity());
+}
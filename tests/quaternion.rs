@@ -19,9 +19,10 @@ use cgmath::{Matrix4, Matrix3};
 use cgmath::Quaternion;
 
 use cgmath::{Rad, rad, ApproxEq};
+use cgmath::{Vector, Vector3};
 use cgmath::Rotation3;
 
-use std::f32;
+use std::{f32, f64};
 
 #[test]
 fn to_matrix4()
@@ -85,3 +86,252 @@ fn to_and_from_quaternion()
     eq((rad(0f32), rad(-hpi), rad(1f32)), xzy_nhp.to_euler());
 
 }
+
+#[test]
+fn from_matrix_large_angles() {
+    // these have a negative trace, and take the other branches
+    for &a in &[2.2f64, 2.5, 3.0, -3.0] {
+        for m in &[Matrix3::from_angle_x(rad(a)), Matrix3::from_angle_y(rad(a)), Matrix3::from_angle_z(rad(a))] {
+            let q = Quaternion::from(*m);
+            assert!(q.magnitude().approx_eq(&1.0));
+            assert!(Matrix3::from(q).approx_eq(m));
+        }
+    }
+}
+
+#[test]
+fn test_from_rotation_matrix() {
+    let q: Quaternion<f64> = Rotation3::from_axis_angle(Vector3::new(0.0, 0.6, 0.8), rad(2.5));
+    let m = Matrix3::from(q);
+    assert!(Quaternion::from_rotation_matrix(m).unwrap().rotation_eq(&q));
+    assert_eq!(Quaternion::from_rotation_matrix(m * 2.0), None);
+    assert_eq!(Quaternion::from_rotation_matrix(-m), None);
+}
+
+#[test]
+fn test_from_scaled_matrix() {
+    let q: Quaternion<f64> = Rotation3::from_axis_angle(Vector3::new(0.0, 0.6, 0.8), rad(2.5));
+    let scale = Matrix3::new(2.0, 0.0, 0.0, 0.0, 0.5, 0.0, 0.0, 0.0, 3.0);
+    let (r, s) = Quaternion::from_scaled_matrix(Matrix3::from(q) * scale).unwrap();
+    assert!(r.rotation_eq(&q));
+    assert!(s.approx_eq(&Vector3::new(2.0, 0.5, 3.0)));
+
+    // a reflection negates the scale
+    let (r, s) = Quaternion::from_scaled_matrix(Matrix3::from(q) * -scale).unwrap();
+    assert!(r.rotation_eq(&q));
+    assert!(s.approx_eq(&Vector3::new(-2.0, -0.5, -3.0)));
+
+    let shear = Matrix3::new(1.0, 0.0, 0.0, 0.5, 1.0, 0.0, 0.0, 0.0, 1.0);
+    assert_eq!(Quaternion::from_scaled_matrix(Matrix3::from(q) * shear), None);
+    assert_eq!(Quaternion::from_scaled_matrix(Matrix3::from(q) * 0.0), None);
+
+    // small scales are fine
+    let (r, s) = Quaternion::from_scaled_matrix(Matrix3::from(q) * (scale * 1e-6)).unwrap();
+    assert!(r.rotation_eq(&q));
+    assert!((s * 1e6).approx_eq(&Vector3::new(2.0, 0.5, 3.0)));
+}
+
+#[test]
+fn test_ln_exp() {
+    let q: Quaternion<f64> = Rotation3::from_axis_angle(Vector3::new(0.0, 0.6, 0.8), rad(1.2));
+    let ln = q.ln();
+    assert!(ln.approx_eq(&Quaternion::new(0.0, 0.0, 0.36, 0.48)));
+    assert!(ln.exp().approx_eq(&q));
+    assert!(Quaternion::<f64>::one().ln().approx_eq(&Quaternion::zero()));
+}
+
+#[test]
+fn test_angle() {
+    let axis = Vector3::new(0.0, 0.6, 0.8);
+    let a: Quaternion<f64> = Rotation3::from_axis_angle(axis, rad(0.3));
+    let b: Quaternion<f64> = Rotation3::from_axis_angle(axis, rad(1.5));
+    assert!(a.angle(b).approx_eq(&rad(1.2)));
+    assert!(b.angle(a).approx_eq(&rad(1.2)));
+    // negated quaternions are the same rotation
+    assert!(a.angle(-b).approx_eq(&rad(1.2)));
+    assert!(a.angle(b * 3.0).approx_eq(&rad(1.2)));
+    assert_eq!(a.angle(a), rad(0.0));
+
+    let tiny: Quaternion<f64> = Rotation3::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), rad(1e-9));
+    assert!((Quaternion::one().angle(tiny).s - 1e-9).abs() < 1e-20);
+
+    let half_turn: Quaternion<f64> = Rotation3::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), rad(3.0));
+    let other: Quaternion<f64> = Rotation3::from_axis_angle(Vector3::new(-1.0, 0.0, 0.0), rad(3.0));
+    assert!(half_turn.angle(other).approx_eq(&rad(2.0 * ::std::f64::consts::PI - 6.0)));
+}
+
+#[test]
+fn test_rotate_towards() {
+    let a = Quaternion::from_angle_z(rad(0.0f64));
+    let b = Quaternion::from_angle_z(rad(1.0f64));
+    assert!(a.rotate_towards(b, rad(0.25)).approx_eq(&Quaternion::from_angle_z(rad(0.25))));
+    assert_eq!(a.rotate_towards(b, rad(1.5)), b);
+
+    // takes the shorter way round, even if `target` is in the other hemisphere
+    assert!(a.rotate_towards(-b, rad(0.25)).approx_eq(&Quaternion::from_angle_z(rad(0.25))));
+}
+
+#[test]
+fn test_clamp_angle() {
+    let axis = Vector3::new(1.0f64, 2.0, 2.0) / 3.0;
+    let q = Quaternion::from_axis_angle(axis, rad(1.2));
+    assert!(q.clamp_angle(rad(0.5)).approx_eq(&Quaternion::from_axis_angle(axis, rad(0.5))));
+    assert_eq!(q.clamp_angle(rad(2.0)), q);
+    assert!((-q).clamp_angle(rad(0.5)).rotation_eq(&Quaternion::from_axis_angle(axis, rad(0.5))));
+
+    let reference = Quaternion::from_angle_x(rad(0.3f64));
+    let limited = q.clamp_angle_from(reference, rad(0.4));
+    assert!(limited.angle(reference).approx_eq(&rad(0.4)));
+    assert!((limited.angle(q) + rad(0.4)).approx_eq(&reference.angle(q)));
+    assert!(reference.clamp_angle_from(q, rad(0.1)).angle(q).approx_eq(&rad(0.1)));
+}
+
+#[test]
+fn test_swing_twist() {
+    let swing = Quaternion::from_angle_x(rad(0.6f64));
+    let twist = Quaternion::from_angle_z(rad(1.1f64));
+    let (s, t) = (swing * twist).swing_twist(Vector3::unit_z());
+    assert!(s.approx_eq(&swing));
+    assert!(t.approx_eq(&twist));
+
+    let q = Quaternion::from_axis_angle(Vector3::new(1.0f64, -2.0, 2.0) / 3.0, rad(2.0));
+    let axis = Vector3::new(0.0f64, 0.6, 0.8);
+    let (s, t) = q.swing_twist(axis);
+    assert!((s * t).approx_eq(&q));
+    assert!(t.v.cross(axis).approx_eq(&Vector3::zero()));
+    assert!(s.v.dot(axis).approx_eq(&0.0));
+
+    // a half turn perpendicular to the axis has no twist
+    let (s, t) = Quaternion::from_angle_x(rad(f64::consts::PI)).swing_twist(Vector3::unit_z());
+    assert!(s.approx_eq(&Quaternion::from_angle_x(rad(f64::consts::PI))));
+    assert_eq!(t, Quaternion::one());
+}
+
+#[test]
+fn test_integrate() {
+    let mut q = Quaternion::from_angle_x(rad(0.5f64));
+    for _ in 0..1000 {
+        q = q.integrate(Vector3::new(0.0, 0.0, 2.0), 0.0005);
+    }
+    // the angular velocity is in world space, so it is applied on the left
+    let expected = Quaternion::from_angle_z(rad(1.0f64)) * Quaternion::from_angle_x(rad(0.5f64));
+    assert!(q.approx_eq_eps(&expected, &1e-3));
+    assert!(q.magnitude().approx_eq(&1.0));
+}
+
+#[test]
+fn test_angular_velocity_to() {
+    let a = Quaternion::from_angle_x(rad(0.5f64));
+    let b = Quaternion::from_angle_y(rad(0.8f64)) * a;
+    let w = a.angular_velocity_to(b, 0.5);
+    assert!(w.approx_eq(&Vector3::new(0.0, 1.6, 0.0)));
+    assert!(a.angular_velocity_to(-b, 0.5).approx_eq(&w));
+    assert!(a.angular_velocity_to(a, 0.5).approx_eq(&Vector3::zero()));
+
+    let mut q = a;
+    for _ in 0..1000 {
+        q = q.integrate(w, 0.0005);
+    }
+    assert!(q.approx_eq_eps(&b, &1e-3));
+}
+
+#[test]
+fn test_non_unit() {
+    let q = Quaternion::new(1.0f64, 2.0, -2.0, 4.0);
+    assert_eq!(q.length2(), 25.0);
+    assert_eq!(q.length(), 5.0);
+    assert_eq!(q.dot(Quaternion::new(1.0, 1.0, 1.0, 1.0)), 5.0);
+    assert_eq!(q.conjugate(), Quaternion::new(1.0, -2.0, 2.0, -4.0));
+    assert!(q.normalize().approx_eq(&Quaternion::new(0.2, 0.4, -0.4, 0.8)));
+
+    let inv = q.inverse();
+    assert!(inv.approx_eq(&Quaternion::new(0.04, -0.08, 0.08, -0.16)));
+    assert!((q * inv).approx_eq(&Quaternion::one()));
+    assert!((inv * q).approx_eq(&Quaternion::one()));
+}
+
+#[test]
+fn test_rotate_vector() {
+    let q = Quaternion::from_axis_angle(Vector3::new(2.0f64, -1.0, 2.0) / 3.0, rad(1.3));
+    let v = Vector3::new(0.5f64, 3.0, -1.0);
+    assert!(q.rotate_vector(v).approx_eq(&(Matrix3::from(q) * v)));
+    assert!(Quaternion::from_angle_z(rad(f64::consts::FRAC_PI_2)).rotate_vector(Vector3::unit_x())
+                .approx_eq(&Vector3::unit_y()));
+    assert_eq!(q * v, q.rotate_vector(v));
+}
+
+#[test]
+fn test_rotation_eq() {
+    let q = Quaternion::from_angle_y(rad(1.0f64));
+    assert!(q.rotation_eq(&-q));
+    assert!(!q.approx_eq(&-q));
+    assert!(q.rotation_eq(&Quaternion::from_angle_y(rad(1.0 - 2.0 * f64::consts::PI))));
+    assert!(!q.rotation_eq(&Quaternion::from_angle_y(rad(1.1))));
+    assert!(q.rotation_eq_eps(&-Quaternion::from_angle_y(rad(1.1)), &0.1));
+}
+
+#[test]
+fn test_predicates() {
+    assert!(Quaternion::<f64>::zero().is_zero());
+    assert!(!Quaternion::<f64>::one().is_zero());
+    assert!(Quaternion::<f64>::one().is_normalized());
+    assert!(!(Quaternion::<f64>::one() * 1.01).is_normalized());
+    assert!(Quaternion::new(0.5f64, 0.5, 0.5, 0.5).is_finite());
+    assert!(!Quaternion::new(0.5f32, f32::NAN, 0.5, 0.5).is_finite());
+}
+
+#[test]
+fn test_squad() {
+    let axis = Vector3::new(0.0f64, 0.0, 1.0);
+    let keys: Vec<Quaternion<f64>> = (0..4).map(|i| Rotation3::from_axis_angle(axis, rad(0.5 * i as f64))).collect();
+    let controls = Quaternion::squad_control_points(&keys);
+    assert_eq!(controls.len(), keys.len());
+
+    // With a constant angular velocity, the control points coincide with the
+    // keyframes and squad reduces to slerp
+    for (control, key) in controls.iter().zip(keys.iter()) {
+        assert!(control.approx_eq(key));
+    }
+    for &t in [0.0, 0.25, 0.5, 1.0].iter() {
+        let squad = keys[1].squad(keys[2], controls[1], controls[2], t);
+        assert!(squad.approx_eq(&keys[1].slerp(keys[2], t)));
+    }
+
+    // The curve passes through the keyframes
+    let keys: Vec<Quaternion<f64>> = vec![Quaternion::one(),
+                                          Rotation3::from_axis_angle(axis, rad(0.5)),
+                                          Rotation3::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), rad(0.5)),
+                                          Rotation3::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), rad(0.3))];
+    let controls = Quaternion::squad_control_points(&keys);
+    for i in 0..3 {
+        assert!(keys[i].squad(keys[i + 1], controls[i], controls[i + 1], 0.0).approx_eq(&keys[i]));
+        assert!(keys[i].squad(keys[i + 1], controls[i], controls[i + 1], 1.0).approx_eq(&keys[i + 1]));
+    }
+}
+
+#[test]
+fn test_precise_slerp() {
+    // halfway between the identity and a half turn about z is a quarter turn
+    let a = Quaternion::new(1.0f32, 0.0, 0.0, 0.0);
+    let b = Quaternion::new(0.0f32, 0.0, 0.0, 1.0);
+    let half = 0.5f32.sqrt();
+    assert_eq!(a.precise_slerp(b, 0.5), Quaternion::new(half, 0.0, 0.0, half));
+}
+
+#[test]
+fn test_precise_mul() {
+    // as for matrices, the products only cancel exactly in f64
+    let a = Quaternion::new(8193.0f32, 0.0, 0.0, 8192.0);
+    let b = Quaternion::new(8192.125f32, 0.0, 0.0, 8192.0);
+    assert_eq!(a.precise_mul(b).s, 9216.125);
+    assert_eq!((a * b).s, 9216.0);
+}
+
+#[test]
+fn test_exact_eq() {
+    let a = Quaternion::new(1.0f64, 0.0, 0.0, 0.0);
+    let b = Quaternion::new(1.0f64, 1e-9, 0.0, 0.0);
+    assert!(a == a);
+    assert!(a != b);
+    assert!(a.approx_eq(&b));
+}
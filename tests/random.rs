@@ -0,0 +1,79 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+extern crate rand;
+
+use cgmath::*;
+use rand::{SeedableRng, XorShiftRng};
+
+const SAMPLES: usize = 20000;
+
+fn rng() -> XorShiftRng {
+    XorShiftRng::from_seed([1, 2, 3, 4])
+}
+
+#[test]
+fn test_random_unit() {
+    let mut rng = rng();
+    let mut mean = Vector3::zero();
+    for _ in 0..SAMPLES {
+        let v = Vector3::<f64>::random_unit(&mut rng);
+        assert!(v.is_normalized());
+        mean = mean + v;
+    }
+    assert!((mean / SAMPLES as f64).length() < 0.02);
+
+    for _ in 0..100 {
+        assert!(Vector2::<f32>::random_unit(&mut rng).is_normalized());
+    }
+}
+
+#[test]
+fn test_random_in_unit_ball() {
+    let mut rng = rng();
+    // a uniform distribution puts 1/8 of the samples inside radius 1/2
+    let mut inner = 0;
+    for _ in 0..SAMPLES {
+        let v = Vector3::<f64>::random_in_unit_ball(&mut rng);
+        assert!(v.length() <= 1.0);
+        if v.length() < 0.5 { inner += 1; }
+    }
+    assert!(((inner as f64 / SAMPLES as f64) - 0.125).abs() < 0.01);
+
+    // and 1/4 of them for a disk
+    let mut inner = 0;
+    for _ in 0..SAMPLES {
+        let v = Vector2::<f64>::random_in_unit_disk(&mut rng);
+        assert!(v.length() <= 1.0);
+        if v.length() < 0.5 { inner += 1; }
+    }
+    assert!(((inner as f64 / SAMPLES as f64) - 0.25).abs() < 0.01);
+}
+
+#[test]
+fn test_random_rotation() {
+    let mut rng = rng();
+    // the rotation angles of uniform rotations have the density (1 - cos θ) / π
+    // so on average 1/2 + 1/π of them are larger than π/2
+    let mut large = 0;
+    for _ in 0..SAMPLES {
+        let q = Quaternion::<f64>::random_rotation(&mut rng);
+        assert!(q.is_normalized());
+        if q.angle(Quaternion::one()) > rad(::std::f64::consts::FRAC_PI_2) { large += 1; }
+    }
+    let expected = 0.5 + 1.0 / ::std::f64::consts::PI;
+    assert!(((large as f64 / SAMPLES as f64) - expected).abs() < 0.01);
+}
@@ -0,0 +1,116 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::{ApproxEq, Plane, Point3, Ray, Sphere, Vector3};
+use std::{f64, i64};
+
+#[test]
+fn test_voxels_axis_aligned() {
+    let ray = Ray::new(Point3::new(0.5f64, 0.5, 0.5), Vector3::new(1.0, 0.0, 0.0));
+    let cells: Vec<_> = ray.voxels(1.0).take(3).collect();
+    assert_eq!(cells, vec![(Vector3::new(0, 0, 0), 0.0),
+                           (Vector3::new(1, 0, 0), 0.5),
+                           (Vector3::new(2, 0, 0), 1.5)]);
+}
+
+#[test]
+fn test_voxels_diagonal() {
+    // Alternately crosses the y and x boundaries of the cells
+    let ray = Ray::new(Point3::new(0.45f64, 0.2, 0.6), Vector3::new(-2.0, -2.0, 0.0));
+    let cells: Vec<_> = ray.voxels(0.5).take_while(|&(_, t)| t < 0.5).map(|(c, _)| c).collect();
+    assert_eq!(cells, vec![Vector3::new(0, 0, 1), Vector3::new(0, -1, 1),
+                           Vector3::new(-1, -1, 1), Vector3::new(-1, -2, 1),
+                           Vector3::new(-2, -2, 1)]);
+}
+
+#[test]
+fn test_voxels_no_direction() {
+    let ray = Ray::new(Point3::new(-0.5f64, 1.5, 2.5), Vector3::new(0.0, 0.0, 0.0));
+    let cells: Vec<_> = ray.voxels(1.0).collect();
+    assert_eq!(cells, vec![(Vector3::new(-1, 1, 2), 0.0)]);
+}
+
+#[test]
+fn test_voxels_on_boundary() {
+    // the origin is on the boundary of the cells in x and y, but each cell is
+    // only visited once
+    let ray = Ray::new(Point3::new(1.0f64, 2.0, 0.5), Vector3::new(-2.0, 1.0, 0.0));
+    let cells: Vec<_> = ray.voxels(1.0).take(3).collect();
+    assert_eq!(cells, vec![(Vector3::new(0, 2, 0), 0.0),
+                           (Vector3::new(-1, 2, 0), 0.5),
+                           (Vector3::new(-1, 3, 0), 1.0)]);
+}
+
+#[test]
+fn test_voxels_non_finite() {
+    let ray = Ray::new(Point3::new(f64::NAN, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    assert_eq!(ray.voxels(1.0).next(), None);
+    let ray = Ray::new(Point3::new(0.0f64, 0.0, 0.0), Vector3::new(1.0, f64::INFINITY, 0.0));
+    assert_eq!(ray.voxels(1.0).next(), None);
+    let ray = Ray::new(Point3::new(0.0f64, 1e300, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    assert_eq!(ray.voxels(1.0).next(), None);
+    let ray = Ray::new(Point3::new(0.0f64, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    assert_eq!(ray.voxels(0.0).next(), None);
+
+    // the traversal ends at the last cell in the range of `i64`
+    let ray = Ray::new(Point3::new(9223372036854774784.0f64, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    let cells: Vec<_> = ray.voxels(1.0).collect();
+    assert_eq!(cells.len(), 1024);
+    assert_eq!(cells.last().unwrap().0.x, i64::MAX);
+}
+
+#[test]
+fn test_intersect_plane() {
+    let plane = Plane::from_point_normal(Point3::new(0.0f64, 0.0, 2.0), Vector3::new(0.0, 0.0, 1.0));
+    let ray = Ray::new(Point3::new(1.0f64, 1.0, 0.0), Vector3::new(0.0, 1.0, 1.0));
+    let (t, point) = ray.intersect_plane(&plane).unwrap();
+    assert!(t.approx_eq(&2.0));
+    assert!(point.approx_eq(&Point3::new(1.0, 3.0, 2.0)));
+
+    // the ray hits the plane from either side
+    let back = Ray::new(Point3::new(1.0f64, 1.0, 5.0), Vector3::new(0.0, 0.0, -2.0));
+    assert!(back.intersect_plane(&plane).unwrap().0.approx_eq(&1.5));
+
+    let parallel = Ray::new(Point3::new(0.0f64, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    assert!(parallel.intersect_plane(&plane).is_none());
+    let away = Ray::new(Point3::new(0.0f64, 0.0, 0.0), Vector3::new(0.0, 0.0, -1.0));
+    assert!(away.intersect_plane(&plane).is_none());
+
+    // the parallel test doesn't depend on the length of the direction
+    let short = Ray::new(Point3::new(1.0f64, 1.0, 0.0), Vector3::new(0.0, 1e-6, 1e-6));
+    assert!(short.intersect_plane(&plane).unwrap().1.approx_eq(&point));
+    let nearly_parallel = Ray::new(Point3::new(0.0f64, 0.0, 0.0), Vector3::new(1e6, 0.0, 1e-4));
+    assert!(nearly_parallel.intersect_plane(&plane).is_none());
+}
+
+#[test]
+fn test_intersect_sphere() {
+    let sphere = Sphere::new(Point3::new(0.0f64, 0.0, 5.0), 1.0);
+    let ray = Ray::new(Point3::new(0.0f64, 0.0, 0.0), Vector3::new(0.0, 0.0, 2.0));
+    let (t, point) = ray.intersect_sphere(&sphere).unwrap();
+    assert!(t.approx_eq(&2.0));
+    assert!(point.approx_eq(&Point3::new(0.0, 0.0, 4.0)));
+
+    // from inside, the ray hits the far side
+    let inside = Ray::new(Point3::new(0.0f64, 0.0, 5.5), Vector3::new(0.0, 0.0, 1.0));
+    assert!(inside.intersect_sphere(&sphere).unwrap().0.approx_eq(&0.5));
+
+    let miss = Ray::new(Point3::new(0.0f64, 1.5, 0.0), Vector3::new(0.0, 0.0, 1.0));
+    assert!(miss.intersect_sphere(&sphere).is_none());
+    let behind = Ray::new(Point3::new(0.0f64, 0.0, 7.0), Vector3::new(0.0, 0.0, 1.0));
+    assert!(behind.intersect_sphere(&sphere).is_none());
+}
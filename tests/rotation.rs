@@ -45,3 +45,21 @@ fn test_invert_basis3() {
     let a: &Matrix3<_> = a.as_ref();
     assert!(a.is_identity());
 }
+
+#[test]
+fn test_accumulated_rotation() {
+    let delta = Quaternion::from_axis_angle(Vector3::new(0.3f32, 1.0, -0.2).normalize(), rad(0.001));
+    let mut naive = Quaternion::one();
+    let mut acc = AccumulatedRotation::new(16);
+    for _ in 0..100000 {
+        naive = delta * naive;
+        acc.rotate(delta);
+    }
+    assert!((acc.quaternion().magnitude() - 1.0).abs() < 1e-6);
+    assert!((acc.quaternion().magnitude() - 1.0).abs() < (naive.magnitude() - 1.0).abs());
+
+    let mut local = AccumulatedRotation::from_rotation(Quaternion::from_angle_z(rad(1.0f64)), 1);
+    local.rotate_local(Quaternion::from_angle_x(rad(0.5)));
+    let expected = Matrix3::from_angle_z(rad(1.0)) * Matrix3::from_angle_x(rad(0.5));
+    assert!(local.matrix().approx_eq(&expected));
+}
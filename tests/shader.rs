@@ -0,0 +1,38 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::{Matrix2, Vector3};
+use cgmath::ShaderLiteral;
+
+#[test]
+fn test_vector_literal() {
+    let v = Vector3::new(1.0f32, -0.5, 2.0);
+    assert_eq!(v.to_glsl_literal(), "vec3(1.0, -0.5, 2.0)");
+    assert_eq!(v.to_hlsl_literal(), "float3(1.0, -0.5, 2.0)");
+
+    let v = Vector3::new(1.0f64, 0.25, 3.0);
+    assert_eq!(v.to_glsl_literal(), "dvec3(1.0lf, 0.25lf, 3.0lf)");
+    assert_eq!(v.to_hlsl_literal(), "double3(1.0L, 0.25L, 3.0L)");
+}
+
+#[test]
+fn test_matrix_literal() {
+    let m = Matrix2::new(1.0f32, 2.0,
+                         3.0, 4.0);
+    assert_eq!(m.to_glsl_literal(), "mat2(1.0, 2.0, 3.0, 4.0)");
+    assert_eq!(m.to_hlsl_literal(), "float2x2(1.0, 3.0, 2.0, 4.0)");
+}
@@ -0,0 +1,71 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::{SphericalHarmonicsL1, SphericalHarmonicsL2};
+use cgmath::{Vector3, ApproxEq};
+use std::f64;
+
+/// Evenly distributed directions on a Fibonacci spiral.
+fn sphere_directions(n: usize) -> Vec<Vector3<f64>> {
+    let golden_angle = f64::consts::PI * (3.0 - 5.0f64.sqrt());
+    (0..n).map(|i| {
+        let z = 1.0 - (2 * i + 1) as f64 / n as f64;
+        let r = (1.0 - z * z).sqrt();
+        let phi = golden_angle * i as f64;
+        Vector3::new(r * phi.cos(), r * phi.sin(), z)
+    }).collect()
+}
+
+#[test]
+fn test_project_constant() {
+    let samples: Vec<_> = sphere_directions(1000).into_iter().map(|d| (d, 1.0)).collect();
+    let sh = SphericalHarmonicsL2::project(&samples);
+    assert!(sh.coefficients[0].approx_eq_eps(&(4.0 * f64::consts::PI).sqrt(), &1e-3));
+    for &c in sh.coefficients[1..].iter() {
+        assert!(c.approx_eq_eps(&0.0, &1e-3));
+    }
+    assert!(sh.evaluate(Vector3::unit_y()).approx_eq_eps(&1.0, &1e-3));
+}
+
+#[test]
+fn test_project_linear() {
+    // f(d) = d.x + 2 d.z lies entirely in the first band
+    let f = |d: Vector3<f64>| d.x + 2.0 * d.z;
+    let samples: Vec<_> = sphere_directions(2000).into_iter().map(|d| (d, f(d))).collect();
+    let sh = SphericalHarmonicsL2::project(&samples);
+    for &d in sphere_directions(10).iter() {
+        assert!(sh.evaluate(d).approx_eq_eps(&f(d), &1e-2));
+        assert!(sh.truncate().evaluate(d).approx_eq_eps(&f(d), &1e-2));
+    }
+}
+
+#[test]
+fn test_dot() {
+    // The basis functions are orthonormal, so the integral of a product of
+    // projected functions is the dot product of their coefficients
+    let directions = sphere_directions(4000);
+    let a: Vec<_> = directions.iter().map(|&d| (d, d.x * d.y + 1.0)).collect();
+    let b: Vec<_> = directions.iter().map(|&d| (d, d.y * d.x)).collect();
+    let product = directions.iter().fold(0.0, |acc, &d| acc + (d.x * d.y + 1.0) * d.y * d.x)
+        * 4.0 * f64::consts::PI / directions.len() as f64;
+    let dot = SphericalHarmonicsL2::project(&a).dot(&SphericalHarmonicsL2::project(&b));
+    assert!(dot.approx_eq_eps(&product, &1e-2));
+
+    let l1 = SphericalHarmonicsL1::basis(Vector3::<f64>::unit_z());
+    assert!(l1.dot(&l1).approx_eq(&(0.282094791773878 * 0.282094791773878 +
+                                    0.488602511902920 * 0.488602511902920)));
+}
@@ -17,6 +17,7 @@
 extern crate cgmath;
 
 use cgmath::*;
+use std::f64;
 
 #[test]
 fn test_invert() {
@@ -41,3 +42,104 @@ fn test_look_at() {
 	let view_point = Point3::new(0.0f64, 1.0, 5.0);
 	assert!(t.transform_point(point).approx_eq(&view_point));
 }
+
+#[test]
+fn test_transform_builder() {
+    let t = Vector3::new(1.0f64, -2.0, 0.5);
+    let r = Quaternion::from_angle_y(rad(0.7));
+    let m = TransformBuilder::new().scale(2.0).rotate(r).translate(t).build();
+    let expected = Matrix4::from_translation(t) * Matrix4::from(r) * Matrix4::from_scale(2.0);
+    assert!(m.approx_eq(&expected));
+
+    // Translating first moves the origin of the rotation
+    let p = Point3::new(0.0, 0.0, 0.0);
+    let m = TransformBuilder::new().translate(Vector3::unit_x())
+                                   .rotate(Matrix3::from_angle_z(rad(f64::consts::FRAC_PI_2)))
+                                   .nonuniform_scale(1.0, 3.0, 1.0)
+                                   .build_affine();
+    assert!(m.transform_point(p).approx_eq(&Point3::new(0.0, 3.0, 0.0)));
+}
+
+#[test]
+fn test_decomposed2() {
+    let t = Decomposed2::new(Vector2::new(3.0f64, -1.0), rad(f64::consts::FRAC_PI_2), Vector2::new(2.0, 0.5));
+    let p = Point2::new(1.0f64, 2.0);
+    // scaled to (2, 1), rotated to (-1, 2), then translated
+    assert!(t.transform_point(p).approx_eq(&Point2::new(2.0, 1.0)));
+    assert!(t.transform_vector(Vector2::new(1.0, 2.0)).approx_eq(&Vector2::new(-1.0, 2.0)));
+
+    let m = Matrix3::from(t);
+    assert!(Point2::from_vec((m * p.to_vec().extend(1.0)).truncate()).approx_eq(&t.transform_point(p)));
+    assert!(Decomposed2::from_matrix(m).approx_eq(&t));
+
+    let ti = t.invert().unwrap();
+    assert!(ti.transform_point(t.transform_point(p)).approx_eq(&p));
+
+    // no rotation in `u`, so the composition carries no shear
+    let u = Decomposed2::new(Vector2::new(0.5f64, 0.5), rad(0.0), Vector2::new(1.5, 3.0));
+    assert!(t.concat(&u).transform_point(p).approx_eq(&t.transform_point(u.transform_point(p))));
+
+    let v: Decomposed2<f64> = Transform::look_at(Point2::new(1.0, 1.0), Point2::new(2.0, 2.0), Vector2::unit_y());
+    assert!(v.transform_point(Point2::new(1.0, 1.0)).approx_eq(&Point2::new(0.0, 0.0)));
+    assert!(v.transform_point(Point2::new(2.0, 2.0)).approx_eq(&Point2::new(0.0, 2.0f64.sqrt())));
+}
+
+fn round_trip<T: Transform3<f64>>(t: T, p: Point3<f64>) -> Point3<f64> {
+    t.invert().unwrap().transform_point(t.transform_point(p))
+}
+
+#[test]
+fn test_transform_representations() {
+    let rot = Quaternion::from_axis_angle(Vector3::new(1.0f64, 2.0, 3.0).normalize(), rad(0.7));
+    let disp = Vector3::new(1.0f64, -2.0, 0.5);
+    let p = Point3::new(3.0f64, 1.0, -2.0);
+
+    let dec = Decomposed { scale: 1.0f64, rot: rot, disp: disp };
+    let iso = Isometry3::new(rot, disp);
+    let mat = Matrix4::from(dec);
+    let aff = AffineMatrix3 { mat: mat };
+    assert!(iso.transform_point(p).approx_eq(&dec.transform_point(p)));
+    assert!(mat.transform_point(p).approx_eq(&dec.transform_point(p)));
+    assert!(aff.transform_point(p).approx_eq(&dec.transform_point(p)));
+    assert!(Transform::transform_point(&rot, p).approx_eq(&rot.rotate_point(p)));
+
+    assert!(round_trip(dec, p).approx_eq(&p));
+    assert!(round_trip(iso, p).approx_eq(&p));
+    assert!(round_trip(mat, p).approx_eq(&p));
+    assert!(round_trip(aff, p).approx_eq(&p));
+    assert!(round_trip(rot, p).approx_eq(&p));
+    assert!(Transform::invert(&Quaternion::<f64>::zero()).is_none());
+
+    let m = AffineMatrix2 { mat: Matrix3::from_cols(Vector3::new(0.0f64, 1.0, 0.0),
+                                                    Vector3::new(-1.0, 0.0, 0.0),
+                                                    Vector3::new(2.0, 3.0, 1.0)) };
+    assert!(m.transform_point(Point2::new(1.0, 0.0)).approx_eq(&Point2::new(2.0, 4.0)));
+    assert!(m.transform_vector(Vector2::new(1.0, 0.0)).approx_eq(&Vector2::new(0.0, 1.0)));
+    assert!(m.invert().unwrap().transform_point(Point2::new(2.0, 4.0)).approx_eq(&Point2::new(1.0, 0.0)));
+    let v: AffineMatrix2<f64> = Transform::look_at(Point2::new(1.0, 1.0), Point2::new(2.0, 2.0), Vector2::unit_y());
+    assert!(v.transform_point(Point2::new(2.0, 2.0)).approx_eq(&Point2::new(0.0, 2.0f64.sqrt())));
+}
+
+#[test]
+fn test_interpolate() {
+    let a = Decomposed { scale: 1.0f64, rot: Quaternion::from_angle_z(rad(0.0)), disp: Vector3::new(0.0, 0.0, 0.0) };
+    let b = Decomposed { scale: 2.0f64, rot: Quaternion::from_angle_z(rad(f64::consts::FRAC_PI_2)), disp: Vector3::new(2.0, 4.0, -2.0) };
+    let c = a.interpolate(&b, 0.5);
+    assert!(c.scale.approx_eq(&1.5));
+    assert!(c.rot.approx_eq(&Quaternion::from_angle_z(rad(f64::consts::FRAC_PI_4))));
+    assert!(c.disp.approx_eq(&Vector3::new(1.0, 2.0, -1.0)));
+
+    // the same rotation in the opposite hemisphere takes the short way round
+    let d = Decomposed { rot: -b.rot, ..b };
+    assert!(a.interpolate(&d, 0.5).rot.approx_eq(&c.rot));
+
+    let ma = Matrix4::from(a);
+    let mb = Matrix4::from(b);
+    assert!(ma.interpolate_affine(&mb, 0.5).unwrap().approx_eq(&Matrix4::from(c)));
+    assert!(ma.interpolate_affine(&mb, 0.0).unwrap().approx_eq(&ma));
+    assert!(ma.interpolate_affine(&mb, 1.0).unwrap().approx_eq(&mb));
+
+    let mirror = Matrix4::from_nonuniform_scale(-1.0f64, 2.0, 1.0);
+    assert!(mirror.interpolate_affine(&mirror, 0.5).unwrap().approx_eq(&mirror));
+    assert!(ma.interpolate_affine(&Matrix4::from_scale(0.0), 0.5).is_none());
+}
@@ -0,0 +1,114 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_barycentric() {
+    let a = Point3::new(1.0f64, 0.0, 0.0);
+    let b = Point3::new(0.0f64, 2.0, 0.0);
+    let c = Point3::new(0.0f64, 0.0, 3.0);
+    assert!(barycentric(a, a, b, c).unwrap().approx_eq(&Vector3::new(1.0, 0.0, 0.0)));
+    assert!(barycentric(c, a, b, c).unwrap().approx_eq(&Vector3::new(0.0, 0.0, 1.0)));
+
+    let p = Point3::new(0.2f64, 0.8, 1.2);
+    let bary = barycentric(p, a, b, c).unwrap();
+    assert!(bary.sum().approx_eq(&1.0));
+    assert!(Point3::from_vec(barycentric_interpolate(bary, a.to_vec(), b.to_vec(), c.to_vec())).approx_eq(&p));
+
+    // outside the triangle some coordinates are negative
+    let bary = barycentric(Point2::new(2.0f64, 2.0), Point2::new(0.0, 0.0),
+                           Point2::new(1.0, 0.0), Point2::new(0.0, 1.0)).unwrap();
+    assert!(bary.approx_eq(&Vector3::new(-3.0, 2.0, 2.0)));
+
+    assert!(barycentric(p, a, a, c).is_none());
+    assert!(barycentric(p, a, b, a + (b - a) * 2.0).is_none());
+
+    // the degeneracy test doesn't depend on the size of the triangle
+    let bary = barycentric(Point2::new(0.01f64, 0.02), Point2::new(0.0, 0.0),
+                           Point2::new(0.05, 0.0), Point2::new(0.0, 0.05)).unwrap();
+    assert!(bary.approx_eq(&Vector3::new(0.4, 0.2, 0.4)));
+    assert!(barycentric(Point2::new(0.0f64, 0.0), Point2::new(0.0, 0.0),
+                        Point2::new(1000.0, 0.0), Point2::new(2000.0, 1e-9)).is_none());
+
+    // thin triangles are fine, however their vertices are labelled
+    let (a, b, c) = (Point2::new(0.0f64, 0.0), Point2::new(1.0, 0.0), Point2::new(1000.0, 1.0));
+    let centroid = Point2::new(1001.0f64 / 3.0, 1.0 / 3.0);
+    for &(a, b, c) in &[(a, b, c), (b, c, a), (c, a, b)] {
+        let bary = barycentric(centroid, a, b, c).unwrap();
+        assert!(bary.approx_eq_eps(&Vector3::from_value(1.0 / 3.0), &1e-3));
+    }
+}
+
+#[test]
+fn test_barycentric_interpolate() {
+    let bary = Vector3::new(0.5f64, 0.25, 0.25);
+    assert_eq!(barycentric_interpolate(bary, 4.0, 8.0, 0.0), 4.0);
+    assert_eq!(barycentric_interpolate(bary, Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0)),
+               Vector2::new(0.25, 0.25));
+}
+
+fn triangle() -> Triangle3<f64> {
+    Triangle3::new(Point3::new(0.0, 0.0, 1.0), Point3::new(2.0, 0.0, 1.0), Point3::new(0.0, 2.0, 1.0))
+}
+
+#[test]
+fn test_triangle_properties() {
+    let t = triangle();
+    assert!(t.normal().approx_eq(&Vector3::unit_z()));
+    assert!(t.area().approx_eq(&2.0));
+    assert!(t.centroid().approx_eq(&Point3::new(2.0 / 3.0, 2.0 / 3.0, 1.0)));
+
+    let flipped = Triangle3::new(t.a, t.c, t.b);
+    assert!(flipped.normal().approx_eq(&-Vector3::unit_z()));
+}
+
+#[test]
+fn test_triangle_contains() {
+    let t = triangle();
+    assert!(t.contains(Point3::new(0.5, 0.5, 1.0)));
+    assert!(t.contains(Point3::new(1.0, 1.0, 1.0)));
+    assert!(t.contains(t.b));
+    assert!(!t.contains(Point3::new(1.5, 1.5, 1.0)));
+    assert!(!t.contains(Point3::new(0.5, 0.5, 1.1)));
+
+    let degenerate = Triangle3::new(t.a, t.b, t.b);
+    assert!(!degenerate.contains(t.a));
+
+    // the distance allowed from the plane scales with the triangle
+    let large = Triangle3::new(t.a * 1e4, t.b * 1e4, t.c * 1e4);
+    assert!(large.contains(Point3::new(5e3, 5e3, 1e4 + 1e-3)));
+    assert!(!large.contains(Point3::new(5e3, 5e3, 1.1e4)));
+    let small = Triangle3::new(t.a * 1e-4, t.b * 1e-4, t.c * 1e-4);
+    assert!(small.contains(Point3::new(5e-5, 5e-5, 1e-4)));
+    assert!(!small.contains(Point3::new(5e-5, 5e-5, 1e-4 + 5e-6)));
+}
+
+#[test]
+fn test_triangle_closest_point() {
+    let t = triangle();
+    // above the face
+    assert!(t.closest_point(Point3::new(0.5, 0.5, 4.0)).approx_eq(&Point3::new(0.5, 0.5, 1.0)));
+    // vertex regions
+    assert!(t.closest_point(Point3::new(-1.0, -1.0, 0.0)).approx_eq(&t.a));
+    assert!(t.closest_point(Point3::new(3.0, -1.0, 1.0)).approx_eq(&t.b));
+    assert!(t.closest_point(Point3::new(0.0, 5.0, 2.0)).approx_eq(&t.c));
+    // edge regions
+    assert!(t.closest_point(Point3::new(1.0, -1.0, 1.0)).approx_eq(&Point3::new(1.0, 0.0, 1.0)));
+    assert!(t.closest_point(Point3::new(-1.0, 1.0, 3.0)).approx_eq(&Point3::new(0.0, 1.0, 1.0)));
+    assert!(t.closest_point(Point3::new(2.0, 2.0, 1.0)).approx_eq(&Point3::new(1.0, 1.0, 1.0)));
+}
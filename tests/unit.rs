@@ -0,0 +1,70 @@
+// Copyright 2015 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate cgmath;
+extern crate rustc_serialize;
+
+use cgmath::{Unit, UnitVector3, UnitQuaternion};
+use cgmath::{Quaternion, Vector2, Vector3};
+use cgmath::{EuclideanVector, Rotation3};
+use cgmath::{rad, ApproxEq};
+use rustc_serialize::json;
+use std::f64;
+
+#[test]
+fn test_constructors() {
+    let v = Vector3::new(3.0f64, 0.0, 4.0);
+    assert!(UnitVector3::try_new(v).is_none());
+    let u = UnitVector3::new_normalize(v);
+    assert!(u.approx_eq(&Unit::new_unchecked(Vector3::new(0.6, 0.0, 0.8))));
+    assert_eq!(UnitVector3::try_new(*u), Some(u));
+    assert!(u.x.approx_eq(&0.6));
+    assert!(UnitQuaternion::try_new(Quaternion::new(1.0f64, 1.0, 0.0, 0.0)).is_none());
+}
+
+#[test]
+fn test_decode() {
+    let u: UnitVector3<f64> = json::decode(r#"{"value":{"x":0.0,"y":-1.0,"z":0.0}}"#).unwrap();
+    assert_eq!(*u, Vector3::new(0.0, -1.0, 0.0));
+    assert!(json::decode::<UnitVector3<f64>>(r#"{"value":{"x":3.0,"y":0.0,"z":4.0}}"#).is_err());
+    assert!(json::decode::<UnitQuaternion<f64>>(r#"{"value":{"s":1.0,"v":{"x":1.0,"y":0.0,"z":0.0}}}"#).is_err());
+}
+
+#[test]
+fn test_vector_ops() {
+    let x = Unit::new_unchecked(Vector2::new(1.0f64, 0.0));
+    let y = Unit::new_unchecked(Vector2::new(0.0f64, 1.0));
+    assert!(x.angle(y).approx_eq(&rad(f64::consts::FRAC_PI_2)));
+    assert!(y.reflect(Vector2::new(2.0, 3.0)).approx_eq(&Vector2::new(2.0, -3.0)));
+
+    let mid = x.slerp(y, 0.5);
+    assert!(mid.approx_eq(&Unit::new_unchecked(Vector2::new(1.0, 1.0).normalize())));
+}
+
+#[test]
+fn test_quaternion_ops() {
+    let axis = UnitVector3::new_normalize(Vector3::new(1.0f64, 2.0, -1.0));
+    let q = UnitQuaternion::from_axis_angle(axis, rad(1.2));
+    let (a, angle) = q.to_axis_angle();
+    assert!(a.approx_eq(&axis));
+    assert!(angle.approx_eq(&rad(1.2)));
+
+    assert!((q * q.invert()).approx_eq(&UnitQuaternion::identity()));
+    let v = Vector3::new(0.5, -1.0, 2.0);
+    assert!(q.rotate_vector(v).approx_eq(&(Quaternion::from_axis_angle(*axis, rad(1.2)) * v)));
+
+    let half = UnitQuaternion::identity().slerp(q, 0.5);
+    assert!(half.approx_eq(&UnitQuaternion::from_axis_angle(axis, rad(0.6))));
+}
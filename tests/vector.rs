@@ -13,12 +13,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-#[macro_use]
 extern crate cgmath;
 
 use cgmath::*;
+use std::cmp;
+use std::f32;
 use std::f64;
 
+#[test]
+fn test_constants() {
+    assert_eq!(Vector3::<u8>::zero(), Vector3::new(0, 0, 0));
+    assert_eq!(Vector4::<i32>::one(), Vector4::new(1, 1, 1, 1));
+    assert_eq!(Vector2::<f32>::unit_y(), Vector2::new(0.0, 1.0));
+    assert_eq!(Vector4::<f64>::unit_w(), Vector4::new(0.0, 0.0, 0.0, 1.0));
+
+    assert_eq!(Matrix3::<f64>::identity(), SquareMatrix::identity());
+    assert_eq!(Matrix4::<f32>::zero(), Matrix4::from_value(0.0));
+    let m = Matrix2::<i32>::identity();
+    assert_eq!((m.x, m.y), (Vector2::new(1, 0), Vector2::new(0, 1)));
+    assert_eq!(Quaternion::<f64>::identity(), Quaternion::new(1.0, 0.0, 0.0, 0.0));
+}
+
 #[test]
 fn test_constructor() {
     assert_eq!(vec2(1f32, 2f32), Vector2::new(1f32, 2f32));
@@ -84,6 +99,75 @@ fn test_max() {
     assert_eq!(Vector4::new(5.0f64, 6.0f64, 7.0f64, 8.0f64).max(), 8.0f64);
 }
 
+#[test]
+fn test_min_max_clamp() {
+    let a = Vector3::new(1.0f64, -2.0, 5.0);
+    let b = Vector3::new(0.5f64, 3.0, 5.5);
+    assert_eq!(a.min_v(b), Vector3::new(0.5, -2.0, 5.0));
+    assert_eq!(a.max_v(b), Vector3::new(1.0, 3.0, 5.5));
+    assert_eq!(Vector2::new(-3, 7).clamp(Vector2::new(0, 0), Vector2::new(5, 5)), Vector2::new(0, 5));
+    assert_eq!(Vector4::new(-0.5f32, 0.25, 1.5, 1.0).saturate(), Vector4::new(0.0, 0.25, 1.0, 1.0));
+
+    assert_eq!(clamp(1.5f64, 0.0, 1.0), 1.0);
+    assert_eq!(clamp(-7, -5, 5), -5);
+    assert_eq!(clamp(0.5f32, 0.0, 1.0), 0.5);
+}
+
+#[test]
+fn test_min_max_index() {
+    assert_eq!(Vector2::new(3, 1).min_index(), 1);
+    assert_eq!(Vector3::new(0.5f64, -2.0, 1.0).min_index(), 1);
+    assert_eq!(Vector3::new(0.5f64, -2.0, 1.0).max_index(), 2);
+    assert_eq!(Vector4::new(1, 4, 4, 0).max_index(), 1);
+    assert_eq!(Vector4::new(1, 1, 1, 1).min_index(), 0);
+}
+
+#[test]
+fn test_predicates() {
+    assert!(Vector3::new(0, 0, 0).is_zero());
+    assert!(!Vector2::new(0.0f64, 1e-300).is_zero());
+
+    assert!(Vector3::new(0.0f64, 0.6, 0.8).is_normalized());
+    assert!(!Vector2::new(1.0f64, 1.0).is_normalized());
+
+    assert!(Vector4::new(1.0f64, 2.0, 3.0, 4.0).is_finite());
+    assert!(!Vector3::new(1.0f64, f64::NAN, 3.0).is_finite());
+    assert!(!Vector2::new(f32::INFINITY, 0.0).is_finite());
+}
+
+#[test]
+fn test_lex_cmp() {
+    use std::cmp::Ordering;
+
+    assert_eq!(Vector2::new(1isize, 2isize).lex_cmp(&Vector2::new(1isize, 3isize)), Ordering::Less);
+    assert_eq!(Vector3::new(2isize, 0isize, 0isize).lex_cmp(&Vector3::new(1isize, 9isize, 9isize)), Ordering::Greater);
+    assert_eq!(Vector4::new(1isize, 2isize, 3isize, 4isize).lex_cmp(&Vector4::new(1isize, 2isize, 3isize, 4isize)), Ordering::Equal);
+
+    assert_eq!(Vector3::new(1.0f64, 2.0, 3.0).lex_partial_cmp(&Vector3::new(1.0, 2.0, 4.0)), Some(Ordering::Less));
+    assert_eq!(Vector3::new(1.0f64, f64::NAN, 3.0).lex_partial_cmp(&Vector3::new(1.0, 2.0, 3.0)), None);
+    assert_eq!(Vector3::new(0.0f64, f64::NAN, 3.0).lex_partial_cmp(&Vector3::new(1.0, 2.0, 3.0)), Some(Ordering::Less));
+}
+
+#[test]
+fn test_total_cmp() {
+    use std::cmp::Ordering;
+
+    assert_eq!(Vector2::new(-0.0f64, 1.0).total_cmp(&Vector2::new(0.0, 0.0)), Ordering::Less);
+    assert_eq!(Vector2::new(f64::NAN, 1.0).total_cmp(&Vector2::new(f64::INFINITY, 0.0)), Ordering::Greater);
+    assert_eq!(Vector2::new(-f64::NAN, 1.0).total_cmp(&Vector2::new(f64::NEG_INFINITY, 0.0)), Ordering::Less);
+    assert_eq!(Vector3::new(1isize, 2isize, 3isize).total_cmp(&Vector3::new(1isize, 2isize, 3isize)), Ordering::Equal);
+
+    let mut vertices = vec![Vector3::new(1.0f32, 0.0, 0.0),
+                            Vector3::new(0.0f32, 1.0, 0.0),
+                            Vector3::new(1.0f32, 0.0, 0.0),
+                            Vector3::new(0.0f32, 0.0, 1.0)];
+    vertices.sort_by(|a, b| a.total_cmp(b));
+    vertices.dedup();
+    assert_eq!(vertices, vec![Vector3::new(0.0f32, 0.0, 1.0),
+                              Vector3::new(0.0f32, 1.0, 0.0),
+                              Vector3::new(1.0f32, 0.0, 0.0)]);
+}
+
 #[test]
 fn test_cross() {
     let a = Vector3::new(1isize, 2isize, 3isize);
@@ -92,6 +176,71 @@ fn test_cross() {
     assert_eq!(a.cross(b), r);
 }
 
+#[test]
+fn test_map_zip() {
+    assert_eq!(Vector2::new(1.5f64, -2.5).map(|x| x.floor() as i32), Vector2::new(1, -3));
+    assert_eq!(Vector3::new(-1.0f64, 0.5, 2.0).map(|x| x.max(0.0).min(1.0)), Vector3::new(0.0, 0.5, 1.0));
+    assert_eq!(Vector4::new(1, 2, 3, 4).zip(Vector4::new(4, 3, 2, 1), |a, b| a * b), Vector4::new(4, 6, 6, 4));
+    assert_eq!(Vector3::new(1, 5, 3).zip(Vector3::new(4, 2, 6), cmp::max), Vector3::new(4, 5, 6));
+}
+
+#[test]
+fn test_perp() {
+    assert_eq!(Vector2::new(1, 0).perp(), Vector2::new(0, 1));
+    assert_eq!(Vector2::new(3.0f64, -2.0).perp(), Vector2::new(2.0, 3.0));
+
+    let a = Vector2::new(2.0f64, 1.0);
+    let b = Vector2::new(-1.0f64, 3.0);
+    assert_eq!(a.perp_dot(b), 7.0);
+    assert_eq!(a.perp().dot(b), a.perp_dot(b));
+    // counter-clockwise turns have a positive perp-dot
+    assert!(Vector2::unit_x().perp_dot(Vector2::new(1.0f64, 0.1)) > 0.0);
+    assert!(Vector2::unit_x().perp_dot(Vector2::new(1.0f64, -0.1)) < 0.0);
+}
+
+#[test]
+fn test_outer() {
+    assert_eq!(Vector2::new(1.0f64, 2.0).outer(Vector2::new(3.0, 4.0)),
+               Matrix2::new(3.0, 6.0, 4.0, 8.0));
+    let u = Vector3::new(1.0f64, 2.0, 3.0);
+    let v = Vector3::new(-1.0f64, 0.5, 2.0);
+    let w = Vector3::new(4.0f64, 5.0, 6.0);
+    assert_eq!(u.outer(v), Matrix3::new(-1.0, -2.0, -3.0,
+                                        0.5, 1.0, 1.5,
+                                        2.0, 4.0, 6.0));
+    assert_eq!(u.outer(v) * w, u * v.dot(w));
+    assert_eq!(Vector4::unit_x().outer(Vector4::new(1.0f64, 2.0, 3.0, 4.0)).row(0),
+               Vector4::new(1.0, 2.0, 3.0, 4.0));
+}
+
+#[test]
+fn test_cross_matrix() {
+    let u = Vector3::new(1.0f64, 2.0, 3.0);
+    let v = Vector3::new(-1.0f64, 0.5, 2.0);
+    let m = u.cross_matrix();
+    assert_eq!(m * v, u.cross(v));
+    assert_eq!(m.transpose(), -m);
+    assert_eq!(m * u, Vector3::zero());
+    // the product of two cross matrices, as in transforming an inertia tensor
+    assert_eq!(m * v.cross_matrix(), v.outer(u) - Matrix3::from_value(u.dot(v)));
+}
+
+#[test]
+fn test_any_orthonormal_pair() {
+    let normals = [Vector3::unit_x(), Vector3::unit_y(), Vector3::unit_z(), -Vector3::unit_z(),
+                   Vector3::new(1.0f64, -2.0, 0.5).normalize(),
+                   Vector3::new(0.3f64, 0.1, -0.9).normalize()];
+    for &n in normals.iter() {
+        let (b1, b2) = n.any_orthonormal_pair();
+        assert!(b1.length().approx_eq(&1.0));
+        assert!(b2.length().approx_eq(&1.0));
+        assert!(b1.dot(n).approx_eq(&0.0));
+        assert!(b2.dot(n).approx_eq(&0.0));
+        assert!(b1.dot(b2).approx_eq(&0.0));
+        assert!(b1.cross(b2).approx_eq(&n));
+    }
+}
+
 #[test]
 fn test_is_perpendicular() {
     assert!(Vector2::new(1.0f64, 0.0f64).is_perpendicular(Vector2::new(0.0f64, 1.0f64)));
@@ -155,6 +304,50 @@ fn test_angle() {
     assert!(Vector4::new(-1.0f64, 0.0f64, -1.0f64, 0.0f64).angle(Vector4::new(0.0f64, 1.0f64, 0.0f64, 1.0f64)).approx_eq( &rad(f64::consts::FRAC_PI_2) ));
 }
 
+#[test]
+fn test_distance() {
+    assert_eq!(Vector2::new(1.0f64, 1.0).distance(Vector2::new(4.0, 5.0)), 5.0);
+    assert_eq!(Vector3::new(1.0f64, 2.0, 3.0).distance2(Vector3::new(3.0, -1.0, 9.0)), 49.0);
+    assert_eq!(Vector4::new(1.0f64, 1.0, 1.0, 1.0).distance(Vector4::new(2.0, 2.0, 2.0, 2.0)), 2.0);
+}
+
+#[test]
+fn test_move_towards() {
+    let a = Vector3::new(1.0f64, 2.0, 3.0);
+    let b = Vector3::new(1.0f64, 6.0, 0.0);
+    assert!(a.move_towards(b, 2.5).approx_eq(&Vector3::new(1.0, 4.0, 1.5)));
+    assert_eq!(a.move_towards(b, 5.0), b);
+    assert_eq!(a.move_towards(b, 10.0), b);
+    assert_eq!(b.move_towards(b, 0.0), b);
+}
+
+#[test]
+fn test_polar() {
+    let v = Vector2::from_polar(2.0f64, rad(f64::consts::FRAC_PI_2));
+    assert!(v.approx_eq(&Vector2::new(0.0, 2.0)));
+    let (radius, angle) = Vector2::new(-1.0f64, -1.0).to_polar();
+    assert!(radius.approx_eq(&2.0f64.sqrt()));
+    assert!(angle.approx_eq(&rad(-3.0 * f64::consts::FRAC_PI_4)));
+}
+
+#[test]
+fn test_spherical() {
+    assert!(Vector3::from_spherical(3.0f64, rad(0.0), rad(1.0)).approx_eq(&Vector3::new(0.0, 0.0, 3.0)));
+    assert!(Vector3::from_spherical(1.0f64, rad(f64::consts::FRAC_PI_2), rad(f64::consts::FRAC_PI_2))
+        .approx_eq(&Vector3::unit_y()));
+
+    let v = Vector3::new(1.0f64, -2.0, 0.5);
+    let (radius, theta, phi) = v.to_spherical();
+    assert!(radius.approx_eq(&v.length()));
+    assert!(Vector3::from_spherical(radius, theta, phi).approx_eq(&v));
+    assert_eq!(Vector3::new(0.0f64, 0.0, -2.0).to_spherical(), (2.0, rad(f64::consts::PI), rad(0.0)));
+
+    let (radius, angle, height) = v.to_cylindrical();
+    assert!(radius.approx_eq(&5.0f64.sqrt()));
+    assert_eq!(height, 0.5);
+    assert!(Vector3::from_cylindrical(radius, angle, height).approx_eq(&v));
+}
+
 #[test]
 fn test_normalize() {
     // TODO: test normalize_to, normalize_sel.0, and normalize_self_to
@@ -169,3 +362,46 @@ fn test_cast() {
     assert_approx_eq!(Vector3::new(1.0f64, 2.4, -3.13).cast(), Vector3::new(1.0f32, 2.4, -3.13));
     assert_approx_eq!(Vector4::new(13.5f64, -4.6, -8.3, 2.41).cast(), Vector4::new(13.5f32, -4.6, -8.3, 2.41));
 }
+
+#[test]
+fn test_display_parse() {
+    let v = Vector3::new(1.5f64, -2.0, 0.25);
+    assert_eq!(v.to_string(), "(1.5, -2, 0.25)");
+    assert_eq!(format!("{:.2}", v), "(1.50, -2.00, 0.25)");
+    assert_eq!(v.to_string().parse::<Vector3<f64>>(), Ok(v));
+    assert_eq!(" ( 1, 2 ) ".parse::<Vector2<i32>>(), Ok(Vector2::new(1, 2)));
+    assert_eq!(Point3::new(1.0f32, 2.0, 3.0).to_string().parse(), Ok(Point3::new(1.0f32, 2.0, 3.0)));
+
+    assert_eq!("[1, 2]".parse::<Vector2<f64>>(), Err(ParseError::Syntax));
+    assert_eq!("(1, 2, 3)".parse::<Vector2<f64>>(), Err(ParseError::Length));
+    assert_eq!("(1, x, 3, 4)".parse::<Vector4<f64>>(), Err(ParseError::Component));
+}
+
+#[test]
+fn test_hash() {
+    use std::collections::HashMap;
+
+    let mut indices = HashMap::new();
+    let vertices = [Vector3::new(1.0f32, 2.0, 3.0), Vector3::new(0.0, 1.0, 0.0),
+                    Vector3::new(1.0, 2.0, 3.0), Vector3::new(-0.0, 1.0, 0.0)];
+    for v in vertices.iter() {
+        let next = indices.len();
+        indices.entry(*v).or_insert(next);
+    }
+    assert_eq!(indices.len(), 2);
+    assert_eq!(indices[&Vector3::new(0.0, 1.0, 0.0)], 1);
+
+    let mut points = HashMap::new();
+    points.insert(Point2::new(0.5f64, 0.25), "a");
+    assert_eq!(points.get(&Point2::new(0.5, 0.25)), Some(&"a"));
+    assert_eq!(points.get(&Point2::new(0.5, 0.2500001)), None);
+}
+
+#[test]
+fn test_as_slice() {
+    let mut v = Vector3::new(1.0f32, 2.0, 3.0);
+    assert_eq!(v.as_slice(), &[1.0, 2.0, 3.0]);
+    v.as_mut_slice()[1] = 5.0;
+    assert_eq!(v, Vector3::new(1.0, 5.0, 3.0));
+    assert_eq!(Point2::new(1i32, 2).as_slice(), &[1, 2]);
+}